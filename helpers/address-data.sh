@@ -0,0 +1,52 @@
+#!/usr/bin/env bash
+
+# ./helpers/address-data.sh
+# Generates src/data/addresses.rs from the JSON files in data/addresses. Each key of a file
+# becomes one dataset named COUNTRY_KEY, e.g. data/addresses/us.json "streets" => DATA_US_STREETS.
+
+OUT=src/data/addresses.rs
+
+cat<<EOF > $OUT
+//! Module addresses provides constants which contain the JSON in \`&str\` format for the street,
+//! city and region names used by the address generator. The data is taken from the files in
+//! \`data/addresses\` and has the same single \`data\` field as the corpora datasets.
+
+// this file is generated by the ./helpers/address-data.sh script
+
+EOF
+
+add_dataset() {
+    FILE="$1"
+    KEY="$2"
+    COUNTRY="$(basename "$FILE" .json | tr '[:lower:]' '[:upper:]')"
+    NAME="${COUNTRY}_$(echo -n "$KEY" | tr '[:lower:]' '[:upper:]')"
+
+    cat <<EOF >> $OUT
+pub const DATA_${NAME}: &str = r#"
+{
+  "data": $(jq ".$KEY" "$FILE")
+}"#;
+
+EOF
+}
+
+add_dataset data/addresses/us.json streets
+add_dataset data/addresses/us.json cities
+add_dataset data/addresses/us.json states
+add_dataset data/addresses/uk.json streets
+add_dataset data/addresses/uk.json towns
+add_dataset data/addresses/uk.json counties
+add_dataset data/addresses/de.json streets
+add_dataset data/addresses/de.json cities
+add_dataset data/addresses/de.json states
+add_dataset data/addresses/fr.json streets
+add_dataset data/addresses/fr.json cities
+add_dataset data/addresses/fr.json regions
+add_dataset data/addresses/jp.json districts
+add_dataset data/addresses/jp.json cities
+add_dataset data/addresses/jp.json prefectures
+
+# remove the trailing empty line
+sed -i '$ d' $OUT
+
+echo "Done."
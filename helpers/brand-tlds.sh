@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+
+# ./helpers/brand-tlds.sh
+# Generates src/data/brand_tlds.rs from the ICANN list of generic TLDs. Brand TLDs are the ones
+# with Specification 13 in their registry agreement. The TLDs in SINGLE_OPERATOR have no
+# Specification 13 but belong to one company which uses them for its own services, like Google's
+# .docs or Amazon's .book, so a random name under them looks just as odd.
+
+OUT=src/data/brand_tlds.rs
+GTLDS_URL=https://www.icann.org/resources/registries/gtlds/v2/gtlds.json
+
+SINGLE_OPERATOR="ads anquan author book buy call catholic channel circle comsec coupon deal docs
+drive fast fire foo free got grocery hangout hot hotels jot joy like map moi nexus now open pay pin
+prod prof read room rsvp safe save search secure silk smile song soy spot talk tunes tushu wanggou
+wow you yun zero"
+
+SPEC_13=$(curl -s "$GTLDS_URL" \
+    | jq -r '.gTLDs[] | select(.specification13 and (.contractTerminated | not) and .removalDate == null) | .gTLD')
+BRANDS=$(printf "%s\n" $SPEC_13 $SINGLE_OPERATOR | tr '[:upper:]' '[:lower:]' | sort -u)
+COUNT=$(echo "$BRANDS" | wc -l | tr -d ' ')
+
+cat<<TMPL > $OUT
+//! Module brand_tlds provides the TLDs which a company or organisation operates for its own use,
+//! taken from the Specification 13 (.brand) registry agreements in <$GTLDS_URL> and the TLDs
+//! of single operators listed in the script.
+// last updated $(date)
+// this file is generated by the ./helpers/brand-tlds.sh script
+
+pub const DATA_BRAND_TLDS: [&str; $COUNT] = [
+$(echo "$BRANDS" | sed 's/.*/    "&",/')
+];
+TMPL
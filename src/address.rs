@@ -0,0 +1,333 @@
+//! Module address provides postal addresses laid out the way each country writes them, with postal
+//! codes that match the country's format.
+//!
+//! Street, city and region names come from the bundled address datasets (see `data/addresses`).
+//! They are picked independently, so a generated address is plausible but never a real location.
+//! Japanese addresses are romanized and written in the western order used for mail from abroad.
+
+use rand::Rng;
+
+use crate::data::countries::{country_code, country_name};
+use crate::data::pick_word;
+use crate::id::random_digits;
+
+/// ISO codes of the countries [`gen_address`] supports.
+pub const ADDRESS_COUNTRIES: [&str; 5] = ["US", "GB", "DE", "FR", "JP"];
+
+const US_STREET_SUFFIXES: [&str; 10] = [
+    "Street",
+    "Avenue",
+    "Road",
+    "Lane",
+    "Drive",
+    "Boulevard",
+    "Court",
+    "Way",
+    "Place",
+    "Terrace",
+];
+
+const UK_STREET_SUFFIXES: [&str; 10] = [
+    "Road", "Street", "Lane", "Close", "Avenue", "Gardens", "Crescent", "Drive", "Way", "Grove",
+];
+
+/// Postcode areas of the UK, the letters at the start of every postcode.
+const UK_POSTCODE_AREAS: [&str; 40] = [
+    "AB", "B", "BA", "BN", "BS", "BT", "CB", "CF", "CH", "CV", "DE", "E", "EC", "EH", "EX", "G",
+    "GL", "IP", "L", "LS", "M", "N", "NE", "NG", "NR", "NW", "OX", "PL", "PO", "RG", "S", "SE",
+    "SO", "SW", "W", "WC", "WR", "YO", "HU", "LE",
+];
+
+/// Letters used in the inward code of UK postcodes, which never contains C, I, K, M, O or V.
+const UK_INWARD_LETTERS: &[u8] = b"ABDEFGHJLNPQRSTUWXYZ";
+
+/// A generated postal address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Address {
+    /// ISO 3166-1 alpha-2 code of the country.
+    pub country_code: String,
+    /// House number, for Japan the `chome-ban-go` block number like `1-2-3`.
+    pub street_number: String,
+    /// Street name, for Japan the district.
+    pub street: String,
+    /// City, post town or ward.
+    pub city: String,
+    /// State, county, federal state, region or prefecture.
+    pub region: String,
+    pub postal_code: String,
+}
+
+impl Address {
+    /// The first line of the address with the house number where the country puts it.
+    pub fn street_line(&self) -> String {
+        match self.country_code.as_str() {
+            "DE" => format!("{} {}", self.street, self.street_number),
+            _ => format!("{} {}", self.street_number, self.street),
+        }
+    }
+
+    /// The lines of the address as written for domestic mail.
+    pub fn lines(&self) -> Vec<String> {
+        let street = self.street_line();
+        match self.country_code.as_str() {
+            "US" => vec![
+                street,
+                format!("{}, {} {}", self.city, self.region, self.postal_code),
+            ],
+            "GB" => vec![street, self.city.to_uppercase(), self.postal_code.clone()],
+            "DE" => vec![street, format!("{} {}", self.postal_code, self.city)],
+            "FR" => vec![
+                street,
+                format!("{} {}", self.postal_code, self.city.to_uppercase()),
+            ],
+            _ => vec![
+                street,
+                format!("{}, {} {}", self.city, self.region, self.postal_code),
+            ],
+        }
+    }
+
+    /// Multi-line label for domestic mail.
+    pub fn label(&self) -> String {
+        self.lines().join("\n")
+    }
+
+    /// Multi-line label for international mail, with the country name in capitals on the last
+    /// line.
+    pub fn international_label(&self) -> String {
+        let mut lines = self.lines();
+        let country = country_name(&self.country_code).unwrap_or(&self.country_code);
+        lines.push(country.to_uppercase());
+        lines.join("\n")
+    }
+}
+
+/// Resolve `country` to one of the supported ISO codes.
+fn supported_country(country: &str) -> Option<&'static str> {
+    let code = country_code(country)?;
+    ADDRESS_COUNTRIES.iter().find(|c| **c == code).copied()
+}
+
+/// Returns a postal code in the format of `country`: US ZIP+4, UK postcode, German PLZ, French
+/// code postal or Japanese postal code. Returns `None` for unsupported countries, see
+/// [`ADDRESS_COUNTRIES`].
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::address::gen_postal_code;
+/// let zip: String = gen_postal_code("US").unwrap();
+/// // zip => "62704-1234"
+/// assert_eq!(zip.len(), 10);
+/// ```
+pub fn gen_postal_code(country: &str) -> Option<String> {
+    let mut rnd = rand::rng();
+    let code = match supported_country(country)? {
+        "US" => format!(
+            "{:05}-{}",
+            rnd.random_range(1001..=99950),
+            random_digits(&mut rnd, 4)
+        ),
+        "GB" => {
+            let area = UK_POSTCODE_AREAS[rnd.random_range(0..UK_POSTCODE_AREAS.len())];
+            let inward: String = (0..2)
+                .map(|_| {
+                    char::from(UK_INWARD_LETTERS[rnd.random_range(0..UK_INWARD_LETTERS.len())])
+                })
+                .collect();
+            format!(
+                "{}{} {}{}",
+                area,
+                rnd.random_range(1..=20),
+                rnd.random_range(0..10),
+                inward
+            )
+        }
+        "DE" => format!("{:05}", rnd.random_range(1067..=99998)),
+        // the first two digits are the département
+        "FR" => format!(
+            "{:02}{}",
+            rnd.random_range(1..=95),
+            random_digits(&mut rnd, 3)
+        ),
+        _ => format!(
+            "{:03}-{}",
+            rnd.random_range(1..=999),
+            random_digits(&mut rnd, 4)
+        ),
+    };
+    Some(code)
+}
+
+/// Returns an address in `country`, given as ISO code or as name from the `countries` dataset.
+/// Returns `None` for unsupported countries, see [`ADDRESS_COUNTRIES`].
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_address;
+/// let address = gen_address("DE").unwrap();
+/// // address.label() => "Goethestraße 12\n10115 Berlin"
+/// assert_eq!(address.postal_code.len(), 5);
+/// assert_eq!(address.label().lines().count(), 2);
+/// assert!(gen_address("Atlantis").is_none());
+/// ```
+pub fn gen_address(country: &str) -> Option<Address> {
+    let code = supported_country(country)?;
+    let mut rnd = rand::rng();
+    let postal_code = gen_postal_code(code)?;
+    let number = rnd.random_range(1..=200);
+    let address = match code {
+        "US" => Address {
+            country_code: code.to_string(),
+            street_number: rnd.random_range(1..=9999).to_string(),
+            street: format!(
+                "{} {}",
+                pick_word("us_streets", &mut rnd),
+                US_STREET_SUFFIXES[rnd.random_range(0..US_STREET_SUFFIXES.len())]
+            ),
+            city: pick_word("us_cities", &mut rnd),
+            region: pick_word("us_states", &mut rnd),
+            postal_code,
+        },
+        "GB" => Address {
+            country_code: code.to_string(),
+            street_number: number.to_string(),
+            street: format!(
+                "{} {}",
+                pick_word("uk_streets", &mut rnd),
+                UK_STREET_SUFFIXES[rnd.random_range(0..UK_STREET_SUFFIXES.len())]
+            ),
+            city: pick_word("uk_towns", &mut rnd),
+            region: pick_word("uk_counties", &mut rnd),
+            postal_code,
+        },
+        "DE" => Address {
+            country_code: code.to_string(),
+            // some houses share a number and get a letter, like 12a
+            street_number: match rnd.random_range(0..8) {
+                0 => format!("{}a", number),
+                1 => format!("{}b", number),
+                _ => number.to_string(),
+            },
+            street: pick_word("de_streets", &mut rnd),
+            city: pick_word("de_cities", &mut rnd),
+            region: pick_word("de_states", &mut rnd),
+            postal_code,
+        },
+        "FR" => Address {
+            country_code: code.to_string(),
+            street_number: match rnd.random_range(0..8) {
+                0 => format!("{} bis", number),
+                _ => number.to_string(),
+            },
+            street: pick_word("fr_streets", &mut rnd),
+            city: pick_word("fr_cities", &mut rnd),
+            region: pick_word("fr_regions", &mut rnd),
+            postal_code,
+        },
+        _ => Address {
+            country_code: code.to_string(),
+            street_number: format!(
+                "{}-{}-{}",
+                rnd.random_range(1..=9),
+                rnd.random_range(1..=30),
+                rnd.random_range(1..=20)
+            ),
+            street: pick_word("jp_districts", &mut rnd),
+            city: pick_word("jp_cities", &mut rnd),
+            region: pick_word("jp_prefectures", &mut rnd),
+            postal_code,
+        },
+    };
+    Some(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_digits(input: &str, length: usize) -> bool {
+        input.len() == length && input.chars().all(|c| c.is_ascii_digit())
+    }
+
+    fn is_uk_postcode(input: &str) -> bool {
+        let Some((outward, inward)) = input.split_once(' ') else {
+            return false;
+        };
+        let area: String = outward
+            .chars()
+            .take_while(|c| c.is_ascii_uppercase())
+            .collect();
+        let district = &outward[area.len()..];
+        let inward: Vec<char> = inward.chars().collect();
+        UK_POSTCODE_AREAS.contains(&area.as_str())
+            && (1..=2).contains(&district.len())
+            && district.chars().all(|c| c.is_ascii_digit())
+            && inward.len() == 3
+            && inward[0].is_ascii_digit()
+            && inward[1..]
+                .iter()
+                .all(|c| UK_INWARD_LETTERS.contains(&(*c as u8)))
+    }
+
+    #[test]
+    fn test_postal_codes() {
+        for _ in 0..200 {
+            let zip = gen_postal_code("US").unwrap();
+            let (zip5, plus4) = zip.split_once('-').unwrap();
+            assert!(is_digits(zip5, 5) && is_digits(plus4, 4), "{}", zip);
+
+            let postcode = gen_postal_code("United Kingdom").unwrap();
+            assert!(is_uk_postcode(&postcode), "{}", postcode);
+
+            assert!(is_digits(&gen_postal_code("DE").unwrap(), 5));
+            assert!(is_digits(&gen_postal_code("France").unwrap(), 5));
+
+            let jp = gen_postal_code("JP").unwrap();
+            let (first, second) = jp.split_once('-').unwrap();
+            assert!(is_digits(first, 3) && is_digits(second, 4), "{}", jp);
+        }
+        assert_eq!(gen_postal_code("Atlantis"), None);
+        assert_eq!(gen_postal_code("Spain"), None);
+    }
+
+    #[test]
+    fn test_gen_address() {
+        for code in ADDRESS_COUNTRIES {
+            let address = gen_address(code).unwrap();
+            assert_eq!(address.country_code, code);
+            for field in [&address.street, &address.city, &address.region] {
+                assert!(!field.is_empty(), "{:?}", address);
+            }
+            let label = address.international_label();
+            assert_eq!(label.lines().count(), address.lines().len() + 1);
+            assert!(label.contains(&address.postal_code));
+        }
+    }
+
+    #[test]
+    fn test_labels() {
+        let address = Address {
+            country_code: "DE".to_string(),
+            street_number: "12".to_string(),
+            street: "Goethestraße".to_string(),
+            city: "Berlin".to_string(),
+            region: "Berlin".to_string(),
+            postal_code: "10115".to_string(),
+        };
+        assert_eq!(address.label(), "Goethestraße 12\n10115 Berlin");
+        assert_eq!(
+            address.international_label(),
+            "Goethestraße 12\n10115 Berlin\nGERMANY"
+        );
+
+        let address = Address {
+            country_code: "GB".to_string(),
+            street_number: "10".to_string(),
+            street: "Station Road".to_string(),
+            city: "York".to_string(),
+            region: "North Yorkshire".to_string(),
+            postal_code: "YO1 7HH".to_string(),
+        };
+        assert_eq!(address.label(), "10 Station Road\nYORK\nYO1 7HH");
+    }
+}
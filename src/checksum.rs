@@ -0,0 +1,230 @@
+//! Module checksum provides the check digit algorithms used by the generators, so generated values
+//! can also be validated in tests.
+
+/// Returns the Luhn check digit for `payload` (all digits except the check digit). Non-digit
+/// characters are ignored.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::luhn_check_digit;
+/// assert_eq!(luhn_check_digit("7992739871"), 3);
+/// ```
+pub fn luhn_check_digit(payload: &str) -> u8 {
+    let sum: u32 = digits(payload)
+        .rev()
+        .enumerate()
+        .map(|(i, d)| {
+            if i % 2 == 0 {
+                let doubled = d * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                d
+            }
+        })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// Returns `true` if the last digit of `number` is a valid Luhn check digit. Spaces and dashes are
+/// ignored, any other non-digit character makes the number invalid.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::luhn_valid;
+/// assert!(luhn_valid("4111 1111 1111 1111"));
+/// assert!(!luhn_valid("4111 1111 1111 1112"));
+/// ```
+pub fn luhn_valid(number: &str) -> bool {
+    let clean: String = number.chars().filter(|c| *c != ' ' && *c != '-').collect();
+    if clean.len() < 2 || !clean.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let (payload, check) = clean.split_at(clean.len() - 1);
+    luhn_check_digit(payload).to_string() == check
+}
+
+/// Returns the remainder of a number given as string of digits and uppercase letters divided by 97,
+/// with letters converted to numbers as in ISO 7064 (`A` = 10, ..., `Z` = 35).
+fn mod97(input: &str) -> u32 {
+    input.chars().fold(0, |rem, c| {
+        let value = c.to_digit(36).unwrap_or(0);
+        if value > 9 {
+            (rem * 100 + value) % 97
+        } else {
+            (rem * 10 + value) % 97
+        }
+    })
+}
+
+/// Returns the two IBAN check digits for a country code and BBAN (ISO 13616, MOD 97-10).
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::iban_check_digits;
+/// assert_eq!(iban_check_digits("GB", "NWBK60161331926819"), "29");
+/// ```
+pub fn iban_check_digits(country: &str, bban: &str) -> String {
+    let rearranged = format!("{}{}00", bban, country).to_uppercase();
+    format!("{:02}", 98 - mod97(&rearranged))
+}
+
+/// Returns `true` if `iban` has valid check digits. Spaces are ignored.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::iban_valid;
+/// assert!(iban_valid("GB29 NWBK 6016 1331 9268 19"));
+/// assert!(!iban_valid("GB28 NWBK 6016 1331 9268 19"));
+/// ```
+pub fn iban_valid(iban: &str) -> bool {
+    let clean: String = iban.chars().filter(|c| *c != ' ').collect();
+    if clean.len() < 5 || !clean.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return false;
+    }
+    let (head, bban) = clean.split_at(4);
+    mod97(&format!("{}{}", bban, head).to_uppercase()) == 1
+}
+
+/// Returns the check digit (the 9th digit) of an ABA routing number for the first 8 digits.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::aba_check_digit;
+/// assert_eq!(aba_check_digit("11100061"), 4);
+/// ```
+pub fn aba_check_digit(payload: &str) -> u8 {
+    const WEIGHTS: [u32; 8] = [3, 7, 1, 3, 7, 1, 3, 7];
+    let sum: u32 = digits(payload).zip(WEIGHTS).map(|(d, w)| d * w).sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// Returns `true` if `routing` is a 9 digit ABA routing number with a valid check digit.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::aba_valid;
+/// assert!(aba_valid("111000614"));
+/// ```
+pub fn aba_valid(routing: &str) -> bool {
+    routing.len() == 9
+        && routing.chars().all(|c| c.is_ascii_digit())
+        && aba_check_digit(&routing[..8]).to_string() == routing[8..]
+}
+
+/// Returns the GS1 check digit used by EAN-8, EAN-13, UPC-A, GTIN-14 and ISBN-13 for `payload`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::gs1_check_digit;
+/// assert_eq!(gs1_check_digit("400638133393"), 1);
+/// ```
+pub fn gs1_check_digit(payload: &str) -> u8 {
+    let sum: u32 = digits(payload)
+        .rev()
+        .enumerate()
+        .map(|(i, d)| if i % 2 == 0 { d * 3 } else { d })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// Returns `true` if `code` ends with a valid GS1 check digit. Spaces and dashes are ignored.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::gs1_valid;
+/// assert!(gs1_valid("4006381333931"));
+/// assert!(gs1_valid("978-3-16-148410-0"));
+/// ```
+pub fn gs1_valid(code: &str) -> bool {
+    let clean: String = code.chars().filter(|c| *c != ' ' && *c != '-').collect();
+    if clean.len() < 2 || !clean.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let (payload, check) = clean.split_at(clean.len() - 1);
+    gs1_check_digit(payload).to_string() == check
+}
+
+/// Returns the MOD 11 check character for the first digits of an ISBN-10 (9 digits) or ISSN
+/// (7 digits). A check value of 10 is written as `X`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::mod11_check_char;
+/// assert_eq!(mod11_check_char("030640615"), '2');
+/// assert_eq!(mod11_check_char("0317847"), '1');
+/// ```
+pub fn mod11_check_char(payload: &str) -> char {
+    let d: Vec<u32> = digits(payload).collect();
+    let len = d.len() as u32;
+    let sum: u32 = d
+        .iter()
+        .enumerate()
+        .map(|(i, d)| d * (len + 1 - i as u32))
+        .sum();
+    match (11 - sum % 11) % 11 {
+        10 => 'X',
+        check => char::from_digit(check, 10).unwrap_or('0'),
+    }
+}
+
+/// Returns `true` if `code` is a valid ISBN-10 or ISSN, i.e. its last character is the MOD 11
+/// check character. Spaces and dashes are ignored.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::mod11_valid;
+/// assert!(mod11_valid("0-306-40615-2"));
+/// assert!(mod11_valid("2049-3630"));
+/// ```
+pub fn mod11_valid(code: &str) -> bool {
+    let clean: String = code
+        .chars()
+        .filter(|c| *c != ' ' && *c != '-')
+        .collect::<String>()
+        .to_uppercase();
+    if clean.len() < 2 || !clean.is_ascii() {
+        return false;
+    }
+    let (payload, check) = clean.split_at(clean.len() - 1);
+    payload.chars().all(|c| c.is_ascii_digit()) && check.starts_with(mod11_check_char(payload))
+}
+
+/// Iterate over the digits in `input`, skipping everything else.
+pub(crate) fn digits(input: &str) -> impl DoubleEndedIterator<Item = u32> + '_ {
+    input.chars().filter_map(|c| c.to_digit(10))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_luhn() {
+        for valid in ["79927398713", "378282246310005", "6011111111111117", "0"] {
+            assert_eq!(luhn_valid(valid), valid.len() > 1, "{}", valid);
+        }
+        assert!(!luhn_valid("79927398710"));
+        assert!(!luhn_valid("7992739871a"));
+        assert_eq!(luhn_check_digit("411111111111111"), 1);
+    }
+
+    #[test]
+    fn test_iban_and_aba() {
+        assert!(iban_valid("DE89370400440532013000"));
+        assert!(iban_valid("NO9386011117947"));
+        assert_eq!(iban_check_digits("DE", "370400440532013000"), "89");
+        assert!(!iban_valid("DE8937040044053201300"));
+        assert!(aba_valid("021000021"));
+        assert!(!aba_valid("021000022"));
+    }
+
+    #[test]
+    fn test_gs1_and_mod11() {
+        assert!(gs1_valid("036000291452"));
+        assert!(gs1_valid("96385074"));
+        assert!(!gs1_valid("036000291453"));
+        assert!(mod11_valid("080442957X"));
+        assert!(!mod11_valid("0804429579"));
+        assert!(mod11_valid("0378-5955"));
+    }
+}
@@ -0,0 +1,255 @@
+//! Module company provides company names, job titles and departments, plus company records with a
+//! matching domain.
+//!
+//! Names are built from the `lastnames`, `objects` and `moods` datasets, job titles from the
+//! `occupations` dataset. Domains only use TLDs from the bundled IANA list, see `data::tlds`.
+
+use rand::Rng;
+
+use crate::context::safe_mode;
+use crate::data::{get_words, pick_word};
+use crate::domain::{tld_category, to_label};
+use crate::text::{capitalize, title_case};
+
+/// Legal forms with the domain suffix a company of that form would usually register.
+const LEGAL_FORMS: [(&str, &str); 9] = [
+    ("Inc", "com"),
+    ("Corp", "com"),
+    ("LLC", "com"),
+    ("GmbH", "de"),
+    ("AG", "de"),
+    ("Ltd", "co.uk"),
+    ("PLC", "co.uk"),
+    ("SARL", "fr"),
+    ("BV", "nl"),
+];
+
+/// Domain suffixes for start-ups, which prefer the newer generic TLDs.
+const LAB_SUFFIXES: [&str; 4] = ["io", "ai", "dev", "tech"];
+
+const SENIORITY_LEVELS: [&str; 7] = [
+    "Junior",
+    "Associate",
+    "Senior",
+    "Lead",
+    "Principal",
+    "Chief",
+    "Head",
+];
+
+const DEPARTMENTS: [&str; 16] = [
+    "Engineering",
+    "Sales",
+    "Marketing",
+    "Finance",
+    "Human Resources",
+    "Legal",
+    "Customer Support",
+    "Operations",
+    "Research and Development",
+    "Product",
+    "Design",
+    "IT",
+    "Procurement",
+    "Logistics",
+    "Quality Assurance",
+    "Facilities",
+];
+
+/// A generated company.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Company {
+    pub name: String,
+    /// Domain derived from the name, e.g. `smith-jones.com` for "Smith & Jones".
+    pub domain: String,
+}
+
+impl Company {
+    /// The URL of the company website: `https://www.smith-jones.com`.
+    pub fn website(&self) -> String {
+        format!("https://www.{}", self.domain)
+    }
+}
+
+/// A random single word from the dataset `name`, for names written in one word like "BoldRocket".
+fn pick_single_word(name: &str, rnd: &mut impl Rng) -> String {
+    let words: Vec<String> = get_words(name)
+        .into_iter()
+        .filter(|w| !w.is_empty() && w.chars().all(|c| c.is_ascii_alphabetic()))
+        .collect();
+    if words.is_empty() {
+        return String::new();
+    }
+    words[rnd.random_range(0..words.len())].clone()
+}
+
+/// The domain label for `name`, like `smith-jones` for "Smith & Jones".
+fn domain_label(name: &str) -> String {
+    let label = to_label(name);
+    if label.is_empty() {
+        return String::from("company");
+    }
+    label
+}
+
+/// Returns `suffix` if its last label is in the IANA TLD list, otherwise `com`.
+fn known_suffix(suffix: &str) -> &str {
+    let tld = suffix.rsplit('.').next().unwrap_or(suffix);
+    if tld_category(tld).is_some() {
+        suffix
+    } else {
+        "com"
+    }
+}
+
+/// Build a company name and the part of it that goes into the domain, together with the domain
+/// suffix.
+fn company_name(rnd: &mut impl Rng) -> (String, String, &'static str) {
+    match rnd.random_range(0..4) {
+        0 => {
+            let name = format!(
+                "{} & {}",
+                pick_word("lastnames", rnd),
+                pick_word("lastnames", rnd)
+            );
+            (name.clone(), name, "com")
+        }
+        1 => {
+            let (form, suffix) = LEGAL_FORMS[rnd.random_range(0..LEGAL_FORMS.len())];
+            let base = title_case(&pick_word("objects", rnd));
+            (format!("{} {}", base, form), base, suffix)
+        }
+        2 => {
+            let base = format!(
+                "{}{}",
+                capitalize(&pick_single_word("moods", rnd)),
+                capitalize(&pick_single_word("objects", rnd))
+            );
+            let suffix = LAB_SUFFIXES[rnd.random_range(0..LAB_SUFFIXES.len())];
+            (format!("{} Labs", base), base, suffix)
+        }
+        _ => {
+            let (form, suffix) = LEGAL_FORMS[rnd.random_range(0..LEGAL_FORMS.len())];
+            let base = format!("{} Group", pick_word("lastnames", rnd));
+            (format!("{} {}", base, form), base, suffix)
+        }
+    }
+}
+
+/// Returns a company name like "Smith & Jones", "Umbrella GmbH" or "BoldRocket Labs".
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_company_name;
+/// let name: String = gen_company_name();
+/// // name => "Smith & Jones"
+/// assert!(!name.is_empty());
+/// ```
+pub fn gen_company_name() -> String {
+    company_name(&mut rand::rng()).0
+}
+
+/// Returns a company with a domain derived from its name. The domain suffix matches the legal
+/// form, so a GmbH gets a `.de` and a Ltd a `.co.uk` domain. In safe mode (see
+/// [`crate::context::set_safe_mode`]) the domain uses the reserved `.example` TLD.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::company::gen_company;
+/// let company = gen_company();
+/// // company.name => "Umbrella GmbH"
+/// // company.domain => "umbrella.de"
+/// // company.website() => "https://www.umbrella.de"
+/// assert!(company.domain.contains('.'));
+/// ```
+pub fn gen_company() -> Company {
+    let (name, base, suffix) = company_name(&mut rand::rng());
+    // in safe mode the domain goes under the reserved `.example` TLD
+    let suffix = if safe_mode() {
+        "example"
+    } else {
+        known_suffix(suffix)
+    };
+    Company {
+        domain: format!("{}.{}", domain_label(&base), suffix),
+        name,
+    }
+}
+
+/// Returns a job title from the `occupations` dataset, most of the time with a seniority level like
+/// "Senior" or "Principal".
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_job_title;
+/// let title: String = gen_job_title();
+/// // title => "Senior Accountant"
+/// assert!(!title.is_empty());
+/// ```
+pub fn gen_job_title() -> String {
+    let mut rnd = rand::rng();
+    let occupation = title_case(&pick_word("occupations", &mut rnd));
+    if rnd.random_range(0..4) == 0 {
+        return occupation;
+    }
+    let level = SENIORITY_LEVELS[rnd.random_range(0..SENIORITY_LEVELS.len())];
+    format!("{} {}", level, occupation)
+}
+
+/// Returns a department like "Engineering" or "Human Resources".
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_department;
+/// let department: String = gen_department();
+/// // department => "Customer Support"
+/// ```
+pub fn gen_department() -> String {
+    let mut rnd = rand::rng();
+    DEPARTMENTS[rnd.random_range(0..DEPARTMENTS.len())].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_label() {
+        assert_eq!(domain_label("Smith & Jones"), "smith-jones");
+        assert_eq!(domain_label("Christmas Ornament"), "christmas-ornament");
+        assert_eq!(domain_label("Müller Group"), "mueller-group");
+        assert_eq!(domain_label("Crème Brûlée"), "creme-brulee");
+        assert_eq!(domain_label("&"), "company");
+        assert_eq!(domain_label(&"a".repeat(80)).len(), 63);
+        assert_eq!(known_suffix("co.uk"), "co.uk");
+        assert_eq!(known_suffix("notatld"), "com");
+    }
+
+    #[test]
+    fn test_gen_company() {
+        let tlds = get_words("tlds");
+        for _ in 0..100 {
+            let company = gen_company();
+            assert!(!company.name.is_empty());
+            let (label, suffix) = company.domain.split_once('.').unwrap();
+            assert!(!label.is_empty() && label.len() <= 63, "{}", company.domain);
+            assert!(!label.starts_with('-') && !label.ends_with('-'));
+            assert!(
+                label
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            );
+            let tld = suffix.rsplit('.').next().unwrap();
+            assert!(tlds.iter().any(|t| t == tld), "{}", company.domain);
+        }
+    }
+
+    #[test]
+    fn test_job_title_and_department() {
+        for _ in 0..50 {
+            let title = gen_job_title();
+            assert!(title.chars().next().unwrap().is_uppercase(), "{}", title);
+            assert!(DEPARTMENTS.contains(&gen_department().as_str()));
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Module context holds settings which apply to every generator of the crate.
+//!
+//! The settings are global to the process, so a test suite can enable them once at start-up.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static SAFE_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable safe mode. In safe mode every generator which returns domains, hostnames or
+/// email addresses only uses the names reserved for documentation and testing by RFC 2606 and
+/// RFC 6761, like `example.com` or `.test`, so generated data can never reach a real host.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::context::set_safe_mode;
+/// use fakedata_generator::domain::is_reserved;
+/// use fakedata_generator::gen_email;
+/// set_safe_mode(true);
+/// let email: String = gen_email();
+/// // email => "ribbon@example.org"
+/// assert!(is_reserved(email.split('@').nth(1).unwrap()));
+/// ```
+pub fn set_safe_mode(enabled: bool) {
+    SAFE_MODE.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns `true` if safe mode is enabled, see [`set_safe_mode`].
+pub fn safe_mode() -> bool {
+    SAFE_MODE.load(Ordering::Relaxed)
+}
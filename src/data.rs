@@ -7,10 +7,14 @@ use rand::Rng;
 use serde::Deserialize;
 use std::error::Error;
 
+pub mod addresses;
+pub mod brand_tlds;
 pub mod corpora;
+pub mod countries;
 pub mod primes;
 pub mod tlds;
 pub mod emojis;
+pub mod texts;
 
 /// JSONDataset represents a generic data structure for storing the parsed JSON. Each JSON taken
 /// from Corpora has a `data` field which is an Array of Strings in JSON (= Vec<String> in Rust).
@@ -76,6 +80,22 @@ fn get_dataset(key: &str) -> Result<JSONDataset, Box<dyn Error>> {
         "appliances" => corpora::DATA_APPLIANCES,
         "new_technologies" => corpora::DATA_NEW_TECHNOLOGIES,
         "programming_languages" => corpora::DATA_PROGRAMMING_LANGUAGES,
+        "us_streets" => addresses::DATA_US_STREETS,
+        "us_cities" => addresses::DATA_US_CITIES,
+        "us_states" => addresses::DATA_US_STATES,
+        "uk_streets" => addresses::DATA_UK_STREETS,
+        "uk_towns" => addresses::DATA_UK_TOWNS,
+        "uk_counties" => addresses::DATA_UK_COUNTIES,
+        "de_streets" => addresses::DATA_DE_STREETS,
+        "de_cities" => addresses::DATA_DE_CITIES,
+        "de_states" => addresses::DATA_DE_STATES,
+        "fr_streets" => addresses::DATA_FR_STREETS,
+        "fr_cities" => addresses::DATA_FR_CITIES,
+        "fr_regions" => addresses::DATA_FR_REGIONS,
+        "jp_districts" => addresses::DATA_JP_DISTRICTS,
+        "jp_cities" => addresses::DATA_JP_CITIES,
+        "jp_prefectures" => addresses::DATA_JP_PREFECTURES,
+        "tlds" => tlds::DATA_TLDS,
         _ => "",
     };
 
@@ -133,6 +153,22 @@ fn get_dataset(key: &str) -> Result<JSONDataset, Box<dyn Error>> {
 /// - `appliances`
 /// - `new_technologies`
 /// - `programming_languages`
+/// - `us_streets`
+/// - `us_cities`
+/// - `us_states`
+/// - `uk_streets`
+/// - `uk_towns`
+/// - `uk_counties`
+/// - `de_streets`
+/// - `de_cities`
+/// - `de_states`
+/// - `fr_streets`
+/// - `fr_cities`
+/// - `fr_regions`
+/// - `jp_districts`
+/// - `jp_cities`
+/// - `jp_prefectures`
+/// - `tlds`
 ///
 /// Each of these will return a random word from the list.
 ///
@@ -163,6 +199,24 @@ pub fn gen_switch(name: String) -> String {
     return data[index].to_string();
 }
 
+/// `get_words` returns every entry of the dataset `name`, using the same names as `gen_switch`.
+/// Unknown datasets return an empty `Vec` so callers can decide how to handle missing data.
+pub(crate) fn get_words(name: &str) -> Vec<String> {
+    match get_dataset(name) {
+        Ok(val) => val.data,
+        Err(_) => Vec::new(),
+    }
+}
+
+/// `pick_word` returns a random entry of the dataset `name`, or an empty string for unknown datasets.
+pub(crate) fn pick_word(name: &str, rnd: &mut impl Rng) -> String {
+    let words = get_words(name);
+    if words.is_empty() {
+        return String::new();
+    }
+    words[rnd.random_range(0..words.len())].clone()
+}
+
 // gen_corpora_switch is deprecated and should not be used, use `gen_switch` instead.
 // `gen_corpora_switch` may be removed in a future release.
 pub fn gen_corpora_switch(name: String) -> String {
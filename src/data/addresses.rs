@@ -0,0 +1,760 @@
+//! Module addresses provides constants which contain the JSON in `&str` format for the street,
+//! city and region names used by the address generator. The data is taken from the files in
+//! `data/addresses` and has the same single `data` field as the corpora datasets.
+
+// this file is generated by the ./helpers/address-data.sh script
+
+pub const DATA_US_STREETS: &str = r#"
+{
+  "data": [
+  "Main",
+  "Oak",
+  "Maple",
+  "Pine",
+  "Cedar",
+  "Elm",
+  "Washington",
+  "Lake",
+  "Hill",
+  "Park",
+  "Walnut",
+  "Sunset",
+  "Lincoln",
+  "Jackson",
+  "Franklin",
+  "Jefferson",
+  "Highland",
+  "Church",
+  "Center",
+  "Spring",
+  "Ridge",
+  "Meadow",
+  "River",
+  "Forest",
+  "Willow",
+  "Chestnut",
+  "Madison",
+  "Adams",
+  "Mill",
+  "Cherry",
+  "Dogwood",
+  "Hickory",
+  "Locust",
+  "Magnolia",
+  "Birch",
+  "Prospect",
+  "Valley",
+  "Pleasant",
+  "Union",
+  "Broad",
+  "Court",
+  "Liberty",
+  "Grant",
+  "Lafayette",
+  "Monroe",
+  "Summit",
+  "Woodland",
+  "Orchard",
+  "Green"
+]
+}"#;
+
+pub const DATA_US_CITIES: &str = r#"
+{
+  "data": [
+  "Springfield",
+  "Riverside",
+  "Franklin",
+  "Greenville",
+  "Bristol",
+  "Clinton",
+  "Fairview",
+  "Salem",
+  "Madison",
+  "Georgetown",
+  "Arlington",
+  "Ashland",
+  "Burlington",
+  "Manchester",
+  "Oxford",
+  "Milton",
+  "Dayton",
+  "Lexington",
+  "Jackson",
+  "Marion",
+  "Auburn",
+  "Chester",
+  "Dover",
+  "Hudson",
+  "Kingston",
+  "Newport",
+  "Oakland",
+  "Plymouth",
+  "Troy",
+  "Winchester",
+  "Centerville",
+  "Mount Vernon",
+  "Lebanon",
+  "Hamilton",
+  "Columbia",
+  "Portland",
+  "Glendale",
+  "Lakewood",
+  "Richmond",
+  "Cleveland",
+  "Bloomington",
+  "Florence",
+  "Cambridge",
+  "Jamestown",
+  "Watertown",
+  "Westfield",
+  "Brookfield",
+  "Lancaster",
+  "Monroe",
+  "Sheridan"
+]
+}"#;
+
+pub const DATA_US_STATES: &str = r#"
+{
+  "data": [
+  "AL",
+  "AK",
+  "AZ",
+  "AR",
+  "CA",
+  "CO",
+  "CT",
+  "DE",
+  "FL",
+  "GA",
+  "HI",
+  "ID",
+  "IL",
+  "IN",
+  "IA",
+  "KS",
+  "KY",
+  "LA",
+  "ME",
+  "MD",
+  "MA",
+  "MI",
+  "MN",
+  "MS",
+  "MO",
+  "MT",
+  "NE",
+  "NV",
+  "NH",
+  "NJ",
+  "NM",
+  "NY",
+  "NC",
+  "ND",
+  "OH",
+  "OK",
+  "OR",
+  "PA",
+  "RI",
+  "SC",
+  "SD",
+  "TN",
+  "TX",
+  "UT",
+  "VT",
+  "VA",
+  "WA",
+  "WV",
+  "WI",
+  "WY",
+  "DC"
+]
+}"#;
+
+pub const DATA_UK_STREETS: &str = r#"
+{
+  "data": [
+  "High",
+  "Station",
+  "Church",
+  "Victoria",
+  "Park",
+  "Mill",
+  "London",
+  "Manor",
+  "Queens",
+  "Kings",
+  "Green",
+  "School",
+  "North",
+  "South",
+  "West",
+  "New",
+  "Grange",
+  "Springfield",
+  "Windsor",
+  "York",
+  "Chapel",
+  "Albert",
+  "Orchard",
+  "Meadow",
+  "Beech",
+  "Oak",
+  "Willow",
+  "Elm",
+  "Highfield",
+  "Alexandra",
+  "Richmond",
+  "Cromwell",
+  "Stanley",
+  "Mount Pleasant",
+  "Bridge",
+  "Castle",
+  "Market",
+  "Abbey",
+  "Priory",
+  "St John's",
+  "Farm",
+  "Hillside",
+  "Woodland",
+  "Riverside",
+  "Sandy",
+  "Chestnut",
+  "Cherry Tree",
+  "Broad",
+  "Rectory"
+]
+}"#;
+
+pub const DATA_UK_TOWNS: &str = r#"
+{
+  "data": [
+  "London",
+  "Birmingham",
+  "Manchester",
+  "Leeds",
+  "Sheffield",
+  "Bristol",
+  "Liverpool",
+  "Newcastle upon Tyne",
+  "Nottingham",
+  "Leicester",
+  "Coventry",
+  "Bradford",
+  "Cardiff",
+  "Swansea",
+  "Edinburgh",
+  "Glasgow",
+  "Aberdeen",
+  "Dundee",
+  "Belfast",
+  "Southampton",
+  "Portsmouth",
+  "Brighton",
+  "Plymouth",
+  "Exeter",
+  "Norwich",
+  "Cambridge",
+  "Oxford",
+  "Reading",
+  "York",
+  "Bath",
+  "Canterbury",
+  "Chester",
+  "Durham",
+  "Lancaster",
+  "Lincoln",
+  "Derby",
+  "Stoke-on-Trent",
+  "Wolverhampton",
+  "Hull",
+  "Sunderland",
+  "Inverness",
+  "Stirling",
+  "Gloucester",
+  "Worcester",
+  "Hereford",
+  "Carlisle",
+  "Truro",
+  "Ipswich",
+  "Colchester",
+  "Luton"
+]
+}"#;
+
+pub const DATA_UK_COUNTIES: &str = r#"
+{
+  "data": [
+  "Bedfordshire",
+  "Berkshire",
+  "Buckinghamshire",
+  "Cambridgeshire",
+  "Cheshire",
+  "Cornwall",
+  "Cumbria",
+  "Derbyshire",
+  "Devon",
+  "Dorset",
+  "Durham",
+  "East Sussex",
+  "Essex",
+  "Gloucestershire",
+  "Greater London",
+  "Greater Manchester",
+  "Hampshire",
+  "Herefordshire",
+  "Hertfordshire",
+  "Kent",
+  "Lancashire",
+  "Leicestershire",
+  "Lincolnshire",
+  "Merseyside",
+  "Norfolk",
+  "North Yorkshire",
+  "Northamptonshire",
+  "Northumberland",
+  "Nottinghamshire",
+  "Oxfordshire",
+  "Shropshire",
+  "Somerset",
+  "Staffordshire",
+  "Suffolk",
+  "Surrey",
+  "Tyne and Wear",
+  "Warwickshire",
+  "West Midlands",
+  "West Sussex",
+  "West Yorkshire",
+  "Wiltshire",
+  "Worcestershire",
+  "Midlothian",
+  "Lanarkshire",
+  "Aberdeenshire",
+  "Fife",
+  "Glamorgan",
+  "Gwynedd",
+  "County Antrim",
+  "County Down"
+]
+}"#;
+
+pub const DATA_DE_STREETS: &str = r#"
+{
+  "data": [
+  "Hauptstraße",
+  "Schulstraße",
+  "Gartenstraße",
+  "Bahnhofstraße",
+  "Dorfstraße",
+  "Bergstraße",
+  "Birkenweg",
+  "Lindenstraße",
+  "Kirchstraße",
+  "Waldstraße",
+  "Ringstraße",
+  "Schillerstraße",
+  "Goethestraße",
+  "Wiesenweg",
+  "Mühlenweg",
+  "Amselweg",
+  "Jahnstraße",
+  "Buchenweg",
+  "Am Sportplatz",
+  "Friedhofstraße",
+  "Feldstraße",
+  "Rosenstraße",
+  "Blumenstraße",
+  "Lessingstraße",
+  "Eichenweg",
+  "Tannenweg",
+  "Industriestraße",
+  "Mozartstraße",
+  "Beethovenstraße",
+  "Poststraße",
+  "Kastanienallee",
+  "Lindenallee",
+  "Marktplatz",
+  "Am Rathaus",
+  "Ahornweg",
+  "Sonnenweg",
+  "Wilhelmstraße",
+  "Friedrichstraße",
+  "Kantstraße",
+  "Uhlandstraße",
+  "Heinestraße",
+  "Parkstraße",
+  "Mittelweg",
+  "Brunnenstraße",
+  "Kiefernweg",
+  "Drosselweg",
+  "Finkenweg",
+  "Im Winkel",
+  "Auf dem Berg",
+  "Seestraße"
+]
+}"#;
+
+pub const DATA_DE_CITIES: &str = r#"
+{
+  "data": [
+  "Berlin",
+  "Hamburg",
+  "München",
+  "Köln",
+  "Frankfurt am Main",
+  "Stuttgart",
+  "Düsseldorf",
+  "Leipzig",
+  "Dortmund",
+  "Essen",
+  "Bremen",
+  "Dresden",
+  "Hannover",
+  "Nürnberg",
+  "Duisburg",
+  "Bochum",
+  "Wuppertal",
+  "Bielefeld",
+  "Bonn",
+  "Münster",
+  "Mannheim",
+  "Karlsruhe",
+  "Augsburg",
+  "Wiesbaden",
+  "Mönchengladbach",
+  "Gelsenkirchen",
+  "Aachen",
+  "Braunschweig",
+  "Kiel",
+  "Chemnitz",
+  "Halle (Saale)",
+  "Magdeburg",
+  "Freiburg im Breisgau",
+  "Krefeld",
+  "Mainz",
+  "Lübeck",
+  "Erfurt",
+  "Oberhausen",
+  "Rostock",
+  "Kassel",
+  "Hagen",
+  "Potsdam",
+  "Saarbrücken",
+  "Hamm",
+  "Ludwigshafen am Rhein",
+  "Oldenburg",
+  "Mülheim an der Ruhr",
+  "Osnabrück",
+  "Leverkusen",
+  "Heidelberg"
+]
+}"#;
+
+pub const DATA_DE_STATES: &str = r#"
+{
+  "data": [
+  "Baden-Württemberg",
+  "Bayern",
+  "Berlin",
+  "Brandenburg",
+  "Bremen",
+  "Hamburg",
+  "Hessen",
+  "Mecklenburg-Vorpommern",
+  "Niedersachsen",
+  "Nordrhein-Westfalen",
+  "Rheinland-Pfalz",
+  "Saarland",
+  "Sachsen",
+  "Sachsen-Anhalt",
+  "Schleswig-Holstein",
+  "Thüringen"
+]
+}"#;
+
+pub const DATA_FR_STREETS: &str = r#"
+{
+  "data": [
+  "rue de la République",
+  "rue Victor Hugo",
+  "rue de la Paix",
+  "rue Pasteur",
+  "rue Jean Jaurès",
+  "rue de l'Église",
+  "rue du Moulin",
+  "rue des Écoles",
+  "rue de la Gare",
+  "rue du Château",
+  "rue des Lilas",
+  "rue des Jardins",
+  "rue du Général de Gaulle",
+  "rue Gambetta",
+  "rue Voltaire",
+  "rue Émile Zola",
+  "rue de la Fontaine",
+  "rue du Stade",
+  "rue des Roses",
+  "rue Nationale",
+  "avenue de la Libération",
+  "avenue Jean Moulin",
+  "avenue des Champs",
+  "avenue Foch",
+  "avenue de Verdun",
+  "avenue du Maréchal Leclerc",
+  "avenue de Paris",
+  "avenue Carnot",
+  "boulevard Saint-Michel",
+  "boulevard Haussmann",
+  "boulevard de la Liberté",
+  "boulevard Gambetta",
+  "place de la Mairie",
+  "place du Marché",
+  "place de l'Église",
+  "place Bellecour",
+  "allée des Tilleuls",
+  "allée des Chênes",
+  "impasse des Peupliers",
+  "impasse du Verger",
+  "chemin des Vignes",
+  "chemin du Lavoir",
+  "quai de la Seine",
+  "quai des Orfèvres",
+  "cours Mirabeau",
+  "route de Lyon",
+  "route Nationale",
+  "square Louis XVI",
+  "passage des Panoramas",
+  "rue Saint-Honoré"
+]
+}"#;
+
+pub const DATA_FR_CITIES: &str = r#"
+{
+  "data": [
+  "Paris",
+  "Marseille",
+  "Lyon",
+  "Toulouse",
+  "Nice",
+  "Nantes",
+  "Montpellier",
+  "Strasbourg",
+  "Bordeaux",
+  "Lille",
+  "Rennes",
+  "Reims",
+  "Toulon",
+  "Saint-Étienne",
+  "Le Havre",
+  "Grenoble",
+  "Dijon",
+  "Angers",
+  "Nîmes",
+  "Villeurbanne",
+  "Clermont-Ferrand",
+  "Le Mans",
+  "Aix-en-Provence",
+  "Brest",
+  "Tours",
+  "Amiens",
+  "Limoges",
+  "Annecy",
+  "Perpignan",
+  "Metz",
+  "Besançon",
+  "Orléans",
+  "Rouen",
+  "Mulhouse",
+  "Caen",
+  "Nancy",
+  "Argenteuil",
+  "Roubaix",
+  "Tourcoing",
+  "Avignon",
+  "Poitiers",
+  "Versailles",
+  "Pau",
+  "La Rochelle",
+  "Calais",
+  "Cannes",
+  "Antibes",
+  "Colmar",
+  "Ajaccio",
+  "Bayonne"
+]
+}"#;
+
+pub const DATA_FR_REGIONS: &str = r#"
+{
+  "data": [
+  "Auvergne-Rhône-Alpes",
+  "Bourgogne-Franche-Comté",
+  "Bretagne",
+  "Centre-Val de Loire",
+  "Corse",
+  "Grand Est",
+  "Hauts-de-France",
+  "Île-de-France",
+  "Normandie",
+  "Nouvelle-Aquitaine",
+  "Occitanie",
+  "Pays de la Loire",
+  "Provence-Alpes-Côte d'Azur"
+]
+}"#;
+
+pub const DATA_JP_DISTRICTS: &str = r#"
+{
+  "data": [
+  "Marunouchi",
+  "Jingumae",
+  "Ginza",
+  "Nihonbashi",
+  "Akasaka",
+  "Roppongi",
+  "Shibuya",
+  "Ebisu",
+  "Daikanyama",
+  "Kichijoji",
+  "Nishi-Shinjuku",
+  "Kabukicho",
+  "Asakusa",
+  "Ueno",
+  "Yanaka",
+  "Kanda",
+  "Otemachi",
+  "Shiba",
+  "Minami-Aoyama",
+  "Kita-Aoyama",
+  "Umeda",
+  "Namba",
+  "Shinsaibashi",
+  "Tenjin",
+  "Hakata-ekimae",
+  "Sakae",
+  "Meieki",
+  "Motomachi",
+  "Kita-Sanjo",
+  "Odori-nishi",
+  "Gion",
+  "Higashiyama",
+  "Shijo-dori",
+  "Kawaramachi",
+  "Sannomiya",
+  "Kitano-cho",
+  "Minatomirai",
+  "Kannai",
+  "Chuo",
+  "Honcho",
+  "Sakuragaoka",
+  "Midori-cho",
+  "Asahi-cho",
+  "Sakae-machi",
+  "Hon-machi",
+  "Nishiki",
+  "Izumi",
+  "Higashi",
+  "Nishi",
+  "Minami"
+]
+}"#;
+
+pub const DATA_JP_CITIES: &str = r#"
+{
+  "data": [
+  "Chiyoda-ku",
+  "Chuo-ku",
+  "Minato-ku",
+  "Shinjuku-ku",
+  "Shibuya-ku",
+  "Taito-ku",
+  "Setagaya-ku",
+  "Meguro-ku",
+  "Bunkyo-ku",
+  "Toshima-ku",
+  "Musashino-shi",
+  "Hachioji-shi",
+  "Yokohama-shi",
+  "Kawasaki-shi",
+  "Sagamihara-shi",
+  "Osaka-shi",
+  "Sakai-shi",
+  "Kyoto-shi",
+  "Kobe-shi",
+  "Nagoya-shi",
+  "Sapporo-shi",
+  "Sendai-shi",
+  "Fukuoka-shi",
+  "Kitakyushu-shi",
+  "Hiroshima-shi",
+  "Okayama-shi",
+  "Kumamoto-shi",
+  "Kagoshima-shi",
+  "Naha-shi",
+  "Niigata-shi",
+  "Shizuoka-shi",
+  "Hamamatsu-shi",
+  "Chiba-shi",
+  "Saitama-shi",
+  "Kanazawa-shi",
+  "Nagano-shi",
+  "Matsuyama-shi",
+  "Takamatsu-shi",
+  "Nara-shi",
+  "Utsunomiya-shi"
+]
+}"#;
+
+pub const DATA_JP_PREFECTURES: &str = r#"
+{
+  "data": [
+  "Hokkaido",
+  "Aomori",
+  "Iwate",
+  "Miyagi",
+  "Akita",
+  "Yamagata",
+  "Fukushima",
+  "Ibaraki",
+  "Tochigi",
+  "Gunma",
+  "Saitama",
+  "Chiba",
+  "Tokyo",
+  "Kanagawa",
+  "Niigata",
+  "Toyama",
+  "Ishikawa",
+  "Fukui",
+  "Yamanashi",
+  "Nagano",
+  "Gifu",
+  "Shizuoka",
+  "Aichi",
+  "Mie",
+  "Shiga",
+  "Kyoto",
+  "Osaka",
+  "Hyogo",
+  "Nara",
+  "Wakayama",
+  "Tottori",
+  "Shimane",
+  "Okayama",
+  "Hiroshima",
+  "Yamaguchi",
+  "Tokushima",
+  "Kagawa",
+  "Ehime",
+  "Kochi",
+  "Fukuoka",
+  "Saga",
+  "Nagasaki",
+  "Kumamoto",
+  "Oita",
+  "Miyazaki",
+  "Kagoshima",
+  "Okinawa"
+]
+}"#;
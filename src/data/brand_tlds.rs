@@ -0,0 +1,477 @@
+//! Module brand_tlds provides the TLDs which a company or organisation operates for its own use,
+//! taken from the Specification 13 (.brand) registry agreements in <https://www.icann.org/resources/registries/gtlds/v2/gtlds.json> and the TLDs
+//! of single operators listed in the script.
+// last updated Mon Oct 19 09:12:40 UTC 2026
+// this file is generated by the ./helpers/brand-tlds.sh script
+
+pub const DATA_BRAND_TLDS: [&str; 469] = [
+    "aaa",
+    "aarp",
+    "abb",
+    "abbott",
+    "abbvie",
+    "abc",
+    "able",
+    "accenture",
+    "aco",
+    "ads",
+    "aeg",
+    "aetna",
+    "afl",
+    "agakhan",
+    "aig",
+    "airbus",
+    "airtel",
+    "akdn",
+    "alibaba",
+    "alipay",
+    "allfinanz",
+    "allstate",
+    "ally",
+    "alstom",
+    "amazon",
+    "americanexpress",
+    "americanfamily",
+    "amex",
+    "amfam",
+    "amica",
+    "android",
+    "anquan",
+    "anz",
+    "aol",
+    "apple",
+    "aquarelle",
+    "aramco",
+    "arte",
+    "asda",
+    "athleta",
+    "audi",
+    "audible",
+    "auspost",
+    "author",
+    "aws",
+    "axa",
+    "azure",
+    "baidu",
+    "banamex",
+    "barclaycard",
+    "barclays",
+    "barefoot",
+    "baseball",
+    "bauhaus",
+    "bbc",
+    "bbt",
+    "bbva",
+    "bcg",
+    "beats",
+    "bestbuy",
+    "bharti",
+    "bing",
+    "blockbuster",
+    "bloomberg",
+    "bms",
+    "bmw",
+    "bnpparibas",
+    "boehringer",
+    "bofa",
+    "book",
+    "booking",
+    "bosch",
+    "bostik",
+    "bradesco",
+    "bridgestone",
+    "brother",
+    "buy",
+    "cal",
+    "call",
+    "calvinklein",
+    "canon",
+    "capitalone",
+    "case",
+    "catholic",
+    "cba",
+    "cbn",
+    "cbre",
+    "cern",
+    "cfa",
+    "chanel",
+    "channel",
+    "chase",
+    "chintai",
+    "chrome",
+    "cipriani",
+    "circle",
+    "cisco",
+    "citadel",
+    "citi",
+    "citic",
+    "clinique",
+    "clubmed",
+    "commbank",
+    "comsec",
+    "coupon",
+    "crown",
+    "crs",
+    "cuisinella",
+    "datsun",
+    "dclk",
+    "deal",
+    "dell",
+    "deloitte",
+    "delta",
+    "dhl",
+    "discover",
+    "dish",
+    "dnp",
+    "docs",
+    "dot",
+    "drive",
+    "dtv",
+    "dupont",
+    "dvag",
+    "dvr",
+    "edeka",
+    "emerck",
+    "epson",
+    "ericsson",
+    "erni",
+    "eurovision",
+    "extraspace",
+    "fage",
+    "fairwinds",
+    "farmers",
+    "fast",
+    "fedex",
+    "ferrari",
+    "ferrero",
+    "fidelity",
+    "fido",
+    "fire",
+    "firestone",
+    "firmdale",
+    "flickr",
+    "flir",
+    "foo",
+    "ford",
+    "fox",
+    "free",
+    "fresenius",
+    "frogans",
+    "frontier",
+    "ftr",
+    "fujitsu",
+    "gallo",
+    "gallup",
+    "gap",
+    "gbiz",
+    "gea",
+    "genting",
+    "george",
+    "ggee",
+    "gle",
+    "globo",
+    "gmail",
+    "gmo",
+    "gmx",
+    "godaddy",
+    "goldpoint",
+    "goodyear",
+    "goog",
+    "google",
+    "got",
+    "grainger",
+    "grocery",
+    "gucci",
+    "guge",
+    "hangout",
+    "hbo",
+    "hdfc",
+    "hdfcbank",
+    "hermes",
+    "hisamitsu",
+    "hitachi",
+    "hkt",
+    "homedepot",
+    "homegoods",
+    "homesense",
+    "honda",
+    "hot",
+    "hotels",
+    "hotmail",
+    "hsbc",
+    "hughes",
+    "hyatt",
+    "hyundai",
+    "ibm",
+    "icbc",
+    "ice",
+    "ieee",
+    "ifm",
+    "ikano",
+    "imamat",
+    "imdb",
+    "infiniti",
+    "intuit",
+    "ipiranga",
+    "ismaili",
+    "itau",
+    "itv",
+    "jaguar",
+    "java",
+    "jcb",
+    "jeep",
+    "jio",
+    "jll",
+    "jmp",
+    "jnj",
+    "jot",
+    "joy",
+    "jpmorgan",
+    "jprs",
+    "juniper",
+    "kddi",
+    "kerryhotels",
+    "kerryproperties",
+    "kfh",
+    "kia",
+    "kindle",
+    "komatsu",
+    "kpmg",
+    "kpn",
+    "kuokgroup",
+    "lacaixa",
+    "lamborghini",
+    "lamer",
+    "landrover",
+    "lanxess",
+    "lasalle",
+    "latrobe",
+    "lds",
+    "leclerc",
+    "lefrak",
+    "lego",
+    "lexus",
+    "lidl",
+    "like",
+    "lilly",
+    "lincoln",
+    "locus",
+    "lotte",
+    "lpl",
+    "lplfinancial",
+    "lundbeck",
+    "maif",
+    "man",
+    "mango",
+    "map",
+    "marriott",
+    "marshalls",
+    "mattel",
+    "mckinsey",
+    "merckmsd",
+    "microsoft",
+    "mini",
+    "mint",
+    "mit",
+    "mitsubishi",
+    "mlb",
+    "mls",
+    "mma",
+    "moi",
+    "monash",
+    "monster",
+    "mormon",
+    "moto",
+    "msd",
+    "mtn",
+    "mtr",
+    "nab",
+    "nba",
+    "nec",
+    "netbank",
+    "netflix",
+    "neustar",
+    "next",
+    "nextdirect",
+    "nexus",
+    "nfl",
+    "nhk",
+    "nico",
+    "nike",
+    "nikon",
+    "nissan",
+    "nissay",
+    "nokia",
+    "norton",
+    "now",
+    "nowtv",
+    "nra",
+    "ntt",
+    "obi",
+    "office",
+    "olayan",
+    "olayangroup",
+    "ollo",
+    "omega",
+    "open",
+    "oracle",
+    "orange",
+    "origins",
+    "otsuka",
+    "ott",
+    "ovh",
+    "panasonic",
+    "pay",
+    "pccw",
+    "pfizer",
+    "philips",
+    "pictet",
+    "pin",
+    "ping",
+    "pioneer",
+    "play",
+    "playstation",
+    "pnc",
+    "pohl",
+    "politie",
+    "praxi",
+    "prime",
+    "prod",
+    "prof",
+    "progressive",
+    "pru",
+    "prudential",
+    "pwc",
+    "quest",
+    "read",
+    "redumbrella",
+    "reliance",
+    "rexroth",
+    "richardli",
+    "ricoh",
+    "ril",
+    "rogers",
+    "room",
+    "rsvp",
+    "rwe",
+    "safe",
+    "safety",
+    "sakura",
+    "samsclub",
+    "samsung",
+    "sandvik",
+    "sandvikcoromant",
+    "sanofi",
+    "sap",
+    "sas",
+    "save",
+    "saxo",
+    "sbi",
+    "sbs",
+    "scb",
+    "schaeffler",
+    "schmidt",
+    "schwarz",
+    "search",
+    "seat",
+    "secure",
+    "seek",
+    "sener",
+    "seven",
+    "sew",
+    "sfr",
+    "shangrila",
+    "sharp",
+    "shell",
+    "shouji",
+    "silk",
+    "sina",
+    "sky",
+    "skype",
+    "sling",
+    "smart",
+    "smile",
+    "sncf",
+    "softbank",
+    "sohu",
+    "song",
+    "sony",
+    "soy",
+    "spot",
+    "stada",
+    "staples",
+    "star",
+    "statebank",
+    "statefarm",
+    "stc",
+    "stcgroup",
+    "suzuki",
+    "swatch",
+    "tab",
+    "talk",
+    "taobao",
+    "target",
+    "tatamotors",
+    "tdk",
+    "temasek",
+    "teva",
+    "thd",
+    "tiaa",
+    "tjmaxx",
+    "tjx",
+    "tkmaxx",
+    "tmall",
+    "toray",
+    "toshiba",
+    "total",
+    "toyota",
+    "travelers",
+    "travelersinsurance",
+    "trv",
+    "tui",
+    "tunes",
+    "tushu",
+    "tvs",
+    "ubank",
+    "ubs",
+    "unicom",
+    "uol",
+    "ups",
+    "vana",
+    "vanguard",
+    "verisign",
+    "vig",
+    "viking",
+    "virgin",
+    "visa",
+    "viva",
+    "vivo",
+    "volvo",
+    "walmart",
+    "walter",
+    "wanggou",
+    "weather",
+    "weatherchannel",
+    "weber",
+    "weibo",
+    "weir",
+    "williamhill",
+    "windows",
+    "wme",
+    "woodside",
+    "wow",
+    "wtc",
+    "xbox",
+    "xerox",
+    "xihuan",
+    "yahoo",
+    "yamaxun",
+    "yandex",
+    "yodobashi",
+    "you",
+    "youtube",
+    "yun",
+    "zappos",
+    "zara",
+    "zero",
+];
@@ -0,0 +1,461 @@
+//! Module countries maps the country names of the `countries` corpora dataset to ISO 3166-1 alpha-2
+//! codes, so generators that need a country can be called with either.
+
+/// ISO 3166-1 alpha-2 code and name of every country in the `countries` corpora dataset.
+pub const DATA_COUNTRY_CODES: [(&str, &str); 198] = [
+    ("AF", "Afghanistan"),
+    ("AL", "Albania"),
+    ("DZ", "Algeria"),
+    ("AD", "Andorra"),
+    ("AO", "Angola"),
+    ("AG", "Antigua & Barbuda"),
+    ("AR", "Argentina"),
+    ("AM", "Armenia"),
+    ("AU", "Australia"),
+    ("AT", "Austria"),
+    ("AZ", "Azerbaijan"),
+    ("BS", "Bahamas"),
+    ("BH", "Bahrain"),
+    ("BD", "Bangladesh"),
+    ("BB", "Barbados"),
+    ("BY", "Belarus"),
+    ("BE", "Belgium"),
+    ("BZ", "Belize"),
+    ("BJ", "Benin"),
+    ("BT", "Bhutan"),
+    ("BO", "Bolivia"),
+    ("BA", "Bosnia & Herzegovina"),
+    ("BW", "Botswana"),
+    ("BR", "Brazil"),
+    ("BN", "Brunei"),
+    ("BG", "Bulgaria"),
+    ("BF", "Burkina Faso"),
+    ("BI", "Burundi"),
+    ("KH", "Cambodia"),
+    ("CM", "Cameroon"),
+    ("CA", "Canada"),
+    ("CV", "Cape Verde"),
+    ("CF", "Central African Republic"),
+    ("TD", "Chad"),
+    ("CL", "Chile"),
+    ("CN", "China"),
+    ("CO", "Colombia"),
+    ("KM", "Comoros"),
+    ("CG", "Congo"),
+    ("CR", "Costa Rica"),
+    ("CI", "Cote D'Ivoire"),
+    ("HR", "Croatia"),
+    ("CU", "Cuba"),
+    ("CY", "Cyprus"),
+    ("CZ", "Czech Republic"),
+    ("CD", "Democratic Republic of the Congo"),
+    ("DK", "Denmark"),
+    ("DJ", "Djibouti"),
+    ("DM", "Dominica"),
+    ("DO", "Dominican Republic"),
+    ("TL", "East Timor"),
+    ("EC", "Ecuador"),
+    ("EG", "Egypt"),
+    ("SV", "El Salvador"),
+    ("GQ", "Equatorial Guinea"),
+    ("ER", "Eritrea"),
+    ("EE", "Estonia"),
+    ("SZ", "Eswatini"),
+    ("ET", "Ethiopia"),
+    ("FJ", "Fiji"),
+    ("FI", "Finland"),
+    ("FR", "France"),
+    ("GA", "Gabon"),
+    ("GM", "Gambia"),
+    ("GE", "Georgia"),
+    ("DE", "Germany"),
+    ("GH", "Ghana"),
+    ("GR", "Greece"),
+    ("GD", "Grenada"),
+    ("GT", "Guatemala"),
+    ("GN", "Guinea"),
+    ("GW", "Guinea-Bissau"),
+    ("GY", "Guyana"),
+    ("HT", "Haiti"),
+    ("HN", "Honduras"),
+    ("HU", "Hungary"),
+    ("IS", "Iceland"),
+    ("IN", "India"),
+    ("ID", "Indonesia"),
+    ("IR", "Iran"),
+    ("IQ", "Iraq"),
+    ("IE", "Ireland"),
+    ("IL", "Israel"),
+    ("IT", "Italy"),
+    ("JM", "Jamaica"),
+    ("JP", "Japan"),
+    ("JO", "Jordan"),
+    ("KZ", "Kazakhstan"),
+    ("KE", "Kenya"),
+    ("KI", "Kiribati"),
+    ("XK", "Kosovo"),
+    ("KW", "Kuwait"),
+    ("KG", "Kyrgyzstan"),
+    ("LA", "Laos"),
+    ("LV", "Latvia"),
+    ("LB", "Lebanon"),
+    ("LS", "Lesotho"),
+    ("LR", "Liberia"),
+    ("LY", "Libya"),
+    ("LI", "Liechtenstein"),
+    ("LT", "Lithuania"),
+    ("LU", "Luxembourg"),
+    ("MG", "Madagascar"),
+    ("MW", "Malawi"),
+    ("MY", "Malaysia"),
+    ("MV", "Maldives"),
+    ("ML", "Mali"),
+    ("MT", "Malta"),
+    ("MH", "Marshall Islands"),
+    ("MR", "Mauritania"),
+    ("MU", "Mauritius"),
+    ("MX", "Mexico"),
+    ("FM", "Micronesia"),
+    ("MD", "Moldova"),
+    ("MC", "Monaco"),
+    ("MN", "Mongolia"),
+    ("ME", "Montenegro"),
+    ("MA", "Morocco"),
+    ("MZ", "Mozambique"),
+    ("MM", "Myanmar"),
+    ("NA", "Namibia"),
+    ("NR", "Nauru"),
+    ("NP", "Nepal"),
+    ("NZ", "New Zealand"),
+    ("NI", "Nicaragua"),
+    ("NE", "Niger"),
+    ("NG", "Nigeria"),
+    ("KP", "North Korea"),
+    ("MK", "North Macedonia"),
+    ("NO", "Norway"),
+    ("OM", "Oman"),
+    ("PK", "Pakistan"),
+    ("PW", "Palau"),
+    ("PS", "Palestinian State"),
+    ("PA", "Panama"),
+    ("PG", "Papua New Guinea"),
+    ("PY", "Paraguay"),
+    ("PE", "Peru"),
+    ("PL", "Poland"),
+    ("PT", "Portugal"),
+    ("QA", "Qatar"),
+    ("RO", "Romania"),
+    ("RU", "Russia"),
+    ("RW", "Rwanda"),
+    ("WS", "Samoa"),
+    ("SM", "San Marino"),
+    ("ST", "Sao Tome & Principe"),
+    ("SA", "Saudi Arabia"),
+    ("SN", "Senegal"),
+    ("RS", "Serbia"),
+    ("SC", "Seychelles"),
+    ("SL", "Sierra Leone"),
+    ("SG", "Singapore"),
+    ("SK", "Slovakia"),
+    ("SI", "Slovenia"),
+    ("SB", "Solomon Islands"),
+    ("SO", "Somalia"),
+    ("ZA", "South Africa"),
+    ("KR", "South Korea"),
+    ("SS", "South Sudan"),
+    ("ES", "Spain"),
+    ("LK", "Sri Lanka"),
+    ("KN", "St. Kitts & Nevis"),
+    ("LC", "St. Lucia"),
+    ("VC", "St. Vincent & The Grenadines"),
+    ("SD", "Sudan"),
+    ("SR", "Suriname"),
+    ("SE", "Sweden"),
+    ("CH", "Switzerland"),
+    ("SY", "Syria"),
+    ("TW", "Taiwan"),
+    ("TJ", "Tajikistan"),
+    ("TZ", "Tanzania"),
+    ("TH", "Thailand"),
+    ("NL", "The Netherlands"),
+    ("PH", "The Philippines"),
+    ("TG", "Togo"),
+    ("TO", "Tonga"),
+    ("TT", "Trinidad & Tobago"),
+    ("TN", "Tunisia"),
+    ("TR", "Turkey"),
+    ("TM", "Turkmenistan"),
+    ("TV", "Tuvalu"),
+    ("UG", "Uganda"),
+    ("UA", "Ukraine"),
+    ("AE", "United Arab Emirates"),
+    ("GB", "United Kingdom"),
+    ("US", "United States Of America"),
+    ("UY", "Uruguay"),
+    ("UZ", "Uzbekistan"),
+    ("VU", "Vanuatu"),
+    ("VA", "Vatican City"),
+    ("VE", "Venezuela"),
+    ("VN", "Vietnam"),
+    ("EH", "Western Sahara"),
+    ("YE", "Yemen"),
+    ("ZM", "Zambia"),
+    ("ZW", "Zimbabwe"),
+];
+
+/// Approximate bounding box of the mainland of every country in the `countries` corpora dataset as
+/// `[min longitude, min latitude, max longitude, max latitude]`, the order GeoJSON uses. Remote
+/// islands and overseas territories are left out, boxes never cross the antimeridian.
+pub const DATA_COUNTRY_BOUNDS: [(&str, [f64; 4]); 198] = [
+    ("AF", [60.5, 29.3, 75.2, 38.5]),
+    ("AL", [19.3, 39.6, 21.1, 42.7]),
+    ("DZ", [-8.7, 19.0, 12.0, 37.1]),
+    ("AD", [1.4, 42.4, 1.8, 42.7]),
+    ("AO", [11.6, -18.0, 24.1, -4.4]),
+    ("AG", [-61.9, 17.0, -61.7, 17.7]),
+    ("AR", [-73.6, -55.1, -53.6, -21.8]),
+    ("AM", [43.4, 38.8, 46.6, 41.3]),
+    ("AU", [113.3, -43.6, 153.6, -10.7]),
+    ("AT", [9.5, 46.4, 17.2, 49.0]),
+    ("AZ", [44.8, 38.4, 50.4, 41.9]),
+    ("BS", [-79.0, 20.9, -72.7, 27.3]),
+    ("BH", [50.4, 25.8, 50.7, 26.3]),
+    ("BD", [88.0, 20.7, 92.7, 26.6]),
+    ("BB", [-59.7, 13.0, -59.4, 13.4]),
+    ("BY", [23.2, 51.3, 32.8, 56.2]),
+    ("BE", [2.5, 49.5, 6.4, 51.5]),
+    ("BZ", [-89.2, 15.9, -87.8, 18.5]),
+    ("BJ", [0.8, 6.2, 3.8, 12.4]),
+    ("BT", [88.8, 26.7, 92.1, 28.3]),
+    ("BO", [-69.6, -22.9, -57.5, -9.7]),
+    ("BA", [15.7, 42.6, 19.6, 45.3]),
+    ("BW", [20.0, -26.9, 29.4, -17.8]),
+    ("BR", [-74.0, -33.8, -34.8, 5.3]),
+    ("BN", [114.1, 4.0, 115.4, 5.0]),
+    ("BG", [22.4, 41.2, 28.6, 44.2]),
+    ("BF", [-5.5, 9.4, 2.4, 15.1]),
+    ("BI", [29.0, -4.5, 30.8, -2.3]),
+    ("KH", [102.3, 10.4, 107.6, 14.7]),
+    ("CM", [8.5, 1.7, 16.2, 13.1]),
+    ("CA", [-141.0, 41.7, -52.6, 83.1]),
+    ("CV", [-25.4, 14.8, -22.7, 17.2]),
+    ("CF", [14.4, 2.2, 27.5, 11.0]),
+    ("TD", [13.5, 7.4, 24.0, 23.5]),
+    ("CL", [-75.6, -55.9, -66.9, -17.5]),
+    ("CN", [73.7, 18.2, 134.8, 53.6]),
+    ("CO", [-79.0, -4.2, -66.9, 12.5]),
+    ("KM", [43.2, -12.4, 44.5, -11.4]),
+    ("CG", [11.1, -5.0, 18.6, 3.7]),
+    ("CR", [-85.9, 8.0, -82.6, 11.2]),
+    ("CI", [-8.6, 4.3, -2.5, 10.7]),
+    ("HR", [13.5, 42.4, 19.4, 46.6]),
+    ("CU", [-85.0, 19.8, -74.1, 23.3]),
+    ("CY", [32.3, 34.6, 34.6, 35.7]),
+    ("CZ", [12.1, 48.6, 18.9, 51.1]),
+    ("CD", [12.2, -13.5, 31.3, 5.4]),
+    ("DK", [8.0, 54.5, 15.2, 57.8]),
+    ("DJ", [41.7, 10.9, 43.4, 12.7]),
+    ("DM", [-61.5, 15.2, -61.2, 15.6]),
+    ("DO", [-72.0, 17.5, -68.3, 19.9]),
+    ("TL", [124.0, -9.5, 127.3, -8.1]),
+    ("EC", [-81.0, -5.0, -75.2, 1.7]),
+    ("EG", [24.7, 22.0, 36.9, 31.7]),
+    ("SV", [-90.1, 13.1, -87.7, 14.5]),
+    ("GQ", [8.4, 0.9, 11.4, 3.8]),
+    ("ER", [36.4, 12.4, 43.1, 18.0]),
+    ("EE", [21.8, 57.5, 28.2, 59.7]),
+    ("SZ", [30.8, -27.3, 32.1, -25.7]),
+    ("ET", [33.0, 3.4, 48.0, 14.9]),
+    ("FJ", [177.0, -19.2, 180.0, -16.0]),
+    ("FI", [20.6, 59.8, 31.6, 70.1]),
+    ("FR", [-5.1, 41.3, 9.6, 51.1]),
+    ("GA", [8.7, -4.0, 14.5, 2.3]),
+    ("GM", [-16.8, 13.1, -13.8, 13.8]),
+    ("GE", [40.0, 41.1, 46.7, 43.6]),
+    ("DE", [5.9, 47.3, 15.0, 55.1]),
+    ("GH", [-3.3, 4.7, 1.2, 11.2]),
+    ("GR", [19.4, 34.8, 28.3, 41.7]),
+    ("GD", [-61.8, 12.0, -61.6, 12.3]),
+    ("GT", [-92.2, 13.7, -88.2, 17.8]),
+    ("GN", [-15.1, 7.2, -7.6, 12.7]),
+    ("GW", [-16.7, 10.9, -13.6, 12.7]),
+    ("GY", [-61.4, 1.2, -56.5, 8.6]),
+    ("HT", [-74.5, 18.0, -71.6, 20.1]),
+    ("HN", [-89.4, 13.0, -83.1, 16.5]),
+    ("HU", [16.1, 45.7, 22.9, 48.6]),
+    ("IS", [-24.5, 63.3, -13.5, 66.6]),
+    ("IN", [68.1, 6.7, 97.4, 35.5]),
+    ("ID", [95.0, -11.0, 141.0, 6.1]),
+    ("IR", [44.0, 25.1, 63.3, 39.8]),
+    ("IQ", [38.8, 29.1, 48.6, 37.4]),
+    ("IE", [-10.5, 51.4, -6.0, 55.4]),
+    ("IL", [34.3, 29.5, 35.9, 33.3]),
+    ("IT", [6.6, 36.6, 18.5, 47.1]),
+    ("JM", [-78.4, 17.7, -76.2, 18.5]),
+    ("JP", [129.4, 31.0, 145.8, 45.5]),
+    ("JO", [34.9, 29.2, 39.3, 33.4]),
+    ("KZ", [46.5, 40.6, 87.4, 55.4]),
+    ("KE", [33.9, -4.7, 41.9, 5.0]),
+    ("KI", [172.6, -2.7, 176.9, 3.4]),
+    ("XK", [20.0, 41.9, 21.8, 43.3]),
+    ("KW", [46.6, 28.5, 48.4, 30.1]),
+    ("KG", [69.3, 39.2, 80.3, 43.3]),
+    ("LA", [100.1, 13.9, 107.6, 22.5]),
+    ("LV", [21.0, 55.7, 28.2, 58.1]),
+    ("LB", [35.1, 33.1, 36.6, 34.7]),
+    ("LS", [27.0, -30.7, 29.5, -28.6]),
+    ("LR", [-11.5, 4.3, -7.4, 8.6]),
+    ("LY", [9.3, 19.5, 25.2, 33.2]),
+    ("LI", [9.47, 47.05, 9.64, 47.27]),
+    ("LT", [21.0, 53.9, 26.8, 56.5]),
+    ("LU", [5.7, 49.4, 6.5, 50.2]),
+    ("MG", [43.2, -25.6, 50.5, -12.0]),
+    ("MW", [32.7, -17.1, 35.9, -9.4]),
+    ("MY", [99.6, 0.85, 119.3, 7.4]),
+    ("MV", [72.6, -0.7, 73.8, 7.1]),
+    ("ML", [-12.2, 10.1, 4.3, 25.0]),
+    ("MT", [14.2, 35.8, 14.6, 36.1]),
+    ("MH", [165.5, 4.6, 172.0, 14.6]),
+    ("MR", [-17.1, 14.7, -4.8, 27.3]),
+    ("MU", [57.3, -20.5, 57.8, -19.98]),
+    ("MX", [-117.1, 14.5, -86.7, 32.7]),
+    ("FM", [138.0, 1.0, 163.1, 10.1]),
+    ("MD", [26.6, 45.5, 30.1, 48.5]),
+    ("MC", [7.41, 43.72, 7.44, 43.75]),
+    ("MN", [87.7, 41.6, 119.9, 52.1]),
+    ("ME", [18.4, 41.85, 20.4, 43.6]),
+    ("MA", [-13.2, 27.7, -1.0, 35.9]),
+    ("MZ", [30.2, -26.9, 40.8, -10.5]),
+    ("MM", [92.2, 9.8, 101.2, 28.5]),
+    ("NA", [11.7, -29.0, 25.3, -17.0]),
+    ("NR", [166.9, -0.55, 166.96, -0.5]),
+    ("NP", [80.1, 26.3, 88.2, 30.4]),
+    ("NZ", [166.4, -47.3, 178.6, -34.4]),
+    ("NI", [-87.7, 10.7, -83.1, 15.0]),
+    ("NE", [0.2, 11.7, 16.0, 23.5]),
+    ("NG", [2.7, 4.3, 14.7, 13.9]),
+    ("KP", [124.2, 37.7, 130.7, 43.0]),
+    ("MK", [20.4, 40.85, 23.0, 42.4]),
+    ("NO", [4.6, 58.0, 31.1, 71.2]),
+    ("OM", [52.0, 16.6, 59.8, 26.4]),
+    ("PK", [60.9, 23.7, 77.8, 37.1]),
+    ("PW", [134.1, 6.9, 134.7, 7.7]),
+    ("PS", [34.2, 31.2, 35.6, 32.6]),
+    ("PA", [-83.1, 7.2, -77.2, 9.6]),
+    ("PG", [140.8, -10.7, 155.9, -1.3]),
+    ("PY", [-62.6, -27.6, -54.3, -19.3]),
+    ("PE", [-81.4, -18.4, -68.7, -0.04]),
+    ("PL", [14.1, 49.0, 24.2, 54.9]),
+    ("PT", [-9.5, 36.9, -6.2, 42.2]),
+    ("QA", [50.7, 24.5, 51.6, 26.2]),
+    ("RO", [20.2, 43.6, 29.7, 48.3]),
+    ("RU", [19.6, 41.2, 180.0, 81.9]),
+    ("RW", [28.9, -2.9, 30.9, -1.05]),
+    ("WS", [-172.8, -14.1, -171.4, -13.4]),
+    ("SM", [12.4, 43.89, 12.52, 43.99]),
+    ("ST", [6.45, -0.02, 7.5, 1.75]),
+    ("SA", [34.5, 16.3, 55.7, 32.2]),
+    ("SN", [-17.6, 12.3, -11.4, 16.7]),
+    ("RS", [18.8, 42.2, 23.0, 46.2]),
+    ("SC", [55.2, -4.8, 55.8, -4.3]),
+    ("SL", [-13.3, 6.9, -10.3, 10.0]),
+    ("SG", [103.6, 1.16, 104.1, 1.47]),
+    ("SK", [16.8, 47.7, 22.6, 49.6]),
+    ("SI", [13.4, 45.4, 16.6, 46.9]),
+    ("SB", [155.5, -11.9, 167.3, -6.6]),
+    ("SO", [41.0, -1.7, 51.4, 12.0]),
+    ("ZA", [16.5, -34.8, 32.9, -22.1]),
+    ("KR", [125.9, 33.1, 129.6, 38.6]),
+    ("SS", [23.9, 3.5, 35.3, 12.2]),
+    ("ES", [-9.4, 36.0, 3.3, 43.8]),
+    ("LK", [79.7, 5.9, 81.9, 9.8]),
+    ("KN", [-62.9, 17.1, -62.5, 17.4]),
+    ("LC", [-61.1, 13.7, -60.9, 14.1]),
+    ("VC", [-61.5, 12.6, -61.1, 13.4]),
+    ("SD", [21.8, 8.7, 38.6, 22.2]),
+    ("SR", [-58.1, 1.8, -54.0, 6.0]),
+    ("SE", [11.0, 55.3, 24.2, 69.1]),
+    ("CH", [5.95, 45.8, 10.5, 47.8]),
+    ("SY", [35.7, 32.3, 42.4, 37.3]),
+    ("TW", [120.0, 21.9, 122.0, 25.3]),
+    ("TJ", [67.3, 36.7, 75.2, 41.0]),
+    ("TZ", [29.3, -11.7, 40.4, -1.0]),
+    ("TH", [97.3, 5.6, 105.6, 20.5]),
+    ("NL", [3.3, 50.75, 7.2, 53.6]),
+    ("PH", [116.9, 4.6, 126.6, 21.1]),
+    ("TG", [-0.05, 6.1, 1.8, 11.1]),
+    ("TO", [-175.4, -21.5, -173.9, -18.6]),
+    ("TT", [-61.9, 10.0, -60.5, 11.4]),
+    ("TN", [7.5, 30.2, 11.6, 37.5]),
+    ("TR", [26.0, 35.8, 44.8, 42.1]),
+    ("TM", [52.4, 35.1, 66.7, 42.8]),
+    ("TV", [176.0, -10.8, 179.9, -5.6]),
+    ("UG", [29.6, -1.4, 35.0, 4.2]),
+    ("UA", [22.1, 44.4, 40.2, 52.4]),
+    ("AE", [51.6, 22.6, 56.4, 26.1]),
+    ("GB", [-8.2, 49.9, 1.8, 60.9]),
+    ("US", [-124.8, 24.5, -66.9, 49.4]),
+    ("UY", [-58.4, -35.0, -53.1, -30.1]),
+    ("UZ", [56.0, 37.2, 73.1, 45.6]),
+    ("VU", [166.5, -20.3, 170.2, -13.1]),
+    ("VA", [12.445, 41.9, 12.458, 41.907]),
+    ("VE", [-73.4, 0.6, -59.8, 12.2]),
+    ("VN", [102.1, 8.6, 109.5, 23.4]),
+    ("EH", [-17.1, 20.8, -8.7, 27.7]),
+    ("YE", [42.6, 12.6, 53.1, 19.0]),
+    ("ZM", [22.0, -18.1, 33.7, -8.2]),
+    ("ZW", [25.2, -22.4, 33.1, -15.6]),
+];
+
+/// Returns the ISO 3166-1 alpha-2 code for a country name from the `countries` dataset or for an
+/// alpha-2 code. The lookup is case-insensitive, `UK` is accepted as an alias for `GB`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::data::countries::country_code;
+/// assert_eq!(country_code("Germany"), Some("DE"));
+/// assert_eq!(country_code("de"), Some("DE"));
+/// assert_eq!(country_code("Atlantis"), None);
+/// ```
+pub fn country_code(name_or_code: &str) -> Option<&'static str> {
+    let input = name_or_code.trim();
+    if input.eq_ignore_ascii_case("UK") {
+        return Some("GB");
+    }
+    DATA_COUNTRY_CODES
+        .iter()
+        .find(|(code, name)| code.eq_ignore_ascii_case(input) || name.eq_ignore_ascii_case(input))
+        .map(|(code, _)| *code)
+}
+
+/// Returns the name of a country as used in the `countries` dataset for its alpha-2 code.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::data::countries::country_name;
+/// assert_eq!(country_name("FR"), Some("France"));
+/// ```
+pub fn country_name(code: &str) -> Option<&'static str> {
+    DATA_COUNTRY_CODES
+        .iter()
+        .find(|(c, _)| c.eq_ignore_ascii_case(code))
+        .map(|(_, name)| *name)
+}
+
+/// Returns the approximate bounding box of a country as `[min longitude, min latitude, max
+/// longitude, max latitude]`. The country can be given as name from the `countries` dataset or as
+/// alpha-2 code.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::data::countries::country_bounds;
+/// let [min_lon, min_lat, max_lon, max_lat] = country_bounds("Germany").unwrap();
+/// assert!(min_lon < 13.4 && 13.4 < max_lon && min_lat < 52.5 && 52.5 < max_lat);
+/// ```
+pub fn country_bounds(name_or_code: &str) -> Option<[f64; 4]> {
+    let code = country_code(name_or_code)?;
+    DATA_COUNTRY_BOUNDS
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, bounds)| *bounds)
+}
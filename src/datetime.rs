@@ -0,0 +1,126 @@
+//! Module datetime provides the small set of date helpers the generators need, without pulling in a
+//! date library. All timestamps are UTC seconds since the Unix epoch.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Current time in seconds since the Unix epoch.
+pub(crate) fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Convert days since the Unix epoch to a `(year, month, day)` date.
+/// Algorithm from <https://howardhinnant.github.io/date_algorithms.html>
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+/// `(year, month, day, hour, minute, second)` of a timestamp.
+pub(crate) fn parts(timestamp: i64) -> (i64, u32, u32, u32, u32, u32) {
+    let (y, m, d) = civil_from_days(timestamp.div_euclid(86_400));
+    let secs = timestamp.rem_euclid(86_400) as u32;
+    (y, m, d, secs / 3600, secs % 3600 / 60, secs % 60)
+}
+
+/// Format a timestamp as RFC 3339 date-time in UTC: `2023-11-14T22:13:20Z`.
+pub(crate) fn rfc3339(timestamp: i64) -> String {
+    let (y, m, d, h, mi, s) = parts(timestamp);
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", y, m, d, h, mi, s)
+}
+
+/// Format a timestamp in milliseconds as RFC 3339 date-time in UTC: `2023-11-14T22:13:20.123Z`.
+pub(crate) fn rfc3339_millis(timestamp_ms: i64) -> String {
+    let (y, m, d, h, mi, s) = parts(timestamp_ms.div_euclid(1000));
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        y,
+        m,
+        d,
+        h,
+        mi,
+        s,
+        timestamp_ms.rem_euclid(1000)
+    )
+}
+
+/// Format a timestamp in microseconds as RFC 3339 date-time in UTC:
+/// `2023-11-14T22:13:20.123456Z`.
+pub(crate) fn rfc3339_micros(timestamp_us: i64) -> String {
+    let seconds = rfc3339(timestamp_us.div_euclid(1_000_000));
+    format!(
+        "{}.{:06}Z",
+        &seconds[..seconds.len() - 1],
+        timestamp_us.rem_euclid(1_000_000)
+    )
+}
+
+/// Format a timestamp like the Common Log Format of web servers: `14/Nov/2023:22:13:20 +0000`.
+pub(crate) fn clf_date(timestamp: i64) -> String {
+    let (y, m, d, h, mi, s) = parts(timestamp);
+    format!(
+        "{:02}/{}/{:04}:{:02}:{:02}:{:02} +0000",
+        d,
+        MONTHS[m as usize - 1],
+        y,
+        h,
+        mi,
+        s
+    )
+}
+
+/// Format a timestamp as HTTP date (IMF-fixdate, RFC 9110): `Tue, 14 Nov 2023 22:13:20 GMT`.
+pub(crate) fn http_date(timestamp: i64) -> String {
+    const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    let (y, m, d, h, mi, s) = parts(timestamp);
+    // 1970-01-01 was a Thursday
+    let weekday = (timestamp.div_euclid(86_400) + 4).rem_euclid(7) as usize;
+    format!(
+        "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+        WEEKDAYS[weekday],
+        d,
+        MONTHS[m as usize - 1],
+        y,
+        h,
+        mi,
+        s
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(parts(1_700_000_000), (2023, 11, 14, 22, 13, 20));
+        assert_eq!(rfc3339(1_700_000_000), "2023-11-14T22:13:20Z");
+        assert_eq!(
+            rfc3339_millis(1_700_000_000_042),
+            "2023-11-14T22:13:20.042Z"
+        );
+        assert_eq!(
+            rfc3339_micros(1_700_000_000_000_042),
+            "2023-11-14T22:13:20.000042Z"
+        );
+        assert_eq!(clf_date(1_700_000_000), "14/Nov/2023:22:13:20 +0000");
+        assert_eq!(http_date(1_700_000_000), "Tue, 14 Nov 2023 22:13:20 GMT");
+        assert_eq!(http_date(784_111_777), "Sun, 06 Nov 1994 08:49:37 GMT");
+    }
+}
@@ -0,0 +1,536 @@
+//! Module domain provides domain names and hostnames built from the corpora words, with TLDs from
+//! the bundled IANA list (see `data::tlds`) filtered by category.
+//!
+//! Generated hostnames are always valid according to RFC 1123: labels of at most 63 letters,
+//! digits and inner dashes, at most 253 characters in total. Internationalized TLDs are written in
+//! Punycode (`xn--p1ai`) unless Unicode rendering is requested.
+
+use std::sync::OnceLock;
+
+use rand::Rng;
+
+use crate::context::safe_mode;
+use crate::data::brand_tlds::DATA_BRAND_TLDS;
+use crate::data::{get_words, pick_word};
+
+/// Datasets the labels of generated domains are taken from.
+const LABEL_DATASETS: [&str; 11] = [
+    "objects",
+    "moods",
+    "fruits",
+    "herbs",
+    "gemstones",
+    "metals",
+    "flowers",
+    "vegetables",
+    "spices",
+    "cats",
+    "dinosaurs",
+];
+
+/// Common names of hosts and services, used for subdomains next to corpora words.
+const SUBDOMAINS: [&str; 16] = [
+    "www", "api", "app", "mail", "smtp", "cdn", "static", "img", "dev", "staging", "test", "eu",
+    "us", "admin", "portal", "vpn",
+];
+
+/// Second-level domains reserved for documentation by RFC 2606.
+const RESERVED_DOMAINS: [&str; 3] = ["example.com", "example.org", "example.net"];
+
+/// TLDs reserved for testing and documentation by RFC 2606 and RFC 6761, which never resolve.
+const RESERVED_TLDS: [&str; 3] = ["test", "example", "invalid"];
+
+/// TLDs for internet infrastructure and sponsored TLDs which only eligible organisations can
+/// register, like the US government and military, treaty organisations or the members of an
+/// industry. The sponsored `asia` and `tel` are generic, anyone can register them.
+const RESTRICTED_TLDS: [&str; 13] = [
+    "aero", "arpa", "cat", "coop", "edu", "gov", "int", "jobs", "mil", "museum", "post", "travel",
+    "xxx",
+];
+
+/// Category of a top-level domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TldCategory {
+    /// Two-letter country code TLDs like `de` or `uk`.
+    CountryCode,
+    /// Generic TLDs open to everyone, like `com`, `org` or `berlin`.
+    Generic,
+    /// TLDs operated by a single company for its own use, like `google` or `docs`, see
+    /// [`crate::data::brand_tlds`].
+    Brand,
+    /// Internationalized TLDs in Punycode, like `xn--p1ai` (`рф`).
+    Idn,
+    /// Infrastructure and restricted TLDs like `arpa`, `gov`, `mil` or `edu`, which are not used
+    /// unless asked for.
+    Restricted,
+}
+
+/// Returns the category of `tld`, or `None` if it is not in the IANA TLD list.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::domain::{tld_category, TldCategory};
+/// assert_eq!(tld_category("de"), Some(TldCategory::CountryCode));
+/// assert_eq!(tld_category("com"), Some(TldCategory::Generic));
+/// assert_eq!(tld_category("google"), Some(TldCategory::Brand));
+/// assert_eq!(tld_category("xn--p1ai"), Some(TldCategory::Idn));
+/// assert_eq!(tld_category("mil"), Some(TldCategory::Restricted));
+/// assert_eq!(tld_category("notatld"), None);
+/// ```
+pub fn tld_category(tld: &str) -> Option<TldCategory> {
+    let tld = tld.to_ascii_lowercase();
+    if !iana_tlds().contains(&tld) {
+        return None;
+    }
+    Some(categorize(&tld))
+}
+
+/// The IANA TLD list, parsed once.
+fn iana_tlds() -> &'static [String] {
+    static TLDS: OnceLock<Vec<String>> = OnceLock::new();
+    TLDS.get_or_init(|| get_words("tlds"))
+}
+
+/// Category of a TLD known to be in the IANA list.
+fn categorize(tld: &str) -> TldCategory {
+    if tld.starts_with("xn--") {
+        TldCategory::Idn
+    } else if tld.len() == 2 {
+        TldCategory::CountryCode
+    } else if RESTRICTED_TLDS.contains(&tld) {
+        TldCategory::Restricted
+    } else if DATA_BRAND_TLDS.contains(&tld) {
+        TldCategory::Brand
+    } else {
+        TldCategory::Generic
+    }
+}
+
+/// Returns every TLD of the IANA list in one of the `categories`, in lowercase.
+pub fn tlds(categories: &[TldCategory]) -> Vec<String> {
+    iana_tlds()
+        .iter()
+        .filter(|tld| categories.contains(&categorize(tld)))
+        .cloned()
+        .collect()
+}
+
+/// Options for [`gen_domain_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainOptions {
+    subdomains: usize,
+    categories: Vec<TldCategory>,
+    unicode: bool,
+    safe: bool,
+}
+
+impl Default for DomainOptions {
+    /// No subdomains, generic and country code TLDs, IDN TLDs in Punycode.
+    fn default() -> Self {
+        DomainOptions {
+            subdomains: 0,
+            categories: vec![TldCategory::Generic, TldCategory::CountryCode],
+            unicode: false,
+            safe: false,
+        }
+    }
+}
+
+impl DomainOptions {
+    /// Number of labels in front of the registered domain, e.g. `2` for `api.eu.example.com`.
+    pub fn subdomains(mut self, subdomains: usize) -> Self {
+        self.subdomains = subdomains;
+        self
+    }
+
+    /// Only use TLDs of these categories. An empty list falls back to the default categories.
+    pub fn categories(mut self, categories: &[TldCategory]) -> Self {
+        self.categories = categories.to_vec();
+        self
+    }
+
+    /// Render IDN TLDs in Unicode (`рф`) instead of Punycode (`xn--p1ai`). Unicode domains are not
+    /// valid RFC 1123 hostnames.
+    pub fn unicode(mut self, unicode: bool) -> Self {
+        self.unicode = unicode;
+        self
+    }
+
+    /// Only use the reserved names of RFC 2606 and RFC 6761, see [`is_reserved`]. The TLD
+    /// categories are ignored. Safe mode is always on while it is enabled globally with
+    /// [`crate::context::set_safe_mode`].
+    pub fn safe(mut self, safe: bool) -> Self {
+        self.safe = safe;
+        self
+    }
+}
+
+/// Returns `true` if `domain` is or belongs to a name reserved by RFC 2606 and RFC 6761:
+/// `example.com`, `example.org`, `example.net` and everything under the `.test`, `.example` and
+/// `.invalid` TLDs.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::domain::is_reserved;
+/// assert!(is_reserved("www.example.com"));
+/// assert!(is_reserved("amber-fig.test"));
+/// assert!(!is_reserved("example.de"));
+/// ```
+pub fn is_reserved(domain: &str) -> bool {
+    let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+    let tld = domain.rsplit('.').next().unwrap_or_default();
+    RESERVED_TLDS.contains(&tld)
+        || RESERVED_DOMAINS
+            .iter()
+            .any(|reserved| domain == *reserved || domain.ends_with(&format!(".{}", reserved)))
+}
+
+/// ASCII replacement for the accented letters found in the corpora datasets.
+fn fold_letter(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'ä' | 'Ä' | 'æ' => "ae",
+        'ö' | 'Ö' | 'ø' => "oe",
+        'ü' | 'Ü' => "ue",
+        'ß' => "ss",
+        'á' | 'à' | 'â' | 'ã' | 'å' => "a",
+        'é' | 'è' | 'ê' | 'ë' | 'É' => "e",
+        'í' | 'ì' | 'î' | 'ï' => "i",
+        'ó' | 'ò' | 'ô' | 'õ' => "o",
+        'ú' | 'ù' | 'û' => "u",
+        'ç' => "c",
+        'ñ' => "n",
+        _ => return None,
+    };
+    Some(folded)
+}
+
+/// Turn `name` into a valid domain label: lowercase ASCII letters and digits, words separated by
+/// dashes and at most 63 characters. Returns an empty string if nothing is left.
+pub(crate) fn to_label(name: &str) -> String {
+    let mut label = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            label.push(c.to_ascii_lowercase());
+        } else if let Some(folded) = fold_letter(c) {
+            label.push_str(folded);
+        } else if !label.is_empty() && !label.ends_with('-') {
+            label.push('-');
+        }
+    }
+    label.truncate(63);
+    label.trim_end_matches('-').to_string()
+}
+
+/// A label made of one or two corpora words, like `amber`, `amberfig` or `amber-fig`.
+fn gen_label(rnd: &mut impl Rng) -> String {
+    let pattern = rnd.random_range(0..3);
+    let first = gen_word_label(rnd);
+    let label = match pattern {
+        0 => first,
+        1 => format!("{}{}", first, gen_word_label(rnd).replace('-', "")),
+        _ => format!("{}-{}", first, gen_word_label(rnd)),
+    };
+    let label = to_label(&label);
+    if label.is_empty() {
+        return String::from("example");
+    }
+    label
+}
+
+/// A single corpora word as label.
+fn gen_word_label(rnd: &mut impl Rng) -> String {
+    let dataset = LABEL_DATASETS[rnd.random_range(0..LABEL_DATASETS.len())];
+    to_label(&pick_word(dataset, rnd))
+}
+
+/// Returns `true` if `hostname` is a valid hostname according to RFC 1123: dot separated labels
+/// of 1 to 63 ASCII letters, digits and dashes which neither start nor end with a dash, and at
+/// most 253 characters in total.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::domain::is_valid_hostname;
+/// assert!(is_valid_hostname("api.amber-fig.com"));
+/// assert!(!is_valid_hostname("-amber.com"));
+/// assert!(!is_valid_hostname("amber..com"));
+/// ```
+pub fn is_valid_hostname(hostname: &str) -> bool {
+    !hostname.is_empty()
+        && hostname.len() <= 253
+        && hostname.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+/// Returns a domain name built with `options`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::domain::{gen_domain_with, DomainOptions, TldCategory};
+/// let options = DomainOptions::default()
+///     .subdomains(2)
+///     .categories(&[TldCategory::CountryCode]);
+/// let domain: String = gen_domain_with(&options);
+/// // domain => "api.ruby.amber-fig.de"
+/// assert_eq!(domain.split('.').count(), 4);
+/// ```
+pub fn gen_domain_with(options: &DomainOptions) -> String {
+    let mut rnd = rand::rng();
+    let mut labels: Vec<String> = (0..options.subdomains)
+        .map(|_| {
+            if rnd.random_bool(0.5) {
+                SUBDOMAINS[rnd.random_range(0..SUBDOMAINS.len())].to_string()
+            } else {
+                gen_label(&mut rnd)
+            }
+        })
+        .collect();
+
+    if options.safe || safe_mode() {
+        if rnd.random_bool(0.5) {
+            labels.push(RESERVED_DOMAINS[rnd.random_range(0..RESERVED_DOMAINS.len())].to_string());
+        } else {
+            labels.push(gen_label(&mut rnd));
+            labels.push(RESERVED_TLDS[rnd.random_range(0..RESERVED_TLDS.len())].to_string());
+        }
+        return join_labels(labels);
+    }
+
+    let categories = if options.categories.is_empty() {
+        DomainOptions::default().categories
+    } else {
+        options.categories.clone()
+    };
+    let tlds = tlds(&categories);
+    let tld = &tlds[rnd.random_range(0..tlds.len())];
+    labels.push(gen_label(&mut rnd));
+    labels.push(tld.clone());
+    let mut domain = join_labels(labels);
+
+    if options.unicode {
+        if let Some(unicode) = tld.strip_prefix("xn--").and_then(punycode_decode) {
+            domain.truncate(domain.len() - tld.len());
+            domain.push_str(&unicode);
+        }
+    }
+    domain
+}
+
+/// Join `labels` with dots, dropping subdomains from the front until the name fits into 253
+/// characters. The last two labels are always kept.
+fn join_labels(mut labels: Vec<String>) -> String {
+    while labels.len() > 2 && labels.join(".").len() > 253 {
+        labels.remove(0);
+    }
+    labels.join(".")
+}
+
+/// Returns a hostname with one to three subdomains, like `www.amber-fig.com`. The hostname is
+/// always valid according to RFC 1123, see [`is_valid_hostname`].
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::domain::{gen_hostname, is_valid_hostname};
+/// let hostname: String = gen_hostname();
+/// // hostname => "cdn.eu.amberfig.com"
+/// assert!(is_valid_hostname(&hostname));
+/// ```
+pub fn gen_hostname() -> String {
+    let subdomains = rand::rng().random_range(1..=3);
+    gen_domain_with(&DomainOptions::default().subdomains(subdomains))
+}
+
+/// Returns `domain` with every Punycode label (`xn--...`) decoded to Unicode. Labels which are not
+/// valid Punycode are returned unchanged.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::domain::to_unicode;
+/// assert_eq!(to_unicode("amber.xn--p1ai"), "amber.рф");
+/// ```
+pub fn to_unicode(domain: &str) -> String {
+    domain
+        .split('.')
+        .map(|label| match label.strip_prefix("xn--") {
+            Some(encoded) => punycode_decode(encoded).unwrap_or_else(|| label.to_string()),
+            None => label.to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+/// Decode a Punycode string (RFC 3492) without the `xn--` prefix.
+fn punycode_decode(input: &str) -> Option<String> {
+    const BASE: u32 = 36;
+    const T_MIN: u32 = 1;
+    const T_MAX: u32 = 26;
+
+    let (basic, extended) = match input.rfind('-') {
+        Some(index) => (&input[..index], &input[index + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() {
+        return None;
+    }
+    let mut output: Vec<char> = basic.chars().collect();
+    let mut n: u32 = 128;
+    let mut i: u32 = 0;
+    let mut bias: u32 = 72;
+    let mut digits = extended.bytes().peekable();
+
+    while digits.peek().is_some() {
+        let old_i = i;
+        let mut weight: u32 = 1;
+        let mut k = BASE;
+        loop {
+            let digit = match digits.next()? {
+                c @ b'a'..=b'z' => u32::from(c - b'a'),
+                c @ b'A'..=b'Z' => u32::from(c - b'A'),
+                c @ b'0'..=b'9' => u32::from(c - b'0') + 26,
+                _ => return None,
+            };
+            i = i.checked_add(digit.checked_mul(weight)?)?;
+            let t = if k <= bias {
+                T_MIN
+            } else if k >= bias + T_MAX {
+                T_MAX
+            } else {
+                k - bias
+            };
+            if digit < t {
+                break;
+            }
+            weight = weight.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+        let length = output.len() as u32 + 1;
+        bias = adapt_bias(i - old_i, length, old_i == 0);
+        n = n.checked_add(i / length)?;
+        i %= length;
+        output.insert(i as usize, char::from_u32(n)?);
+        i += 1;
+    }
+    Some(output.into_iter().collect())
+}
+
+/// Bias adaptation function of RFC 3492, section 6.1.
+fn adapt_bias(delta: u32, length: u32, first: bool) -> u32 {
+    let mut delta = if first { delta / 700 } else { delta / 2 };
+    delta += delta / length;
+    let mut k = 0;
+    while delta > ((36 - 1) * 26) / 2 {
+        delta /= 36 - 1;
+        k += 36;
+    }
+    k + (36 * delta) / (delta + 38)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tld_categories() {
+        assert!(
+            DATA_BRAND_TLDS
+                .iter()
+                .all(|tld| tld_category(tld).is_some())
+        );
+        let country_codes = tlds(&[TldCategory::CountryCode]);
+        assert!(country_codes.iter().all(|tld| tld.len() == 2));
+        assert!(country_codes.contains(&"de".to_string()));
+        let idn = tlds(&[TldCategory::Idn]);
+        assert!(idn.iter().all(|tld| tld.starts_with("xn--")));
+        let generic = tlds(&[TldCategory::Generic]);
+        for brand in [
+            "google",
+            "bestbuy",
+            "homedepot",
+            "samsclub",
+            "booking",
+            "weibo",
+            "nowtv",
+            "progressive",
+            "docs",
+            "foo",
+            "audible",
+            "bmw",
+        ] {
+            assert_eq!(tld_category(brand), Some(TldCategory::Brand), "{}", brand);
+            assert!(!generic.contains(&brand.to_string()));
+        }
+        assert!(
+            RESTRICTED_TLDS
+                .iter()
+                .all(|tld| tld_category(tld).is_some())
+        );
+        assert!(
+            RESTRICTED_TLDS
+                .iter()
+                .all(|tld| !generic.contains(&tld.to_string()))
+        );
+        assert_eq!(
+            tlds(&[TldCategory::Restricted]).len(),
+            RESTRICTED_TLDS.len()
+        );
+    }
+
+    #[test]
+    fn test_punycode() {
+        assert_eq!(punycode_decode("p1ai").unwrap(), "рф");
+        assert_eq!(
+            punycode_decode("vermgensberater-ctb").unwrap(),
+            "vermögensberater"
+        );
+        assert_eq!(punycode_decode("mnchen-3ya").unwrap(), "münchen");
+        assert!(punycode_decode("!").is_none());
+        assert_eq!(to_unicode("www.xn--mnchen-3ya.de"), "www.münchen.de");
+    }
+
+    #[test]
+    fn test_gen_domain_with() {
+        for _ in 0..100 {
+            let hostname = gen_hostname();
+            assert!(is_valid_hostname(&hostname), "{}", hostname);
+            let labels = hostname.split('.').count();
+            assert!((3..=5).contains(&labels), "{}", hostname);
+
+            let domain = gen_domain_with(&DomainOptions::default().subdomains(10));
+            assert!(is_valid_hostname(&domain), "{}", domain);
+            let tld = domain.rsplit('.').next().unwrap();
+            assert!(
+                matches!(
+                    tld_category(tld),
+                    Some(TldCategory::Generic | TldCategory::CountryCode)
+                ),
+                "{}",
+                domain
+            );
+
+            let tld = gen_domain_with(&DomainOptions::default().categories(&[TldCategory::Brand]));
+            let tld = tld.rsplit('.').next().unwrap();
+            assert_eq!(tld_category(tld), Some(TldCategory::Brand));
+
+            let idn = gen_domain_with(
+                &DomainOptions::default()
+                    .categories(&[TldCategory::Idn])
+                    .unicode(true),
+            );
+            assert!(!idn.contains("xn--"), "{}", idn);
+        }
+        for _ in 0..50 {
+            let domain = gen_domain_with(&DomainOptions::default().subdomains(2).safe(true));
+            assert!(is_reserved(&domain), "{}", domain);
+            assert!(is_valid_hostname(&domain), "{}", domain);
+        }
+        assert!(!is_reserved("example.com.de"));
+        assert!(!is_reserved("myexample.com"));
+        assert_eq!(to_label("Christmas Ornament"), "christmas-ornament");
+        assert_eq!(to_label("Crème Brûlée"), "creme-brulee");
+        assert_eq!(to_label("&"), "");
+    }
+}
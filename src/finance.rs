@@ -0,0 +1,6 @@
+//! Module finance provides generators for payment, banking and money data.
+
+pub mod bank;
+pub mod card;
+pub mod currency;
+pub mod transaction;
@@ -0,0 +1,297 @@
+//! Module bank provides IBANs, BIC/SWIFT codes and national bank account formats (US ABA routing
+//! numbers, UK sort codes) with valid check digits.
+//!
+//! Countries can be given as ISO 3166-1 alpha-2 code (`DE`) or by their name in the `countries`
+//! corpora dataset (`Germany`), see [`crate::data::countries::country_code`].
+
+use rand::Rng;
+
+use crate::checksum::{aba_check_digit, iban_check_digits};
+use crate::data::countries::country_code;
+use crate::id::random_digits;
+
+/// BBAN structure per IBAN country as in the SWIFT IBAN registry: `n` = digits, `a` = uppercase
+/// letters, `c` = uppercase letters and digits, together with the total IBAN length.
+const IBAN_FORMATS: [(&str, &str, usize); 73] = [
+    ("AD", "4n4n12c", 24),
+    ("AE", "3n16n", 23),
+    ("AL", "8n16c", 28),
+    ("AT", "5n11n", 20),
+    ("AZ", "4a20c", 28),
+    ("BA", "3n3n8n2n", 20),
+    ("BE", "3n7n2n", 16),
+    ("BG", "4a4n2n8c", 22),
+    ("BH", "4a14c", 22),
+    ("BR", "8n5n10n1a1c", 29),
+    ("BY", "4c4n16c", 28),
+    ("CH", "5n12c", 21),
+    ("CR", "4n14n", 22),
+    ("CY", "3n5n16c", 28),
+    ("CZ", "4n6n10n", 24),
+    ("DE", "8n10n", 22),
+    ("DK", "4n9n1n", 18),
+    ("DO", "4c20n", 28),
+    ("EE", "2n2n11n1n", 20),
+    ("EG", "4n4n17n", 29),
+    ("ES", "4n4n1n1n10n", 24),
+    ("FI", "3n11n", 18),
+    ("FO", "4n9n1n", 18),
+    ("FR", "5n5n11c2n", 27),
+    ("GB", "4a6n8n", 22),
+    ("GE", "2a16n", 22),
+    ("GI", "4a15c", 23),
+    ("GL", "4n9n1n", 18),
+    ("GR", "3n4n16c", 27),
+    ("GT", "4c20c", 28),
+    ("HR", "7n10n", 21),
+    ("HU", "3n4n1n15n1n", 28),
+    ("IE", "4a6n8n", 22),
+    ("IL", "3n3n13n", 23),
+    ("IQ", "4a3n12n", 23),
+    ("IS", "4n2n6n10n", 26),
+    ("IT", "1a5n5n12c", 27),
+    ("JO", "4a4n18c", 30),
+    ("KW", "4a22c", 30),
+    ("KZ", "3n13c", 20),
+    ("LB", "4n20c", 28),
+    ("LC", "4a24c", 32),
+    ("LI", "5n12c", 21),
+    ("LT", "5n11n", 20),
+    ("LU", "3n13c", 20),
+    ("LV", "4a13c", 21),
+    ("MC", "5n5n11c2n", 27),
+    ("MD", "2c18c", 24),
+    ("ME", "3n13n2n", 22),
+    ("MK", "3n10c2n", 19),
+    ("MR", "5n5n11n2n", 27),
+    ("MT", "4a5n18c", 31),
+    ("MU", "4a2n2n12n3n3a", 30),
+    ("NL", "4a10n", 18),
+    ("NO", "4n6n1n", 15),
+    ("PK", "4a16c", 24),
+    ("PL", "8n16n", 28),
+    ("PS", "4a21c", 29),
+    ("PT", "4n4n11n2n", 25),
+    ("QA", "4a21c", 29),
+    ("RO", "4a16c", 24),
+    ("RS", "3n13n2n", 22),
+    ("SA", "2n18c", 24),
+    ("SC", "4a2n2n16n3a", 31),
+    ("SE", "3n16n1n", 24),
+    ("SI", "5n8n2n", 19),
+    ("SK", "4n6n10n", 24),
+    ("SM", "1a5n5n12c", 27),
+    ("ST", "4n4n11n2n", 25),
+    ("SV", "4a20n", 28),
+    ("TL", "3n14n2n", 23),
+    ("TN", "2n3n13n2n", 24),
+    ("XK", "4n10n2n", 20),
+];
+
+/// First two digits of ABA routing numbers: Federal Reserve districts 01-12, thrift institutions
+/// 21-32, electronic transactions 61-72 and traveler's cheques 80.
+const ABA_PREFIXES: [u32; 37] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 61, 62,
+    63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 80,
+];
+
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const ALPHANUMERIC: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Returns the ISO country codes [`gen_iban`] supports.
+pub fn iban_countries() -> Vec<&'static str> {
+    IBAN_FORMATS.iter().map(|(code, _, _)| *code).collect()
+}
+
+/// Generate random characters following a registry structure like `4a6n8n`.
+fn gen_structured(structure: &str, rnd: &mut impl Rng) -> String {
+    let mut out = String::new();
+    let mut count = 0;
+    for c in structure.chars() {
+        if let Some(d) = c.to_digit(10) {
+            count = count * 10 + d as usize;
+            continue;
+        }
+        let alphabet = match c {
+            'n' => {
+                out.push_str(&random_digits(rnd, count));
+                count = 0;
+                continue;
+            }
+            'a' => LETTERS,
+            _ => ALPHANUMERIC,
+        };
+        for _ in 0..count {
+            out.push(char::from(alphabet[rnd.random_range(0..alphabet.len())]));
+        }
+        count = 0;
+    }
+    out
+}
+
+/// Returns an IBAN for `country` with the country's length and BBAN structure and valid MOD 97
+/// check digits. `country` is an ISO code or a name from the `countries` dataset. Returns `None`
+/// for countries that don't use IBANs, see [`iban_countries`].
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_iban;
+/// use fakedata_generator::checksum::iban_valid;
+/// let iban: String = gen_iban("Germany").unwrap();
+/// // iban => "DE44500105175407324931"
+/// assert_eq!(iban.len(), 22);
+/// assert!(iban_valid(&iban));
+/// assert_eq!(gen_iban("United States Of America"), None);
+/// ```
+pub fn gen_iban(country: &str) -> Option<String> {
+    // territories like the Faroe Islands (`FO`) aren't part of the countries dataset
+    let code = country_code(country).unwrap_or(country);
+    let (code, structure, _) = IBAN_FORMATS
+        .iter()
+        .find(|(c, _, _)| c.eq_ignore_ascii_case(code))?;
+    let mut rnd = rand::rng();
+    let bban = gen_structured(structure, &mut rnd);
+    Some(format!(
+        "{}{}{}",
+        code,
+        iban_check_digits(code, &bban),
+        bban
+    ))
+}
+
+/// Format an IBAN in groups of four characters as it is usually printed.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::finance::bank::format_iban;
+/// assert_eq!(format_iban("GB29NWBK60161331926819"), "GB29 NWBK 6016 1331 9268 19");
+/// ```
+pub fn format_iban(iban: &str) -> String {
+    iban.chars()
+        .collect::<Vec<char>>()
+        .chunks(4)
+        .map(|chunk| chunk.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Returns a BIC (SWIFT code) for `country`: four letters bank code, country code, two
+/// characters location code and, randomly, a three characters branch code. Returns `None` for
+/// unknown countries.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::finance::bank::gen_bic;
+/// let bic: String = gen_bic("FR").unwrap();
+/// // bic => "QWBZFRP3" or "QWBZFRP3XXX"
+/// assert_eq!(&bic[4..6], "FR");
+/// ```
+pub fn gen_bic(country: &str) -> Option<String> {
+    let code = country_code(country)?;
+    let mut rnd = rand::rng();
+    let bank = gen_structured("4a", &mut rnd);
+    let location = gen_structured("2c", &mut rnd);
+    let branch = match rnd.random_range(0..3) {
+        0 => String::new(),
+        1 => "XXX".to_string(),
+        _ => gen_structured("3c", &mut rnd),
+    };
+    Some(format!("{}{}{}{}", bank, code, location, branch))
+}
+
+/// Returns a US ABA routing number with a valid check digit.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::finance::bank::gen_aba_routing_number;
+/// use fakedata_generator::checksum::aba_valid;
+/// let routing: String = gen_aba_routing_number();
+/// // routing => "021000021"
+/// assert!(aba_valid(&routing));
+/// ```
+pub fn gen_aba_routing_number() -> String {
+    let mut rnd = rand::rng();
+    let prefix = ABA_PREFIXES[rnd.random_range(0..ABA_PREFIXES.len())];
+    let mut routing = format!("{:02}{}", prefix, gen_structured("6n", &mut rnd));
+    routing.push(char::from(b'0' + aba_check_digit(&routing)));
+    routing
+}
+
+/// A UK bank account made of a sort code and an 8 digit account number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UkBankAccount {
+    /// Sort code formatted as `NN-NN-NN`.
+    pub sort_code: String,
+    /// 8 digit account number.
+    pub account_number: String,
+}
+
+/// Returns a UK sort code and account number.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::finance::bank::gen_uk_bank_account;
+/// let account = gen_uk_bank_account();
+/// // account.sort_code => "60-16-13"
+/// // account.account_number => "31926819"
+/// assert_eq!(account.sort_code.len(), 8);
+/// ```
+pub fn gen_uk_bank_account() -> UkBankAccount {
+    let mut rnd = rand::rng();
+    let sort = gen_structured("6n", &mut rnd);
+    UkBankAccount {
+        sort_code: format!("{}-{}-{}", &sort[0..2], &sort[2..4], &sort[4..6]),
+        account_number: gen_structured("8n", &mut rnd),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum::{aba_valid, iban_valid};
+    use crate::data::countries::DATA_COUNTRY_CODES;
+
+    #[test]
+    fn test_iban_formats_match_length() {
+        for (code, structure, length) in IBAN_FORMATS {
+            let mut rnd = rand::rng();
+            assert_eq!(
+                gen_structured(structure, &mut rnd).len() + 4,
+                length,
+                "{}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_gen_iban() {
+        for code in iban_countries() {
+            for _ in 0..10 {
+                let iban = gen_iban(code).unwrap();
+                assert!(iban.starts_with(code));
+                assert!(iban_valid(&iban), "{}", iban);
+            }
+        }
+        // every IBAN country that is part of the countries dataset can be selected by its name
+        for (code, name) in DATA_COUNTRY_CODES {
+            let iban = gen_iban(name);
+            assert_eq!(iban.is_some(), iban_countries().contains(&code), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_bic_and_national_formats() {
+        let bic = gen_bic("United Kingdom").unwrap();
+        assert!(bic.len() == 8 || bic.len() == 11);
+        assert_eq!(&bic[4..6], "GB");
+        assert_eq!(gen_bic("Atlantis"), None);
+
+        for _ in 0..100 {
+            assert!(aba_valid(&gen_aba_routing_number()));
+        }
+        let account = gen_uk_bank_account();
+        assert_eq!(account.sort_code.split('-').count(), 3);
+        assert_eq!(account.account_number.len(), 8);
+    }
+}
@@ -0,0 +1,252 @@
+//! Module card provides payment card numbers with valid Luhn check digits, expiry dates and CVVs.
+//!
+//! Numbers are only taken from the test cards published by payment providers (e.g.
+//! `4111 1111 1111 1111` or `5555 5555 5555 4444`), so real cards are never produced. Every number
+//! still matches the brand's IIN range and length and passes the Luhn check.
+
+use rand::Rng;
+
+use crate::datetime;
+use crate::id::random_digits;
+
+/// Payment card brands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardBrand {
+    Visa,
+    /// Mastercard from the classic `51`-`55` range.
+    Mastercard,
+    /// Mastercard from the `2221`-`2720` range.
+    Mastercard2Series,
+    AmericanExpress,
+    Discover,
+    Jcb,
+    UnionPay,
+}
+
+impl CardBrand {
+    /// All supported brands.
+    pub const ALL: [CardBrand; 7] = [
+        CardBrand::Visa,
+        CardBrand::Mastercard,
+        CardBrand::Mastercard2Series,
+        CardBrand::AmericanExpress,
+        CardBrand::Discover,
+        CardBrand::Jcb,
+        CardBrand::UnionPay,
+    ];
+
+    /// Human readable name of the brand.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardBrand::Visa => "Visa",
+            CardBrand::Mastercard | CardBrand::Mastercard2Series => "Mastercard",
+            CardBrand::AmericanExpress => "American Express",
+            CardBrand::Discover => "Discover",
+            CardBrand::Jcb => "JCB",
+            CardBrand::UnionPay => "UnionPay",
+        }
+    }
+
+    /// Test card numbers of the brand, as published by Stripe, Adyen and Braintree.
+    fn test_numbers(&self) -> &'static [&'static str] {
+        match self {
+            CardBrand::Visa => &[
+                "4111111111111111",
+                "4242424242424242",
+                "4012888888881881",
+                "4000056655665556",
+            ],
+            CardBrand::Mastercard => &["5555555555554444", "5105105105105100", "5200828282828210"],
+            CardBrand::Mastercard2Series => &["2223003122003222", "2222400070000005"],
+            CardBrand::AmericanExpress => {
+                &["378282246310005", "371449635398431", "378734493671000"]
+            }
+            CardBrand::Discover => &["6011111111111117", "6011000990139424"],
+            CardBrand::Jcb => &["3530111333300000", "3566002020360505"],
+            CardBrand::UnionPay => &["6200000000000005", "6205500000000000004"],
+        }
+    }
+
+    /// Length of the card verification code: 4 digits for American Express, 3 for all others.
+    pub fn cvv_length(&self) -> usize {
+        match self {
+            CardBrand::AmericanExpress => 4,
+            _ => 3,
+        }
+    }
+}
+
+/// How card numbers are formatted by [`PaymentCard::formatted`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CardNumberFormat {
+    /// only digits: `4111111111111111`
+    #[default]
+    Plain,
+    /// groups separated by spaces: `4111 1111 1111 1111`
+    Spaces,
+    /// groups separated by dashes: `4111-1111-1111-1111`
+    Dashes,
+}
+
+/// A generated payment card.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentCard {
+    pub brand: CardBrand,
+    /// The card number, digits only.
+    pub number: String,
+    /// Expiry month, `1` to `12`.
+    pub expiry_month: u32,
+    /// Four digit expiry year.
+    pub expiry_year: u32,
+    /// Card verification code of the right length for the brand.
+    pub cvv: String,
+}
+
+impl PaymentCard {
+    /// The card number grouped the way it is printed on the card, e.g. 4-6-5 for American Express.
+    pub fn formatted(&self, format: CardNumberFormat) -> String {
+        format_card_number(&self.number, format)
+    }
+
+    /// The expiry date as `MM/YY`.
+    pub fn expiry(&self) -> String {
+        format!("{:02}/{:02}", self.expiry_month, self.expiry_year % 100)
+    }
+}
+
+/// Format a card number with spaces or dashes between the groups. 15 digit numbers use 4-6-5
+/// groups, all others groups of 4.
+pub fn format_card_number(number: &str, format: CardNumberFormat) -> String {
+    let separator = match format {
+        CardNumberFormat::Plain => return number.to_string(),
+        CardNumberFormat::Spaces => " ",
+        CardNumberFormat::Dashes => "-",
+    };
+    let groups: Vec<usize> = if number.len() == 15 {
+        vec![4, 6, 5]
+    } else {
+        vec![4; number.len().div_ceil(4)]
+    };
+    let mut parts: Vec<&str> = Vec::new();
+    let mut rest = number;
+    for size in groups {
+        let (part, tail) = rest.split_at(size.min(rest.len()));
+        parts.push(part);
+        rest = tail;
+    }
+    parts.join(separator)
+}
+
+/// Returns a published test card number of the given brand.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::luhn_valid;
+/// use fakedata_generator::finance::card::{gen_card_number, CardBrand};
+/// let number: String = gen_card_number(CardBrand::Visa);
+/// // number => "4242424242424242"
+/// assert!(number.starts_with('4'));
+/// assert!(luhn_valid(&number));
+/// ```
+pub fn gen_card_number(brand: CardBrand) -> String {
+    let numbers = brand.test_numbers();
+    numbers[rand::rng().random_range(0..numbers.len())].to_string()
+}
+
+/// Returns a payment card of the given brand with a number, an expiry date within the next five
+/// years and a CVV.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_payment_card;
+/// use fakedata_generator::finance::card::{CardBrand, CardNumberFormat};
+/// let card = gen_payment_card(CardBrand::AmericanExpress);
+/// // card.formatted(CardNumberFormat::Spaces) => "3782 822463 10005"
+/// // card.expiry() => "08/29"
+/// // card.cvv => "4821"
+/// assert_eq!(card.number.len(), 15);
+/// assert_eq!(card.cvv.len(), 4);
+/// ```
+pub fn gen_payment_card(brand: CardBrand) -> PaymentCard {
+    let mut rnd = rand::rng();
+    let (year, month, _, _, _, _) = datetime::parts(datetime::now());
+    // months from now until expiry, at least one month in the future
+    let months_ahead = rnd.random_range(1..=60);
+    let total = year as u32 * 12 + (month - 1) + months_ahead;
+    let cvv = random_digits(&mut rnd, brand.cvv_length());
+
+    PaymentCard {
+        brand,
+        number: gen_card_number(brand),
+        expiry_month: total % 12 + 1,
+        expiry_year: total / 12,
+        cvv,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum::luhn_valid;
+
+    #[test]
+    fn test_card_numbers() {
+        for brand in CardBrand::ALL {
+            for _ in 0..50 {
+                let number = gen_card_number(brand);
+                assert!(luhn_valid(&number), "{}", number);
+                assert!(brand.test_numbers().contains(&number.as_str()));
+                let len = number.len();
+                match brand {
+                    CardBrand::Visa => assert!(number.starts_with('4') && len == 16),
+                    CardBrand::Mastercard => {
+                        let iin: u32 = number[..2].parse().unwrap();
+                        assert!((51..=55).contains(&iin) && len == 16);
+                    }
+                    CardBrand::Mastercard2Series => {
+                        let iin: u32 = number[..4].parse().unwrap();
+                        assert!((2221..=2720).contains(&iin) && len == 16);
+                    }
+                    CardBrand::AmericanExpress => {
+                        assert!((number.starts_with("34") || number.starts_with("37")) && len == 15)
+                    }
+                    CardBrand::Discover => assert!(number.starts_with("6011") && len == 16),
+                    CardBrand::Jcb => {
+                        let iin: u32 = number[..4].parse().unwrap();
+                        assert!((3528..=3589).contains(&iin) && len == 16);
+                    }
+                    CardBrand::UnionPay => assert!(number.starts_with("62") && len >= 16),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_card_formatting() {
+        assert_eq!(
+            format_card_number("4111111111111111", CardNumberFormat::Spaces),
+            "4111 1111 1111 1111"
+        );
+        assert_eq!(
+            format_card_number("378282246310005", CardNumberFormat::Dashes),
+            "3782-822463-10005"
+        );
+        assert_eq!(
+            format_card_number("6205500000000000004", CardNumberFormat::Spaces),
+            "6205 5000 0000 0000 004"
+        );
+    }
+
+    #[test]
+    fn test_payment_card_expiry() {
+        let (year, month, _, _, _, _) = datetime::parts(datetime::now());
+        for _ in 0..100 {
+            let card = gen_payment_card(CardBrand::Visa);
+            assert!((1..=12).contains(&card.expiry_month));
+            let expiry = card.expiry_year * 12 + card.expiry_month;
+            assert!(expiry > year as u32 * 12 + month);
+            assert_eq!(card.cvv.len(), 3);
+            assert_eq!(card.expiry().len(), 5);
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! Module currency provides ISO 4217 currencies and money amounts formatted for different locales.
+//!
+//! Amounts are stored as an integer number of minor units (cents, pence, ...), so decimal strings
+//! are always exact and never show floating point drift like `0.30000000000000004`.
+
+use std::fmt;
+
+use rand::Rng;
+
+/// An ISO 4217 currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+    /// Alphabetic code, e.g. `EUR`.
+    pub code: &'static str,
+    /// Numeric code, e.g. `978`.
+    pub numeric: u16,
+    /// Number of digits after the decimal separator: 0 for JPY, 2 for USD, 3 for BHD.
+    pub minor_units: u8,
+    /// Symbol used when formatting amounts, the code for currencies without a common symbol.
+    pub symbol: &'static str,
+    pub name: &'static str,
+    /// Approximate units per 1,000 US dollars, used to scale realistic amounts.
+    per_kusd: u32,
+}
+
+macro_rules! currency {
+    ($code:literal, $numeric:literal, $minor:literal, $symbol:literal, $name:literal, $per_kusd:literal) => {
+        Currency {
+            code: $code,
+            numeric: $numeric,
+            minor_units: $minor,
+            symbol: $symbol,
+            name: $name,
+            per_kusd: $per_kusd,
+        }
+    };
+}
+
+/// The currencies known to [`currency`] and the generators.
+pub const CURRENCIES: [Currency; 36] = [
+    currency!("AED", 784, 2, "AED", "UAE Dirham", 3670),
+    currency!("AUD", 36, 2, "A$", "Australian Dollar", 1500),
+    currency!("BHD", 48, 3, "BHD", "Bahraini Dinar", 376),
+    currency!("BRL", 986, 2, "R$", "Brazilian Real", 5000),
+    currency!("CAD", 124, 2, "CA$", "Canadian Dollar", 1360),
+    currency!("CHF", 756, 2, "CHF", "Swiss Franc", 880),
+    currency!("CLP", 152, 0, "CLP", "Chilean Peso", 930000),
+    currency!("CNY", 156, 2, "CN¥", "Yuan Renminbi", 7200),
+    currency!("CZK", 203, 2, "Kč", "Czech Koruna", 23000),
+    currency!("DKK", 208, 2, "kr.", "Danish Krone", 6900),
+    currency!("EUR", 978, 2, "€", "Euro", 920),
+    currency!("GBP", 826, 2, "£", "Pound Sterling", 790),
+    currency!("HKD", 344, 2, "HK$", "Hong Kong Dollar", 7800),
+    currency!("HUF", 348, 2, "Ft", "Forint", 360000),
+    currency!("ILS", 376, 2, "₪", "New Israeli Sheqel", 3700),
+    currency!("INR", 356, 2, "₹", "Indian Rupee", 83000),
+    currency!("ISK", 352, 0, "ISK", "Iceland Krona", 138000),
+    currency!("JOD", 400, 3, "JOD", "Jordanian Dinar", 709),
+    currency!("JPY", 392, 0, "¥", "Yen", 150000),
+    currency!("KRW", 410, 0, "₩", "Won", 1350000),
+    currency!("KWD", 414, 3, "KWD", "Kuwaiti Dinar", 307),
+    currency!("MXN", 484, 2, "MX$", "Mexican Peso", 17000),
+    currency!("NOK", 578, 2, "kr", "Norwegian Krone", 10600),
+    currency!("NZD", 554, 2, "NZ$", "New Zealand Dollar", 1650),
+    currency!("OMR", 512, 3, "OMR", "Rial Omani", 385),
+    currency!("PLN", 985, 2, "zł", "Zloty", 4000),
+    currency!("SAR", 682, 2, "SAR", "Saudi Riyal", 3750),
+    currency!("SEK", 752, 2, "kr", "Swedish Krona", 10500),
+    currency!("SGD", 702, 2, "S$", "Singapore Dollar", 1350),
+    currency!("THB", 764, 2, "฿", "Baht", 36000),
+    currency!("TND", 788, 3, "TND", "Tunisian Dinar", 3100),
+    currency!("TRY", 949, 2, "₺", "Turkish Lira", 32000),
+    currency!("TWD", 901, 2, "NT$", "New Taiwan Dollar", 32000),
+    currency!("USD", 840, 2, "$", "US Dollar", 1000),
+    currency!("VND", 704, 0, "₫", "Dong", 25000000),
+    currency!("ZAR", 710, 2, "R", "Rand", 18500),
+];
+
+/// Returns the currency for an ISO 4217 alphabetic code, case-insensitive.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::finance::currency::currency;
+/// assert_eq!(currency("bhd").unwrap().minor_units, 3);
+/// assert!(currency("XYZ").is_none());
+/// ```
+pub fn currency(code: &str) -> Option<Currency> {
+    CURRENCIES
+        .iter()
+        .find(|c| c.code.eq_ignore_ascii_case(code))
+        .copied()
+}
+
+/// Number formatting conventions for money amounts. Spaces are plain ASCII spaces, not the
+/// non-breaking spaces some locales use in print.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// `$1,234.56`
+    #[default]
+    EnUs,
+    /// `£1,234.56`
+    EnGb,
+    /// `1.234,56 €`
+    DeDe,
+    /// `1 234,56 €`
+    FrFr,
+    /// `€ 1.234,56`
+    NlNl,
+    /// `CHF 1’234.56`
+    DeCh,
+    /// `¥1,235`
+    JaJp,
+}
+
+impl Locale {
+    /// Group separator, decimal separator, whether the symbol comes first and whether there is a
+    /// space between symbol and number.
+    fn conventions(&self) -> (&'static str, &'static str, bool, bool) {
+        match self {
+            Locale::EnUs | Locale::EnGb | Locale::JaJp => (",", ".", true, false),
+            Locale::DeDe => (".", ",", false, true),
+            Locale::FrFr => (" ", ",", false, true),
+            Locale::NlNl => (".", ",", true, true),
+            Locale::DeCh => ("’", ".", true, true),
+        }
+    }
+}
+
+/// An exact amount of money.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    /// The amount in minor units, e.g. cents for USD.
+    pub minor: i64,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(minor: i64, currency: Currency) -> Self {
+        Money { minor, currency }
+    }
+
+    /// Parse an exact decimal string like `"1234.5"` or `"-0.125"`. Returns `None` if the string
+    /// isn't a decimal number or has more decimal places than the currency's minor units.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use fakedata_generator::finance::currency::{currency, Money};
+    /// let usd = currency("USD").unwrap();
+    /// assert_eq!(Money::parse("19.9", usd).unwrap().minor, 1990);
+    /// assert!(Money::parse("19.999", usd).is_none());
+    /// ```
+    pub fn parse(input: &str, currency: Currency) -> Option<Self> {
+        let (negative, digits) = match input.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, input),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let units = currency.minor_units as usize;
+        let all_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if whole.is_empty() || !all_digits(whole) || !all_digits(fraction) || fraction.len() > units
+        {
+            return None;
+        }
+        let minor: i64 = format!("{}{:0<units$}", whole, fraction).parse().ok()?;
+        Some(Money::new(if negative { -minor } else { minor }, currency))
+    }
+
+    /// The amount as exact decimal string with all minor digits and no grouping: `1234.56`.
+    pub fn to_decimal_string(&self) -> String {
+        let (whole, fraction) = self.split();
+        let sign = if self.minor < 0 { "-" } else { "" };
+        match fraction {
+            Some(fraction) => format!("{}{}.{}", sign, whole, fraction),
+            None => format!("{}{}", sign, whole),
+        }
+    }
+
+    /// The amount formatted with the currency symbol for `locale`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use fakedata_generator::finance::currency::{currency, Locale, Money};
+    /// let eur = Money::new(123456, currency("EUR").unwrap());
+    /// assert_eq!(eur.format(Locale::DeDe), "1.234,56 €");
+    /// let usd = Money::new(123456, currency("USD").unwrap());
+    /// assert_eq!(usd.format(Locale::EnUs), "$1,234.56");
+    /// ```
+    pub fn format(&self, locale: Locale) -> String {
+        let (group, decimal, symbol_first, spaced) = locale.conventions();
+        let (whole, fraction) = self.split();
+        let mut number = group_digits(&whole, group);
+        if let Some(fraction) = fraction {
+            number = format!("{}{}{}", number, decimal, fraction);
+        }
+        // symbols made of letters, like currency codes, always get a space
+        let space = if spaced || self.currency.symbol.chars().all(|c| c.is_alphabetic()) {
+            " "
+        } else {
+            ""
+        };
+        let sign = if self.minor < 0 { "-" } else { "" };
+        if symbol_first {
+            format!("{}{}{}{}", sign, self.currency.symbol, space, number)
+        } else {
+            format!("{}{}{}{}", sign, number, space, self.currency.symbol)
+        }
+    }
+
+    /// Split the absolute amount into whole and fractional digits.
+    fn split(&self) -> (String, Option<String>) {
+        let units = self.currency.minor_units as u32;
+        let abs = self.minor.unsigned_abs();
+        if units == 0 {
+            return (abs.to_string(), None);
+        }
+        let factor = 10u64.pow(units);
+        (
+            (abs / factor).to_string(),
+            Some(format!("{:0width$}", abs % factor, width = units as usize)),
+        )
+    }
+}
+
+impl fmt::Display for Money {
+    /// Formats as decimal string followed by the currency code: `1234.56 USD`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal_string(), self.currency.code)
+    }
+}
+
+/// Insert `separator` between groups of three digits.
+fn group_digits(digits: &str, separator: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push_str(separator);
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Returns a random currency.
+pub fn gen_currency() -> Currency {
+    let mut rnd = rand::rng();
+    CURRENCIES[rnd.random_range(0..CURRENCIES.len())]
+}
+
+/// Returns an amount between `min` and `max` (inclusive), both given as exact decimal strings.
+/// Returns `None` for unknown currencies, invalid numbers or if `min` is larger than `max`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_amount;
+/// let amount = gen_amount("BHD", "10", "20.5").unwrap();
+/// // amount.to_decimal_string() => "14.237"
+/// assert!(amount.minor >= 10_000 && amount.minor <= 20_500);
+/// ```
+pub fn gen_amount(currency_code: &str, min: &str, max: &str) -> Option<Money> {
+    let currency = currency(currency_code)?;
+    let min = Money::parse(min, currency)?;
+    let max = Money::parse(max, currency)?;
+    if min.minor > max.minor {
+        return None;
+    }
+    let mut rnd = rand::rng();
+    Some(Money::new(
+        rnd.random_range(min.minor..=max.minor),
+        currency,
+    ))
+}
+
+/// Returns a realistic payment amount: most amounts are small, few are large (log-uniform between
+/// about 1 and 2,000 US dollars in value), and some end in `.99` or are whole numbers like retail
+/// prices. Returns `None` for unknown currencies.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::finance::currency::gen_transaction_amount;
+/// use fakedata_generator::finance::currency::Locale;
+/// let amount = gen_transaction_amount("JPY").unwrap();
+/// // amount.format(Locale::JaJp) => "¥4,980"
+/// assert!(amount.minor > 0);
+/// ```
+pub fn gen_transaction_amount(currency_code: &str) -> Option<Money> {
+    let currency = currency(currency_code)?;
+    let mut rnd = rand::rng();
+    let usd: f64 = rnd.random_range(0.0..(2000f64).ln()).exp();
+    let factor = 10i64.pow(currency.minor_units as u32);
+    // the float is only used to pick the magnitude, the amount itself is an integer
+    let mut minor =
+        ((usd * currency.per_kusd as f64 / 1000.0 * factor as f64).round() as i64).max(1);
+    match rnd.random_range(0..4) {
+        0 if factor > 1 => minor = (minor / factor).max(1) * factor - factor / 100,
+        1 => minor = (minor / factor).max(1) * factor,
+        _ => {}
+    }
+    Some(Money::new(minor.max(1), currency))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_strings() {
+        let usd = currency("USD").unwrap();
+        let jpy = currency("JPY").unwrap();
+        let bhd = currency("BHD").unwrap();
+        assert_eq!(Money::new(30, usd).to_decimal_string(), "0.30");
+        assert_eq!(Money::new(-5, usd).to_decimal_string(), "-0.05");
+        assert_eq!(Money::new(1234, jpy).to_decimal_string(), "1234");
+        assert_eq!(Money::new(1500, bhd).to_string(), "1.500 BHD");
+        assert_eq!(Money::parse("0.1", usd).unwrap().minor, 10);
+        assert_eq!(Money::parse("-12", bhd).unwrap().minor, -12000);
+        for invalid in ["", ".5", "1.2.3", "abc", "1.001", "--1"] {
+            assert!(Money::parse(invalid, usd).is_none(), "{}", invalid);
+        }
+        // adding ten cents three times doesn't drift
+        let sum = Money::new(Money::parse("0.1", usd).unwrap().minor * 3, usd);
+        assert_eq!(sum.to_decimal_string(), "0.30");
+    }
+
+    #[test]
+    fn test_locale_formatting() {
+        let eur = Money::new(123456, currency("EUR").unwrap());
+        assert_eq!(eur.format(Locale::DeDe), "1.234,56 €");
+        assert_eq!(eur.format(Locale::FrFr), "1 234,56 €");
+        assert_eq!(eur.format(Locale::NlNl), "€ 1.234,56");
+        assert_eq!(eur.format(Locale::EnGb), "€1,234.56");
+        let chf = Money::new(-123456789, currency("CHF").unwrap());
+        assert_eq!(chf.format(Locale::DeCh), "-CHF 1’234’567.89");
+        assert_eq!(chf.format(Locale::EnUs), "-CHF 1,234,567.89");
+        let jpy = Money::new(1235, currency("JPY").unwrap());
+        assert_eq!(jpy.format(Locale::JaJp), "¥1,235");
+        let kwd = Money::new(999, currency("KWD").unwrap());
+        assert_eq!(kwd.format(Locale::EnUs), "KWD 0.999");
+    }
+
+    #[test]
+    fn test_generated_amounts() {
+        for c in CURRENCIES {
+            for _ in 0..50 {
+                let amount = gen_amount(c.code, "1", "100").unwrap();
+                let factor = 10i64.pow(c.minor_units as u32);
+                assert!((factor..=100 * factor).contains(&amount.minor));
+                let realistic = gen_transaction_amount(c.code).unwrap();
+                assert!(realistic.minor > 0);
+                assert_eq!(
+                    Money::parse(&realistic.to_decimal_string(), c),
+                    Some(realistic)
+                );
+            }
+        }
+        assert!(gen_amount("USD", "10", "1").is_none());
+        assert!(gen_amount("XYZ", "1", "10").is_none());
+    }
+}
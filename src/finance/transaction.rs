@@ -0,0 +1,336 @@
+//! Module transaction provides payment transaction records and a ledger which generates
+//! sequences of transfers with consistent per-account balances, e.g. to test reconciliation jobs.
+
+use rand::Rng;
+
+use crate::data::pick_word;
+use crate::datetime;
+use crate::finance::bank::gen_iban;
+use crate::finance::card::{CardBrand, gen_card_number};
+use crate::finance::currency::{Currency, Money, currency, gen_transaction_amount};
+use crate::id::uuid_v4;
+
+/// Merchant category codes (ISO 18245) with their description and a word used in merchant names.
+const MERCHANT_CATEGORIES: [(u16, &str, &str); 15] = [
+    (4111, "Commuter Transport", "Transit"),
+    (4121, "Taxicabs and Limousines", "Cabs"),
+    (4511, "Airlines", "Air"),
+    (4900, "Utilities", "Energy"),
+    (5311, "Department Stores", "Department Store"),
+    (5411, "Grocery Stores, Supermarkets", "Market"),
+    (5541, "Service Stations", "Fuel"),
+    (5732, "Electronics Stores", "Electronics"),
+    (5812, "Eating Places, Restaurants", "Bistro"),
+    (5814, "Fast Food Restaurants", "Burgers"),
+    (5912, "Drug Stores and Pharmacies", "Pharmacy"),
+    (5942, "Book Stores", "Books"),
+    (5999, "Miscellaneous Retail Stores", "Goods"),
+    (7011, "Hotels and Motels", "Hotel"),
+    (7832, "Motion Picture Theaters", "Cinema"),
+];
+
+/// Merchant category code for money transfers between accounts.
+pub const MCC_MONEY_TRANSFER: u16 = 4829;
+
+/// Status of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransactionStatus {
+    Pending,
+    Completed,
+    Failed,
+    Reversed,
+}
+
+impl TransactionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionStatus::Pending => "pending",
+            TransactionStatus::Completed => "completed",
+            TransactionStatus::Failed => "failed",
+            TransactionStatus::Reversed => "reversed",
+        }
+    }
+}
+
+/// A payment transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transaction {
+    /// Random UUID (version 4).
+    pub id: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: i64,
+    /// Account of the payer: an IBAN or a masked card number.
+    pub payer: String,
+    /// Merchant name or IBAN of the payee.
+    pub payee: String,
+    /// Amount transferred, always positive.
+    pub amount: Money,
+    /// Merchant category code.
+    pub mcc: u16,
+    pub status: TransactionStatus,
+    pub description: String,
+}
+
+impl Transaction {
+    /// The timestamp as RFC 3339 date-time in UTC.
+    pub fn timestamp_rfc3339(&self) -> String {
+        datetime::rfc3339(self.timestamp)
+    }
+}
+
+/// Mask all but the first six and last four digits of a card number.
+fn mask_card_number(number: &str) -> String {
+    let len = number.len();
+    format!(
+        "{}{}{}",
+        &number[..6],
+        "*".repeat(len - 10),
+        &number[len - 4..]
+    )
+}
+
+/// Returns a card payment to a merchant within the last 30 days. The payer is a masked test card
+/// number or an IBAN, the status is mostly `Completed`. Returns `None` for unknown currencies.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::finance::transaction::gen_transaction;
+/// let transaction = gen_transaction("EUR").unwrap();
+/// // transaction.id => "3f0c8a1e-6b7d-4e2f-9a51-0d8c2b7e4f13"
+/// // transaction.payer => "411111******1111"
+/// // transaction.payee => "Miller Market"
+/// // transaction.amount.to_string() => "23.49 EUR"
+/// // transaction.mcc => 5411
+/// // transaction.description => "Grocery Stores, Supermarkets"
+/// assert!(transaction.amount.minor > 0);
+/// ```
+pub fn gen_transaction(currency_code: &str) -> Option<Transaction> {
+    let amount = gen_transaction_amount(currency_code)?;
+    let mut rnd = rand::rng();
+    let (mcc, description, merchant_word) =
+        MERCHANT_CATEGORIES[rnd.random_range(0..MERCHANT_CATEGORIES.len())];
+    let payer = if rnd.random_bool(0.7) {
+        let brand = CardBrand::ALL[rnd.random_range(0..CardBrand::ALL.len())];
+        mask_card_number(&gen_card_number(brand))
+    } else {
+        gen_iban("DE")?
+    };
+    let status = match rnd.random_range(0..100) {
+        0..=84 => TransactionStatus::Completed,
+        85..=92 => TransactionStatus::Pending,
+        93..=97 => TransactionStatus::Failed,
+        _ => TransactionStatus::Reversed,
+    };
+    Some(Transaction {
+        id: uuid_v4(&mut rnd),
+        timestamp: datetime::now() - rnd.random_range(0..30 * 86_400),
+        payer,
+        payee: format!("{} {}", pick_word("lastnames", &mut rnd), merchant_word),
+        amount,
+        mcc,
+        status,
+        description: description.to_string(),
+    })
+}
+
+/// An account in a [`Ledger`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Account {
+    pub iban: String,
+    pub holder: String,
+    /// Current balance in minor units of the ledger currency.
+    pub balance: i64,
+}
+
+/// A transaction generated by a [`Ledger`] together with the balances of payer and payee after
+/// the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerEntry {
+    pub transaction: Transaction,
+    pub payer_balance: Money,
+    pub payee_balance: Money,
+}
+
+/// Generates transfers between a fixed set of accounts and keeps their balances consistent: the
+/// balance of every account is its opening balance plus all completed incoming transfers minus
+/// all completed outgoing transfers. Without overdraft a transfer larger than the payer's balance
+/// fails and doesn't change any balance, so balances never go negative.
+///
+/// The ledger is an endless iterator of [`LedgerEntry`] values with increasing timestamps.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::finance::currency::currency;
+/// use fakedata_generator::finance::transaction::Ledger;
+/// let ledger = Ledger::new(currency("EUR").unwrap(), 5);
+/// for entry in ledger.take(100) {
+///     assert!(entry.payer_balance.minor >= 0);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ledger {
+    currency: Currency,
+    accounts: Vec<Account>,
+    allow_overdraft: bool,
+    timestamp: i64,
+}
+
+impl Ledger {
+    /// A ledger with `accounts` accounts (at least 2) with random opening balances in `currency`.
+    pub fn new(currency: Currency, accounts: usize) -> Self {
+        let mut rnd = rand::rng();
+        let accounts = (0..accounts.max(2))
+            .map(|_| Account {
+                iban: gen_iban("DE").unwrap_or_default(),
+                holder: format!(
+                    "{} {}",
+                    pick_word("firstnames", &mut rnd),
+                    pick_word("lastnames", &mut rnd)
+                ),
+                balance: gen_transaction_amount(currency.code)
+                    .map(|m| m.minor * 10)
+                    .unwrap_or_default(),
+            })
+            .collect();
+        Ledger {
+            currency,
+            accounts,
+            allow_overdraft: false,
+            timestamp: datetime::now() - 30 * 86_400,
+        }
+    }
+
+    /// Allow transfers that take the payer's balance below zero.
+    pub fn allow_overdraft(mut self, allow: bool) -> Self {
+        self.allow_overdraft = allow;
+        self
+    }
+
+    /// Set the timestamp of the first transaction, in seconds since the Unix epoch.
+    pub fn start_at(mut self, timestamp: i64) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// The accounts with their current balances.
+    pub fn accounts(&self) -> &[Account] {
+        &self.accounts
+    }
+
+    /// The sum of all balances, which never changes because money only moves between accounts.
+    pub fn total(&self) -> Money {
+        Money::new(self.accounts.iter().map(|a| a.balance).sum(), self.currency)
+    }
+
+    /// Generate the next transfer and update the balances.
+    pub fn next_entry(&mut self) -> LedgerEntry {
+        let mut rnd = rand::rng();
+        let payer = rnd.random_range(0..self.accounts.len());
+        let mut payee = rnd.random_range(0..self.accounts.len() - 1);
+        if payee >= payer {
+            payee += 1;
+        }
+        let amount = gen_transaction_amount(self.currency.code)
+            .unwrap_or_else(|| Money::new(1, self.currency));
+        let covered = self.accounts[payer].balance >= amount.minor;
+        let status = if covered || self.allow_overdraft {
+            self.accounts[payer].balance -= amount.minor;
+            self.accounts[payee].balance += amount.minor;
+            TransactionStatus::Completed
+        } else {
+            TransactionStatus::Failed
+        };
+        self.timestamp += rnd.random_range(1..6 * 3600);
+
+        let description = match status {
+            TransactionStatus::Failed => "Transfer declined: insufficient funds".to_string(),
+            _ => format!("Transfer to {}", self.accounts[payee].holder),
+        };
+        LedgerEntry {
+            transaction: Transaction {
+                id: uuid_v4(&mut rnd),
+                timestamp: self.timestamp,
+                payer: self.accounts[payer].iban.clone(),
+                payee: self.accounts[payee].iban.clone(),
+                amount,
+                mcc: MCC_MONEY_TRANSFER,
+                status,
+                description,
+            },
+            payer_balance: Money::new(self.accounts[payer].balance, self.currency),
+            payee_balance: Money::new(self.accounts[payee].balance, self.currency),
+        }
+    }
+}
+
+impl Iterator for Ledger {
+    type Item = LedgerEntry;
+
+    fn next(&mut self) -> Option<LedgerEntry> {
+        Some(self.next_entry())
+    }
+}
+
+/// Returns a ledger in the currency `currency_code` with `accounts` accounts, see [`Ledger`].
+/// Returns `None` for unknown currencies.
+pub fn gen_ledger(currency_code: &str, accounts: usize) -> Option<Ledger> {
+    currency(currency_code).map(|c| Ledger::new(c, accounts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_gen_transaction() {
+        for _ in 0..50 {
+            let transaction = gen_transaction("USD").unwrap();
+            assert_eq!(transaction.id.len(), 36);
+            assert_eq!(&transaction.id[14..15], "4");
+            assert!(transaction.amount.minor > 0);
+            assert!(transaction.timestamp <= datetime::now());
+            assert!(transaction.timestamp_rfc3339().ends_with('Z'));
+            assert!(!transaction.payee.is_empty() && !transaction.description.is_empty());
+        }
+        assert!(gen_transaction("XYZ").is_none());
+        assert_eq!(mask_card_number("4111111111111111"), "411111******1111");
+    }
+
+    #[test]
+    fn test_ledger_balances() {
+        let mut ledger = gen_ledger("GBP", 4).unwrap();
+        let total = ledger.total();
+        let mut balances: HashMap<String, i64> = ledger
+            .accounts()
+            .iter()
+            .map(|a| (a.iban.clone(), a.balance))
+            .collect();
+        let mut last = 0;
+        for entry in ledger.by_ref().take(500) {
+            let t = &entry.transaction;
+            assert!(t.timestamp > last);
+            last = t.timestamp;
+            assert_ne!(t.payer, t.payee);
+            if t.status == TransactionStatus::Completed {
+                *balances.get_mut(&t.payer).unwrap() -= t.amount.minor;
+                *balances.get_mut(&t.payee).unwrap() += t.amount.minor;
+            }
+            assert_eq!(balances[&t.payer], entry.payer_balance.minor);
+            assert_eq!(balances[&t.payee], entry.payee_balance.minor);
+            assert!(entry.payer_balance.minor >= 0);
+        }
+        assert_eq!(ledger.total(), total);
+    }
+
+    #[test]
+    fn test_ledger_overdraft() {
+        let ledger = Ledger::new(currency("JPY").unwrap(), 2).allow_overdraft(true);
+        let entries: Vec<LedgerEntry> = ledger.take(300).collect();
+        assert!(
+            entries
+                .iter()
+                .all(|e| e.transaction.status == TransactionStatus::Completed)
+        );
+    }
+}
@@ -0,0 +1,491 @@
+//! Module geo provides coordinates, points inside bounding boxes and circles, geohashes and GeoJSON
+//! geometries.
+//!
+//! All distances are in meters on a sphere with the mean earth radius, and random points are
+//! uniformly distributed by area, not by degrees, so they don't cluster at the poles.
+
+use rand::Rng;
+use serde_json::{Value, json};
+
+use crate::data::countries::country_bounds;
+
+/// Mean earth radius in meters (IUGG).
+pub const EARTH_RADIUS: f64 = 6_371_008.8;
+
+const GEOHASH_ALPHABET: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// A point given by latitude and longitude in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Point {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Point {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// The point with latitude and longitude rounded to `precision` decimal places.
+    pub fn round(&self, precision: u32) -> Point {
+        Point::new(
+            round(self.latitude, precision),
+            round(self.longitude, precision),
+        )
+    }
+
+    /// The great-circle distance to `other` in meters (haversine formula).
+    pub fn distance(&self, other: &Point) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (other.longitude - self.longitude).to_radians();
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS * a.sqrt().min(1.0).asin()
+    }
+
+    /// The point reached after travelling `distance` meters from this point in the direction of
+    /// `bearing` degrees (clockwise from north).
+    pub fn destination(&self, bearing: f64, distance: f64) -> Point {
+        let delta = distance / EARTH_RADIUS;
+        let theta = bearing.to_radians();
+        let lat1 = self.latitude.to_radians();
+        let lon1 = self.longitude.to_radians();
+        let lat2 = (lat1.sin() * delta.cos() + lat1.cos() * delta.sin() * theta.cos()).asin();
+        let lon2 = lon1
+            + (theta.sin() * delta.sin() * lat1.cos()).atan2(delta.cos() - lat1.sin() * lat2.sin());
+        Point::new(lat2.to_degrees(), normalize_longitude(lon2.to_degrees()))
+    }
+
+    /// The geohash of the point with `precision` characters.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use fakedata_generator::geo::Point;
+    /// assert_eq!(Point::new(57.64911, 10.40744).geohash(11), "u4pruydqqvj");
+    /// ```
+    pub fn geohash(&self, precision: usize) -> String {
+        let (mut lat, mut lon) = ((-90.0, 90.0), (-180.0, 180.0));
+        let mut hash = String::with_capacity(precision);
+        let mut even = true;
+        let (mut bits, mut index) = (0, 0);
+        while hash.len() < precision {
+            let (range, value) = if even {
+                (&mut lon, self.longitude)
+            } else {
+                (&mut lat, self.latitude)
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            index <<= 1;
+            if value >= mid {
+                index |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even = !even;
+            bits += 1;
+            if bits == 5 {
+                hash.push(char::from(GEOHASH_ALPHABET[index]));
+                bits = 0;
+                index = 0;
+            }
+        }
+        hash
+    }
+}
+
+/// A bounding box given by its south-west and north-east corners. If `min_longitude` is larger
+/// than `max_longitude` the box crosses the antimeridian.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_latitude: f64,
+    pub min_longitude: f64,
+    pub max_latitude: f64,
+    pub max_longitude: f64,
+}
+
+impl BoundingBox {
+    pub fn new(
+        min_latitude: f64,
+        min_longitude: f64,
+        max_latitude: f64,
+        max_longitude: f64,
+    ) -> Self {
+        BoundingBox {
+            min_latitude,
+            min_longitude,
+            max_latitude,
+            max_longitude,
+        }
+    }
+
+    /// The approximate bounding box of a country from the `countries` dataset, given as name or
+    /// ISO code. Returns `None` for unknown countries.
+    pub fn country(name_or_code: &str) -> Option<Self> {
+        let [min_lon, min_lat, max_lon, max_lat] = country_bounds(name_or_code)?;
+        Some(BoundingBox::new(min_lat, min_lon, max_lat, max_lon))
+    }
+
+    /// Returns `true` if `point` is inside the box.
+    pub fn contains(&self, point: &Point) -> bool {
+        let lon_inside = if self.min_longitude <= self.max_longitude {
+            (self.min_longitude..=self.max_longitude).contains(&point.longitude)
+        } else {
+            point.longitude >= self.min_longitude || point.longitude <= self.max_longitude
+        };
+        lon_inside && (self.min_latitude..=self.max_latitude).contains(&point.latitude)
+    }
+}
+
+/// A GeoJSON geometry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    Point(Point),
+    LineString(Vec<Point>),
+    /// A polygon with a single, closed exterior ring.
+    Polygon(Vec<Point>),
+}
+
+impl Geometry {
+    /// The GeoJSON geometry object, with coordinates in `[longitude, latitude]` order.
+    pub fn to_geojson(&self) -> Value {
+        let position = |p: &Point| json!([p.longitude, p.latitude]);
+        match self {
+            Geometry::Point(p) => json!({"type": "Point", "coordinates": position(p)}),
+            Geometry::LineString(points) => json!({
+                "type": "LineString",
+                "coordinates": points.iter().map(position).collect::<Vec<Value>>(),
+            }),
+            Geometry::Polygon(ring) => json!({
+                "type": "Polygon",
+                "coordinates": [ring.iter().map(position).collect::<Vec<Value>>()],
+            }),
+        }
+    }
+
+    /// A GeoJSON `Feature` with this geometry and empty properties.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use fakedata_generator::geo::{Geometry, Point};
+    /// let feature = Geometry::Point(Point::new(52.52, 13.405)).to_feature();
+    /// assert_eq!(
+    ///     feature.to_string(),
+    ///     r#"{"geometry":{"coordinates":[13.405,52.52],"type":"Point"},"properties":{},"type":"Feature"}"#
+    /// );
+    /// ```
+    pub fn to_feature(&self) -> Value {
+        json!({"type": "Feature", "geometry": self.to_geojson(), "properties": {}})
+    }
+}
+
+fn round(value: f64, precision: u32) -> f64 {
+    let factor = 10f64.powi(precision as i32);
+    (value * factor).round() / factor
+}
+
+/// Wrap a longitude into the range -180 to 180.
+fn normalize_longitude(longitude: f64) -> f64 {
+    (longitude + 540.0).rem_euclid(360.0) - 180.0
+}
+
+/// Random latitude in degrees between `min` and `max`, uniform by area.
+fn random_latitude(min: f64, max: f64, rnd: &mut impl Rng) -> f64 {
+    let (low, high) = (min.to_radians().sin(), max.to_radians().sin());
+    if low >= high {
+        return min;
+    }
+    rnd.random_range(low..=high).asin().to_degrees()
+}
+
+/// Returns a latitude between -90 and 90 rounded to `precision` decimal places.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::geo::gen_latitude;
+/// let lat: f64 = gen_latitude(4);
+/// // lat => 48.8566
+/// assert!((-90.0..=90.0).contains(&lat));
+/// ```
+pub fn gen_latitude(precision: u32) -> f64 {
+    let mut rnd = rand::rng();
+    round(random_latitude(-90.0, 90.0, &mut rnd), precision)
+}
+
+/// Returns a longitude between -180 and 180 rounded to `precision` decimal places.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::geo::gen_longitude;
+/// let lon: f64 = gen_longitude(4);
+/// // lon => 2.3522
+/// assert!((-180.0..=180.0).contains(&lon));
+/// ```
+pub fn gen_longitude(precision: u32) -> f64 {
+    let mut rnd = rand::rng();
+    round(rnd.random_range(-180.0..180.0), precision)
+}
+
+/// Returns a random point anywhere on earth.
+pub fn gen_point() -> Point {
+    gen_point_in(&BoundingBox::new(-90.0, -180.0, 90.0, 180.0))
+}
+
+/// Returns a random point inside `bbox`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::geo::{gen_point_in, BoundingBox};
+/// let bbox = BoundingBox::new(47.3, 5.9, 55.1, 15.0);
+/// let point = gen_point_in(&bbox);
+/// assert!(bbox.contains(&point));
+/// ```
+pub fn gen_point_in(bbox: &BoundingBox) -> Point {
+    let mut rnd = rand::rng();
+    let latitude = random_latitude(bbox.min_latitude, bbox.max_latitude, &mut rnd);
+    let mut width = bbox.max_longitude - bbox.min_longitude;
+    if width < 0.0 {
+        width += 360.0;
+    }
+    let longitude = normalize_longitude(bbox.min_longitude + rnd.random_range(0.0..=width));
+    Point::new(latitude, longitude)
+}
+
+/// Returns a random point inside the approximate bounding box of a country from the `countries`
+/// dataset. Returns `None` for unknown countries.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::geo::gen_point_in_country;
+/// let point = gen_point_in_country("Japan").unwrap();
+/// // point => Point { latitude: 35.6762, longitude: 139.6503 }
+/// assert!(point.longitude > 129.0);
+/// ```
+pub fn gen_point_in_country(country: &str) -> Option<Point> {
+    BoundingBox::country(country).map(|bbox| gen_point_in(&bbox))
+}
+
+/// Returns a random point at most `radius` meters away from `center`, uniformly distributed over
+/// the spherical cap.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::geo::{gen_point_within, Point};
+/// let berlin = Point::new(52.52, 13.405);
+/// let point = gen_point_within(berlin, 1_000.0);
+/// assert!(point.distance(&berlin) <= 1_000.0 + 1e-6);
+/// ```
+pub fn gen_point_within(center: Point, radius: f64) -> Point {
+    let mut rnd = rand::rng();
+    let max_angle = (radius / EARTH_RADIUS).min(std::f64::consts::PI);
+    // the area of a cap grows linearly with 1 - cos(angle)
+    let angle = (1.0 - rnd.random_range(0.0..=1.0) * (1.0 - max_angle.cos())).acos();
+    center.destination(rnd.random_range(0.0..360.0), angle * EARTH_RADIUS)
+}
+
+/// Returns the geohash of a random point with `precision` characters.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::geo::gen_geohash;
+/// let hash: String = gen_geohash(7);
+/// // hash => "u33dc0c"
+/// assert_eq!(hash.len(), 7);
+/// ```
+pub fn gen_geohash(precision: usize) -> String {
+    gen_point().geohash(precision)
+}
+
+/// Returns a track of `points` points starting at `start`, made by a random walk: every step is
+/// between half and one and a half times `step` meters long and turns by up to 30 degrees.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::geo::{gen_track, Geometry, Point};
+/// let track = gen_track(Point::new(51.5, -0.12), 20, 50.0);
+/// let line = Geometry::LineString(track).to_feature();
+/// assert_eq!(line["geometry"]["coordinates"].as_array().unwrap().len(), 20);
+/// ```
+pub fn gen_track(start: Point, points: usize, step: f64) -> Vec<Point> {
+    let mut rnd = rand::rng();
+    let mut track = Vec::with_capacity(points);
+    let mut heading: f64 = rnd.random_range(0.0..360.0);
+    let mut current = start;
+    for _ in 0..points {
+        track.push(current);
+        heading += rnd.random_range(-30.0..=30.0);
+        current = current.destination(heading, step * rnd.random_range(0.5..=1.5));
+    }
+    track
+}
+
+/// Returns the closed exterior ring of a polygon with `vertices` corners (at least 3) around
+/// `center`, with every corner between 40% and 100% of `radius` meters away. The radius is limited
+/// to a quarter of the earth's circumference.
+///
+/// The center is moved just as far as needed to keep the polygon at least its radius away from the
+/// poles and off the antimeridian, so the longitudes stay between -180 and 180 without cutting the
+/// polygon as RFC 7946 would require. The corners are ordered by their angle around the center in
+/// longitude and latitude, so the ring never intersects itself and is counterclockwise.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::geo::{gen_polygon, Geometry, Point};
+/// let ring = gen_polygon(Point::new(48.85, 2.35), 6, 500.0);
+/// assert_eq!(ring.len(), 7);
+/// assert_eq!(ring.first(), ring.last());
+/// let polygon = Geometry::Polygon(ring).to_geojson();
+/// // close to the antimeridian the polygon is moved west
+/// let ring = gen_polygon(Point::new(50.0, 179.9), 6, 50_000.0);
+/// assert!(ring.iter().all(|p| p.longitude > 178.0 && p.longitude <= 180.0));
+/// ```
+pub fn gen_polygon(center: Point, vertices: usize, radius: f64) -> Vec<Point> {
+    let mut rnd = rand::rng();
+    let vertices = vertices.max(3);
+    let sector = 360.0 / vertices as f64;
+    let arc = (radius / EARTH_RADIUS).clamp(0.0, std::f64::consts::FRAC_PI_4);
+    let radius = arc * EARTH_RADIUS;
+    let max_latitude = 90.0 - 2.0 * arc.to_degrees();
+    let latitude = center.latitude.clamp(-max_latitude, max_latitude);
+    // half the width of the circle around the center in degrees of longitude
+    let width = (arc.sin() / latitude.to_radians().cos())
+        .asin()
+        .to_degrees()
+        + 1e-9;
+    let longitude = normalize_longitude(center.longitude).clamp(-180.0 + width, 180.0 - width);
+    let center = Point::new(latitude, longitude);
+
+    let mut ring: Vec<Point> = (0..vertices)
+        .map(|i| {
+            let bearing = (i as f64 + rnd.random_range(0.1..0.9)) * sector;
+            center.destination(bearing, radius * rnd.random_range(0.4..=1.0))
+        })
+        .collect();
+    // bearings on the sphere and angles on the map differ far from the equator, order by the
+    // angle on the map to get a star-shaped ring
+    let angle = |p: &Point| (p.latitude - center.latitude).atan2(p.longitude - center.longitude);
+    ring.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+    // only a triangle can leave the center outside, and then the order may be clockwise
+    let area: f64 = ring
+        .iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(a, b)| a.longitude * b.latitude - b.longitude * a.latitude)
+        .sum();
+    if area < 0.0 {
+        ring.reverse();
+    }
+    ring.push(ring[0]);
+    ring
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::countries::DATA_COUNTRY_CODES;
+
+    /// Returns `true` if the segments `a`-`b` and `c`-`d` properly intersect.
+    fn intersects(a: &Point, b: &Point, c: &Point, d: &Point) -> bool {
+        let cross = |o: &Point, p: &Point, q: &Point| {
+            (p.longitude - o.longitude) * (q.latitude - o.latitude)
+                - (p.latitude - o.latitude) * (q.longitude - o.longitude)
+        };
+        cross(a, b, c) * cross(a, b, d) < 0.0 && cross(c, d, a) * cross(c, d, b) < 0.0
+    }
+
+    #[test]
+    fn test_points() {
+        let latitude = gen_latitude(2);
+        assert_eq!(latitude, round(latitude, 2));
+        let center = Point::new(-33.86, 151.21);
+        for radius in [10.0, 5_000.0, 2_000_000.0] {
+            for _ in 0..100 {
+                let point = gen_point_within(center, radius);
+                assert!(point.distance(&center) <= radius * (1.0 + 1e-9));
+            }
+        }
+        // a box crossing the antimeridian
+        let bbox = BoundingBox::new(-20.0, 170.0, -10.0, -170.0);
+        for _ in 0..100 {
+            let point = gen_point_in(&bbox);
+            assert!(bbox.contains(&point), "{:?}", point);
+        }
+        let paris = Point::new(48.8566, 2.3522);
+        let london = Point::new(51.5074, -0.1278);
+        assert!((paris.distance(&london) - 343_500.0).abs() < 1_000.0);
+    }
+
+    #[test]
+    fn test_countries_and_geohash() {
+        for (code, name) in DATA_COUNTRY_CODES {
+            let bbox = BoundingBox::country(name).unwrap_or_else(|| panic!("{}", code));
+            assert!(bbox.contains(&gen_point_in_country(code).unwrap()));
+        }
+        assert!(gen_point_in_country("Atlantis").is_none());
+        assert_eq!(Point::new(-25.38262, -49.26561).geohash(8), "6gkzwgjz");
+        assert_eq!(gen_geohash(12).len(), 12);
+    }
+
+    #[test]
+    fn test_geometries() {
+        let center = Point::new(40.7, -74.0);
+        for vertices in [3, 5, 12] {
+            let ring = gen_polygon(center, vertices, 1_000.0);
+            assert_eq!(ring.len(), vertices + 1);
+            // shoelace formula, positive for counterclockwise rings
+            let area: f64 = ring
+                .windows(2)
+                .map(|w| w[0].longitude * w[1].latitude - w[1].longitude * w[0].latitude)
+                .sum();
+            assert!(area > 0.0);
+            let edges: Vec<(&Point, &Point)> = ring.windows(2).map(|w| (&w[0], &w[1])).collect();
+            for (i, (a, b)) in edges.iter().enumerate() {
+                for (c, d) in edges.iter().skip(i + 2) {
+                    assert!(!intersects(a, b, c, d));
+                }
+            }
+        }
+
+        // across the antimeridian and close to the poles
+        for center in [
+            Point::new(50.0, 179.9),
+            Point::new(-10.0, -179.95),
+            Point::new(89.9, 30.0),
+            Point::new(-89.5, 179.0),
+            Point::new(90.0, 0.0),
+            Point::new(-90.0, 180.0),
+        ] {
+            for _ in 0..20 {
+                let ring = gen_polygon(center, 12, 50_000.0);
+                let area: f64 = ring
+                    .windows(2)
+                    .map(|w| w[0].longitude * w[1].latitude - w[1].longitude * w[0].latitude)
+                    .sum();
+                assert!(area > 0.0);
+                assert!(ring.iter().all(|p| p.longitude.abs() <= 180.0));
+                assert!(ring.iter().all(|p| p.latitude.abs() < 90.0));
+                // the corners are spread out and not all at the center
+                assert!(ring[0].distance(&ring[1]) > 1_000.0);
+                let edges: Vec<(&Point, &Point)> =
+                    ring.windows(2).map(|w| (&w[0], &w[1])).collect();
+                for (i, (a, b)) in edges.iter().enumerate() {
+                    for (c, d) in edges.iter().skip(i + 2) {
+                        assert!(!intersects(a, b, c, d));
+                    }
+                }
+            }
+        }
+
+        let track = gen_track(center, 10, 100.0);
+        assert_eq!(track[0], center);
+        for pair in track.windows(2) {
+            assert!(pair[0].distance(&pair[1]) <= 150.0 + 1e-6);
+        }
+        let feature = Geometry::LineString(track).to_feature();
+        assert_eq!(feature["type"], "Feature");
+        assert_eq!(feature["geometry"]["type"], "LineString");
+        assert_eq!(feature["geometry"]["coordinates"][0][0], -74.0);
+    }
+}
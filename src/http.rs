@@ -0,0 +1,640 @@
+//! Module http provides the building blocks of HTTP messages: status codes with their reason
+//! phrases, User-Agent strings, MIME types and header name/value pairs.
+//!
+//! Status codes and reason phrases follow the IANA HTTP status code registry (RFC 9110 and
+//! extensions). User-Agent strings use the formats current browsers, crawlers and HTTP clients
+//! send, with version numbers in the range of recent releases. Complete raw messages are built by
+//! the [`message`] module.
+
+use std::fmt;
+
+use rand::Rng;
+
+use crate::datetime;
+use crate::domain::{DomainOptions, gen_domain_with};
+use crate::id::{to_hex, uuid_v4};
+use crate::url::gen_url;
+use crate::{gen_password, gen_username};
+
+pub mod message;
+
+const STATUS_CODES: [(u16, &str); 61] = [
+    (100, "Continue"),
+    (101, "Switching Protocols"),
+    (102, "Processing"),
+    (103, "Early Hints"),
+    (200, "OK"),
+    (201, "Created"),
+    (202, "Accepted"),
+    (203, "Non-Authoritative Information"),
+    (204, "No Content"),
+    (205, "Reset Content"),
+    (206, "Partial Content"),
+    (207, "Multi-Status"),
+    (208, "Already Reported"),
+    (226, "IM Used"),
+    (300, "Multiple Choices"),
+    (301, "Moved Permanently"),
+    (302, "Found"),
+    (303, "See Other"),
+    (304, "Not Modified"),
+    (305, "Use Proxy"),
+    (307, "Temporary Redirect"),
+    (308, "Permanent Redirect"),
+    (400, "Bad Request"),
+    (401, "Unauthorized"),
+    (402, "Payment Required"),
+    (403, "Forbidden"),
+    (404, "Not Found"),
+    (405, "Method Not Allowed"),
+    (406, "Not Acceptable"),
+    (407, "Proxy Authentication Required"),
+    (408, "Request Timeout"),
+    (409, "Conflict"),
+    (410, "Gone"),
+    (411, "Length Required"),
+    (412, "Precondition Failed"),
+    (413, "Content Too Large"),
+    (414, "URI Too Long"),
+    (415, "Unsupported Media Type"),
+    (416, "Range Not Satisfiable"),
+    (417, "Expectation Failed"),
+    (421, "Misdirected Request"),
+    (422, "Unprocessable Content"),
+    (423, "Locked"),
+    (424, "Failed Dependency"),
+    (425, "Too Early"),
+    (426, "Upgrade Required"),
+    (428, "Precondition Required"),
+    (429, "Too Many Requests"),
+    (431, "Request Header Fields Too Large"),
+    (451, "Unavailable For Legal Reasons"),
+    (500, "Internal Server Error"),
+    (501, "Not Implemented"),
+    (502, "Bad Gateway"),
+    (503, "Service Unavailable"),
+    (504, "Gateway Timeout"),
+    (505, "HTTP Version Not Supported"),
+    (506, "Variant Also Negotiates"),
+    (507, "Insufficient Storage"),
+    (508, "Loop Detected"),
+    (510, "Not Extended"),
+    (511, "Network Authentication Required"),
+];
+
+const MIME_TYPES: [&str; 28] = [
+    "text/html",
+    "text/plain",
+    "text/css",
+    "text/csv",
+    "text/javascript",
+    "text/markdown",
+    "application/json",
+    "application/ld+json",
+    "application/xml",
+    "application/pdf",
+    "application/zip",
+    "application/gzip",
+    "application/octet-stream",
+    "application/x-www-form-urlencoded",
+    "application/wasm",
+    "multipart/form-data",
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "image/avif",
+    "image/svg+xml",
+    "audio/mpeg",
+    "audio/ogg",
+    "video/mp4",
+    "video/webm",
+    "font/woff2",
+    "font/ttf",
+];
+
+/// Names of the headers [`gen_http_header_value`] supports.
+pub const HTTP_HEADERS: [&str; 14] = [
+    "Accept",
+    "Accept-Encoding",
+    "Accept-Language",
+    "Authorization",
+    "Cache-Control",
+    "Connection",
+    "Content-Type",
+    "Cookie",
+    "Host",
+    "If-None-Match",
+    "Origin",
+    "Referer",
+    "User-Agent",
+    "X-Request-ID",
+];
+
+/// Languages as `(language, region)` for `Accept-Language`.
+const LANGUAGES: [(&str, &str); 10] = [
+    ("en", "US"),
+    ("en", "GB"),
+    ("de", "DE"),
+    ("fr", "FR"),
+    ("es", "ES"),
+    ("it", "IT"),
+    ("nl", "NL"),
+    ("pt", "BR"),
+    ("pl", "PL"),
+    ("ja", "JP"),
+];
+
+const BOT_USER_AGENTS: [&str; 7] = [
+    "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
+    "Mozilla/5.0 (compatible; bingbot/2.0; +http://www.bing.com/bingbot.htm)",
+    "DuckDuckBot/1.1; (+http://duckduckgo.com/duckduckbot.html)",
+    "Mozilla/5.0 (compatible; YandexBot/3.0; +http://yandex.com/bots)",
+    "Mozilla/5.0 (compatible; AhrefsBot/7.0; +http://ahrefs.com/robot/)",
+    "Mozilla/5.0 (compatible; Baiduspider/2.0; +http://www.baidu.com/search/spider.html)",
+    "facebookexternalhit/1.1 (+http://www.facebook.com/externalhit_uatext.php)",
+];
+
+/// The class of an HTTP status code, given by its first digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusClass {
+    /// `1xx`
+    Informational,
+    /// `2xx`
+    Success,
+    /// `3xx`
+    Redirection,
+    /// `4xx`
+    ClientError,
+    /// `5xx`
+    ServerError,
+}
+
+impl StatusClass {
+    /// The first digit of the status codes in this class.
+    fn digit(self) -> u16 {
+        match self {
+            StatusClass::Informational => 1,
+            StatusClass::Success => 2,
+            StatusClass::Redirection => 3,
+            StatusClass::ClientError => 4,
+            StatusClass::ServerError => 5,
+        }
+    }
+}
+
+/// An HTTP status code with its reason phrase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HttpStatus {
+    pub code: u16,
+    pub reason: &'static str,
+}
+
+impl HttpStatus {
+    /// Returns the status for `code`, or `None` for codes which are not registered.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use fakedata_generator::http::HttpStatus;
+    /// assert_eq!(HttpStatus::from_code(404).unwrap().reason, "Not Found");
+    /// assert!(HttpStatus::from_code(299).is_none());
+    /// ```
+    pub fn from_code(code: u16) -> Option<HttpStatus> {
+        STATUS_CODES
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(code, reason)| HttpStatus {
+                code: *code,
+                reason,
+            })
+    }
+
+    /// The class of the status code, e.g. [`StatusClass::ClientError`] for `404`.
+    pub fn class(&self) -> StatusClass {
+        match self.code / 100 {
+            1 => StatusClass::Informational,
+            2 => StatusClass::Success,
+            3 => StatusClass::Redirection,
+            4 => StatusClass::ClientError,
+            _ => StatusClass::ServerError,
+        }
+    }
+}
+
+impl fmt::Display for HttpStatus {
+    /// Status code and reason phrase as in a status line: `404 Not Found`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.code, self.reason)
+    }
+}
+
+/// Returns a status code with the distribution of a typical web server: mostly `2xx`, some
+/// redirects and client errors and few server errors.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_http_status;
+/// let status = gen_http_status();
+/// // status.to_string() => "200 OK"
+/// assert!((100..600).contains(&status.code));
+/// ```
+pub fn gen_http_status() -> HttpStatus {
+    let mut rnd = rand::rng();
+    let class = match rnd.random_range(0..100) {
+        0..70 => {
+            // most successful responses are a plain 200
+            if rnd.random_bool(0.8) {
+                return HttpStatus::from_code(200).unwrap();
+            }
+            StatusClass::Success
+        }
+        70..82 => StatusClass::Redirection,
+        82..96 => StatusClass::ClientError,
+        96..99 => StatusClass::ServerError,
+        _ => StatusClass::Informational,
+    };
+    gen_http_status_in(class)
+}
+
+/// Returns a status code of the given class, all registered codes of the class are equally
+/// likely.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::http::{gen_http_status_in, StatusClass};
+/// let status = gen_http_status_in(StatusClass::ServerError);
+/// // status.to_string() => "503 Service Unavailable"
+/// assert_eq!(status.code / 100, 5);
+/// ```
+pub fn gen_http_status_in(class: StatusClass) -> HttpStatus {
+    let codes: Vec<&(u16, &str)> = STATUS_CODES
+        .iter()
+        .filter(|(code, _)| code / 100 == class.digit())
+        .collect();
+    let (code, reason) = codes[rand::rng().random_range(0..codes.len())];
+    HttpStatus {
+        code: *code,
+        reason,
+    }
+}
+
+/// Kinds of HTTP clients for [`gen_user_agent_of`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UserAgentKind {
+    /// Chrome, Firefox, Safari and Edge on Windows, macOS and Linux.
+    Desktop,
+    /// Chrome, Samsung Internet and Firefox on Android, Safari on iOS.
+    Mobile,
+    /// Search engine and social media crawlers.
+    Bot,
+    /// Command line tools and HTTP libraries like curl or python-requests.
+    Cli,
+}
+
+/// Returns a User-Agent string of a desktop or mobile browser, a crawler or a command line client.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_user_agent;
+/// let ua: String = gen_user_agent();
+/// // ua => "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/138.0.0.0 Safari/537.36"
+/// assert!(!ua.is_empty());
+/// ```
+pub fn gen_user_agent() -> String {
+    let kind = match rand::rng().random_range(0..20) {
+        0..10 => UserAgentKind::Desktop,
+        10..16 => UserAgentKind::Mobile,
+        16..18 => UserAgentKind::Bot,
+        _ => UserAgentKind::Cli,
+    };
+    gen_user_agent_of(kind)
+}
+
+/// Returns a User-Agent string of the given kind of client.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::http::{gen_user_agent_of, UserAgentKind};
+/// let ua: String = gen_user_agent_of(UserAgentKind::Cli);
+/// // ua => "curl/8.5.0"
+/// assert!(!ua.starts_with("Mozilla"));
+/// ```
+pub fn gen_user_agent_of(kind: UserAgentKind) -> String {
+    let mut rnd = rand::rng();
+    let chrome = rnd.random_range(120..=141);
+    let firefox = rnd.random_range(115..=143);
+    let safari = (rnd.random_range(16..=18), rnd.random_range(0..=6));
+    match kind {
+        UserAgentKind::Desktop => {
+            let os = [
+                "Windows NT 10.0; Win64; x64",
+                "Macintosh; Intel Mac OS X 10_15_7",
+                "X11; Linux x86_64",
+            ][rnd.random_range(0..3)];
+            match rnd.random_range(0..10) {
+                0..5 => format!(
+                    "Mozilla/5.0 ({}) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{}.0.0.0 Safari/537.36",
+                    os, chrome
+                ),
+                5..7 => {
+                    let os = [
+                        "Windows NT 10.0; Win64; x64",
+                        "Macintosh; Intel Mac OS X 10.15",
+                        "X11; Ubuntu; Linux x86_64",
+                    ][rnd.random_range(0..3)];
+                    format!(
+                        "Mozilla/5.0 ({}; rv:{}.0) Gecko/20100101 Firefox/{}.0",
+                        os, firefox, firefox
+                    )
+                }
+                7..9 => format!(
+                    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/{}.{} Safari/605.1.15",
+                    safari.0, safari.1
+                ),
+                _ => format!(
+                    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{}.0.0.0 Safari/537.36 Edg/{}.0.0.0",
+                    chrome, chrome
+                ),
+            }
+        }
+        UserAgentKind::Mobile => match rnd.random_range(0..10) {
+            0..5 => format!(
+                "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{}.0.0.0 Mobile Safari/537.36",
+                chrome
+            ),
+            5..8 => format!(
+                "Mozilla/5.0 (iPhone; CPU iPhone OS {}_{} like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/{}.{} Mobile/15E148 Safari/604.1",
+                safari.0, safari.1, safari.0, safari.1
+            ),
+            8 => format!(
+                "Mozilla/5.0 (Linux; Android {}; SAMSUNG SM-S918B) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/{}.0 Chrome/{}.0.0.0 Mobile Safari/537.36",
+                rnd.random_range(12..=15),
+                rnd.random_range(24..=28),
+                chrome
+            ),
+            _ => format!(
+                "Mozilla/5.0 (Android {}; Mobile; rv:{}.0) Gecko/{}.0 Firefox/{}.0",
+                rnd.random_range(12..=15),
+                firefox,
+                firefox,
+                firefox
+            ),
+        },
+        UserAgentKind::Bot => {
+            BOT_USER_AGENTS[rnd.random_range(0..BOT_USER_AGENTS.len())].to_string()
+        }
+        UserAgentKind::Cli => match rnd.random_range(0..8) {
+            0 => format!(
+                "curl/8.{}.{}",
+                rnd.random_range(0..=16),
+                rnd.random_range(0..=1)
+            ),
+            1 => format!(
+                "Wget/1.{}.{}",
+                rnd.random_range(21..=25),
+                rnd.random_range(0..=2)
+            ),
+            2 => format!(
+                "python-requests/2.{}.{}",
+                rnd.random_range(28..=32),
+                rnd.random_range(0..=3)
+            ),
+            3 => format!("Go-http-client/{}", ["1.1", "2.0"][rnd.random_range(0..2)]),
+            4 => format!(
+                "PostmanRuntime/7.{}.{}",
+                rnd.random_range(36..=45),
+                rnd.random_range(0..=3)
+            ),
+            5 => format!("okhttp/4.{}.0", rnd.random_range(9..=12)),
+            6 => format!("HTTPie/3.2.{}", rnd.random_range(1..=4)),
+            _ => format!(
+                "axios/1.{}.{}",
+                rnd.random_range(4..=9),
+                rnd.random_range(0..=5)
+            ),
+        },
+    }
+}
+
+/// Returns a MIME type like `application/json` or `image/webp`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_mime_type;
+/// let mime: String = gen_mime_type();
+/// // mime => "image/png"
+/// assert!(mime.contains('/'));
+/// ```
+pub fn gen_mime_type() -> String {
+    MIME_TYPES[rand::rng().random_range(0..MIME_TYPES.len())].to_string()
+}
+
+/// Base64 encoding of `bytes`, URL-safe and without padding for `url_safe` (RFC 4648).
+fn base64(bytes: &[u8], url_safe: bool) -> String {
+    let alphabet: &[u8] = if url_safe {
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+    } else {
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+    };
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (u32::from(*b) << (16 - 8 * i)));
+        for i in 0..=chunk.len() {
+            encoded.push(char::from(alphabet[((n >> (18 - 6 * i)) & 0x3f) as usize]));
+        }
+        if !url_safe {
+            encoded.push_str(&"=".repeat(3 - chunk.len()));
+        }
+    }
+    encoded
+}
+
+/// A JSON Web Token with random subject and signature.
+fn gen_jwt(rnd: &mut impl Rng) -> String {
+    let payload = serde_json::json!({
+        "sub": uuid_v4(rnd),
+        "iat": datetime::now() - rnd.random_range(0..86_400),
+    });
+    let signature: [u8; 32] = rnd.random();
+    format!(
+        "{}.{}.{}",
+        base64(br#"{"alg":"HS256","typ":"JWT"}"#, true),
+        base64(payload.to_string().as_bytes(), true),
+        base64(&signature, true)
+    )
+}
+
+/// Returns `Accept-Language` with one to three languages and quality values.
+fn gen_accept_language(rnd: &mut impl Rng) -> String {
+    let (language, region) = LANGUAGES[rnd.random_range(0..LANGUAGES.len())];
+    let mut value = format!("{}-{}", language, region);
+    match rnd.random_range(0..3) {
+        0 => {}
+        1 => value.push_str(&format!(",{};q=0.9", language)),
+        _ if language != "en" => value.push_str(&format!(",{};q=0.9,en;q=0.8", language)),
+        _ => value.push_str(",en;q=0.9"),
+    }
+    value
+}
+
+/// Returns a plausible value for the header `name` (case-insensitive), or `None` for headers not
+/// in [`HTTP_HEADERS`].
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::http::gen_http_header_value;
+/// let value = gen_http_header_value("accept-language").unwrap();
+/// // value => "de-DE,de;q=0.9,en;q=0.8"
+/// assert!(gen_http_header_value("X-Unknown").is_none());
+/// ```
+pub fn gen_http_header_value(name: &str) -> Option<String> {
+    let mut rnd = rand::rng();
+    let name = HTTP_HEADERS
+        .iter()
+        .find(|header| header.eq_ignore_ascii_case(name))?;
+    let value = match *name {
+        "Accept" => [
+            "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
+            "application/json",
+            "application/json, text/plain, */*",
+            "*/*",
+            "image/avif,image/webp,image/png,image/svg+xml,image/*;q=0.8,*/*;q=0.5",
+        ][rnd.random_range(0..5)]
+        .to_string(),
+        "Accept-Encoding" => [
+            "gzip, deflate, br",
+            "gzip, deflate, br, zstd",
+            "gzip",
+            "identity",
+        ][rnd.random_range(0..4)]
+        .to_string(),
+        "Accept-Language" => gen_accept_language(&mut rnd),
+        "Authorization" => {
+            if rnd.random_bool(0.7) {
+                format!("Bearer {}", gen_jwt(&mut rnd))
+            } else {
+                let credentials = format!("{}:{}", gen_username(), gen_password(12));
+                format!("Basic {}", base64(credentials.as_bytes(), false))
+            }
+        }
+        "Cache-Control" => match rnd.random_range(0..6) {
+            0 => String::from("no-cache"),
+            1 => String::from("no-store"),
+            2 => format!(
+                "max-age={}",
+                [60, 300, 600, 3600, 86_400][rnd.random_range(0..5)]
+            ),
+            3 => String::from("public, max-age=31536000, immutable"),
+            4 => String::from("private, max-age=0, must-revalidate"),
+            _ => String::from("s-maxage=600, stale-while-revalidate=30"),
+        },
+        "Connection" => ["keep-alive", "close"][rnd.random_range(0..2)].to_string(),
+        "Content-Type" => {
+            let mime = MIME_TYPES[rnd.random_range(0..MIME_TYPES.len())];
+            if mime == "multipart/form-data" {
+                let boundary: [u8; 8] = rnd.random();
+                format!("{}; boundary=----FormBoundary{}", mime, to_hex(&boundary))
+            } else if mime.starts_with("text/") || mime.ends_with("json") {
+                format!("{}; charset=utf-8", mime)
+            } else {
+                mime.to_string()
+            }
+        }
+        "Cookie" => {
+            let session: [u8; 16] = rnd.random();
+            format!(
+                "session_id={}; theme={}",
+                to_hex(&session),
+                ["dark", "light"][rnd.random_range(0..2)]
+            )
+        }
+        "Host" => gen_domain_with(&DomainOptions::default().subdomains(rnd.random_range(0..=1))),
+        "If-None-Match" => {
+            let tag: [u8; 8] = rnd.random();
+            let weak = if rnd.random_bool(0.3) { "W/" } else { "" };
+            format!("{}\"{}\"", weak, to_hex(&tag))
+        }
+        "Origin" => format!("https://{}", gen_domain_with(&DomainOptions::default())),
+        "Referer" => gen_url(),
+        "User-Agent" => gen_user_agent(),
+        _ => uuid_v4(&mut rnd),
+    };
+    Some(value)
+}
+
+/// Returns a header name and value, see [`HTTP_HEADERS`] for the supported headers.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_http_header;
+/// let (name, value) = gen_http_header();
+/// // name => "Cache-Control"
+/// // value => "max-age=3600"
+/// assert!(!value.is_empty());
+/// ```
+pub fn gen_http_header() -> (String, String) {
+    let name = HTTP_HEADERS[rand::rng().random_range(0..HTTP_HEADERS.len())];
+    // every name in HTTP_HEADERS has a value
+    let value = gen_http_header_value(name).unwrap_or_default();
+    (name.to_string(), value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_status() {
+        for class in [
+            StatusClass::Informational,
+            StatusClass::Success,
+            StatusClass::Redirection,
+            StatusClass::ClientError,
+            StatusClass::ServerError,
+        ] {
+            for _ in 0..20 {
+                assert_eq!(gen_http_status_in(class).class(), class);
+            }
+        }
+        for _ in 0..100 {
+            let status = gen_http_status();
+            assert_eq!(HttpStatus::from_code(status.code), Some(status));
+        }
+        assert_eq!(
+            HttpStatus::from_code(503).unwrap().to_string(),
+            "503 Service Unavailable"
+        );
+    }
+
+    #[test]
+    fn test_user_agents() {
+        for _ in 0..50 {
+            assert!(gen_user_agent_of(UserAgentKind::Desktop).starts_with("Mozilla/5.0 ("));
+            let mobile = gen_user_agent_of(UserAgentKind::Mobile);
+            assert!(mobile.contains("Mobile"), "{}", mobile);
+            assert!(gen_user_agent_of(UserAgentKind::Bot).contains("http://"));
+            let cli = gen_user_agent_of(UserAgentKind::Cli);
+            assert!(!cli.contains(' ') && cli.contains('/'), "{}", cli);
+        }
+    }
+
+    #[test]
+    fn test_http_headers() {
+        assert_eq!(base64(b"user:pass", false), "dXNlcjpwYXNz");
+        assert_eq!(base64(b"ab", false), "YWI=");
+        assert_eq!(base64(b"a", false), "YQ==");
+        assert_eq!(base64(&[0xfb, 0xff], true), "-_8");
+        for name in HTTP_HEADERS {
+            let value = gen_http_header_value(&name.to_lowercase()).unwrap();
+            assert!(!value.is_empty(), "{}", name);
+            // header values must not contain line breaks
+            assert!(!value.contains('\r') && !value.contains('\n'), "{}", name);
+        }
+        let jwt = gen_jwt(&mut rand::rng());
+        assert_eq!(jwt.split('.').count(), 3);
+        assert!(jwt.starts_with("eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9."));
+    }
+}
@@ -0,0 +1,707 @@
+//! Module message provides complete raw HTTP/1.1 requests and responses, e.g. to test HTTP parsers
+//! and proxies, and exports them as HAR (HTTP Archive 1.2) to replay them in browser tooling.
+//!
+//! Messages are coherent: `Content-Length` always matches the body, a chunked body is announced
+//! with `Transfer-Encoding: chunked`, responses to `HEAD` requests and `204`/`304` responses have
+//! no body, and redirects come with a `Location` header.
+
+use std::fmt;
+
+use rand::Rng;
+use serde_json::{Value, json};
+
+use crate::data::pick_word;
+use crate::datetime;
+use crate::http::{
+    HttpStatus, StatusClass, gen_http_header_value, gen_http_status, gen_user_agent,
+};
+use crate::id::{to_hex, uuid_v4};
+use crate::text::gen_paragraph;
+use crate::url::{Url, UrlOptions, gen_url_with, percent_encode};
+use crate::{gen_email, gen_http_method};
+
+const SERVERS: [&str; 6] = [
+    "nginx/1.26.2",
+    "Apache/2.4.62 (Debian)",
+    "Microsoft-IIS/10.0",
+    "cloudflare",
+    "envoy",
+    "Caddy",
+];
+
+/// Sizes of the chunks of a chunked body, used in turn so the encoding is reproducible.
+const CHUNK_SIZES: [usize; 5] = [17, 64, 5, 128, 33];
+
+/// The kind of body of a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BodyKind {
+    /// A JSON object, `application/json`.
+    Json,
+    /// Form fields, `application/x-www-form-urlencoded`.
+    Form,
+    /// A Lorem Ipsum paragraph, `text/plain`.
+    Text,
+}
+
+impl BodyKind {
+    fn content_type(self) -> &'static str {
+        match self {
+            BodyKind::Json => "application/json",
+            BodyKind::Form => "application/x-www-form-urlencoded",
+            BodyKind::Text => "text/plain; charset=utf-8",
+        }
+    }
+}
+
+/// Options for [`gen_http_request_with`] and [`gen_http_response`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MessageOptions {
+    chunked: bool,
+    body: Option<BodyKind>,
+}
+
+impl MessageOptions {
+    /// Send bodies with `Transfer-Encoding: chunked` instead of `Content-Length`.
+    pub fn chunked(mut self, chunked: bool) -> Self {
+        self.chunked = chunked;
+        self
+    }
+
+    /// The kind of body for messages which have one. By default requests have a random kind and
+    /// responses a JSON or text body depending on the `Accept` header of the request.
+    pub fn body(mut self, body: BodyKind) -> Self {
+        self.body = Some(body);
+        self
+    }
+}
+
+/// A generated HTTP/1.1 request. `Display` renders the raw message with CRLF line endings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: String,
+    /// The URL the request is sent to, without userinfo and fragment.
+    pub url: Url,
+    /// The request target of the request line: a path, `*` for `OPTIONS` or `host:port` for
+    /// `CONNECT`.
+    pub target: String,
+    pub headers: Vec<(String, String)>,
+    /// The body, without chunked encoding.
+    pub body: String,
+    /// Whether the body is sent with chunked transfer encoding.
+    pub chunked: bool,
+}
+
+impl HttpRequest {
+    /// The request line: `GET /fruits/fig HTTP/1.1`.
+    pub fn request_line(&self) -> String {
+        format!("{} {} HTTP/1.1", self.method, self.target)
+    }
+
+    /// The value of the first header called `name`, case-insensitive.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+impl fmt::Display for HttpRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_message(
+            f,
+            &self.request_line(),
+            &self.headers,
+            &self.body,
+            self.chunked,
+        )
+    }
+}
+
+/// A generated HTTP/1.1 response. `Display` renders the raw message with CRLF line endings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: HttpStatus,
+    pub headers: Vec<(String, String)>,
+    /// The body, without chunked encoding.
+    pub body: String,
+    /// Whether the body is sent with chunked transfer encoding.
+    pub chunked: bool,
+}
+
+impl HttpResponse {
+    /// The status line: `HTTP/1.1 404 Not Found`.
+    pub fn status_line(&self) -> String {
+        format!("HTTP/1.1 {}", self.status)
+    }
+
+    /// The value of the first header called `name`, case-insensitive.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+impl fmt::Display for HttpResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_message(
+            f,
+            &self.status_line(),
+            &self.headers,
+            &self.body,
+            self.chunked,
+        )
+    }
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Write start line, headers and body of a message.
+fn write_message(
+    f: &mut fmt::Formatter<'_>,
+    start_line: &str,
+    headers: &[(String, String)],
+    body: &str,
+    chunked: bool,
+) -> fmt::Result {
+    write!(f, "{}\r\n", start_line)?;
+    for (name, value) in headers {
+        write!(f, "{}: {}\r\n", name, value)?;
+    }
+    write!(f, "\r\n")?;
+    if chunked {
+        write!(f, "{}", encode_chunked(body))
+    } else {
+        write!(f, "{}", body)
+    }
+}
+
+/// Encode `body` with chunked transfer encoding. Chunks never split a UTF-8 character.
+fn encode_chunked(body: &str) -> String {
+    let mut encoded = String::new();
+    let mut start = 0;
+    let mut sizes = CHUNK_SIZES.iter().cycle();
+    while start < body.len() {
+        let mut end = (start + sizes.next().unwrap()).min(body.len());
+        while !body.is_char_boundary(end) {
+            end += 1;
+        }
+        encoded.push_str(&format!("{:x}\r\n{}\r\n", end - start, &body[start..end]));
+        start = end;
+    }
+    encoded.push_str("0\r\n\r\n");
+    encoded
+}
+
+/// Encode a value for `application/x-www-form-urlencoded`, with `+` for spaces.
+fn form_encode(value: &str) -> String {
+    percent_encode(value).replace("%20", "+")
+}
+
+fn gen_request_body(kind: BodyKind, rnd: &mut impl Rng) -> String {
+    let name = format!(
+        "{} {}",
+        pick_word("firstnames", rnd),
+        pick_word("lastnames", rnd)
+    );
+    match kind {
+        BodyKind::Json => json!({
+            "name": name,
+            "email": gen_email(),
+            "quantity": rnd.random_range(1..10),
+            "tags": [pick_word("fruits", rnd), pick_word("moods", rnd)],
+            "active": rnd.random_bool(0.5),
+        })
+        .to_string(),
+        BodyKind::Form => format!(
+            "name={}&email={}&message={}",
+            form_encode(&name),
+            form_encode(&gen_email()),
+            form_encode(&pick_word("objects", rnd))
+        ),
+        BodyKind::Text => gen_paragraph(),
+    }
+}
+
+fn gen_response_body(kind: BodyKind, status: &HttpStatus, rnd: &mut impl Rng) -> String {
+    let failed = matches!(
+        status.class(),
+        StatusClass::ClientError | StatusClass::ServerError
+    );
+    match kind {
+        BodyKind::Json if failed => json!({
+            "error": { "code": status.code, "message": status.reason },
+        })
+        .to_string(),
+        BodyKind::Json => json!({
+            "id": uuid_v4(rnd),
+            "status": "ok",
+            "created_at": datetime::rfc3339(datetime::now()),
+            "items": [pick_word("objects", rnd), pick_word("objects", rnd)],
+        })
+        .to_string(),
+        BodyKind::Form => format!(
+            "status={}&message={}",
+            status.code,
+            form_encode(status.reason)
+        ),
+        BodyKind::Text if failed => format!("{}\n", status),
+        BodyKind::Text => gen_paragraph(),
+    }
+}
+
+/// Add `Content-Type` and the length headers for `body`.
+fn push_body_headers(
+    headers: &mut Vec<(String, String)>,
+    kind: BodyKind,
+    body: &str,
+    chunked: bool,
+) {
+    headers.push(("Content-Type".to_string(), kind.content_type().to_string()));
+    if chunked {
+        headers.push(("Transfer-Encoding".to_string(), "chunked".to_string()));
+    } else {
+        headers.push(("Content-Length".to_string(), body.len().to_string()));
+    }
+}
+
+/// Returns a request with the default options, see [`MessageOptions`].
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::http::message::gen_http_request;
+/// let request = gen_http_request();
+/// // request.to_string() => "POST /herbs/basil HTTP/1.1\r\nHost: amber-fig.com\r\n..."
+/// assert!(request.request_line().ends_with(" HTTP/1.1"));
+/// ```
+pub fn gen_http_request() -> HttpRequest {
+    gen_http_request_with(&MessageOptions::default())
+}
+
+/// Returns a request built with `options`. `POST`, `PUT` and `PATCH` requests have a body.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::http::message::{gen_http_request_with, BodyKind, MessageOptions};
+/// let options = MessageOptions::default().body(BodyKind::Json);
+/// let request = gen_http_request_with(&options);
+/// if !request.body.is_empty() {
+///     let length: usize = request.header("Content-Length").unwrap().parse().unwrap();
+///     assert_eq!(length, request.body.len());
+/// }
+/// ```
+pub fn gen_http_request_with(options: &MessageOptions) -> HttpRequest {
+    let mut rnd = rand::rng();
+    let method = gen_http_method();
+    let mut url = gen_url_with(&UrlOptions::default());
+    url.userinfo = None;
+    url.fragment = None;
+    let target = match method.as_str() {
+        "CONNECT" => format!("{}:{}", url.host, url.port.as_deref().unwrap_or("443")),
+        "OPTIONS" if rnd.random_range(0..4) == 0 => String::from("*"),
+        _ => url.request_target(),
+    };
+
+    let user_agent = gen_user_agent();
+    let api = !user_agent.starts_with("Mozilla") || rnd.random_bool(0.3);
+    let mut headers = vec![
+        (
+            "Host".to_string(),
+            // CONNECT requests name the tunnel endpoint with its port
+            if method == "CONNECT" {
+                target.clone()
+            } else {
+                url.authority()
+            },
+        ),
+        ("User-Agent".to_string(), user_agent),
+        (
+            "Accept".to_string(),
+            if api {
+                "application/json".to_string()
+            } else {
+                "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8".to_string()
+            },
+        ),
+    ];
+    for name in [
+        "Accept-Encoding",
+        "Accept-Language",
+        "Authorization",
+        "Cookie",
+        "X-Request-ID",
+    ] {
+        if rnd.random_bool(0.5) {
+            let value = gen_http_header_value(name).unwrap_or_default();
+            headers.push((name.to_string(), value));
+        }
+    }
+
+    let mut body = String::new();
+    if ["POST", "PUT", "PATCH"].contains(&method.as_str()) {
+        let kind = options.body.unwrap_or_else(|| {
+            [BodyKind::Json, BodyKind::Form, BodyKind::Text][rnd.random_range(0..3)]
+        });
+        body = gen_request_body(kind, &mut rnd);
+        push_body_headers(&mut headers, kind, &body, options.chunked);
+    }
+
+    HttpRequest {
+        chunked: options.chunked && !body.is_empty(),
+        method,
+        url,
+        target,
+        headers,
+        body,
+    }
+}
+
+/// Returns a response to `request` built with `options`, dated now.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::http::message::{gen_http_request, gen_http_response, MessageOptions};
+/// let request = gen_http_request();
+/// let response = gen_http_response(&request, &MessageOptions::default().chunked(true));
+/// // response.to_string() => "HTTP/1.1 200 OK\r\nDate: Tue, 14 Nov 2023 22:13:20 GMT\r\n..."
+/// if !response.body.is_empty() {
+///     assert_eq!(response.header("Transfer-Encoding"), Some("chunked"));
+///     assert!(response.to_string().ends_with("0\r\n\r\n"));
+/// }
+/// ```
+pub fn gen_http_response(request: &HttpRequest, options: &MessageOptions) -> HttpResponse {
+    response_at(request, options, datetime::now())
+}
+
+fn response_at(request: &HttpRequest, options: &MessageOptions, timestamp: i64) -> HttpResponse {
+    let mut rnd = rand::rng();
+    let connect = request.method == "CONNECT";
+    let status = if connect {
+        HttpStatus::from_code(200).unwrap()
+    } else {
+        // informational responses are always followed by a final one
+        let mut status = gen_http_status();
+        while status.class() == StatusClass::Informational {
+            status = gen_http_status();
+        }
+        status
+    };
+
+    let mut headers = vec![
+        ("Date".to_string(), datetime::http_date(timestamp)),
+        (
+            "Server".to_string(),
+            SERVERS[rnd.random_range(0..SERVERS.len())].to_string(),
+        ),
+    ];
+    match status.code {
+        201 | 300..=303 | 305..=308 => {
+            let location = gen_url_with(&UrlOptions::default().https_only(true));
+            headers.push(("Location".to_string(), location.to_string()));
+        }
+        401 => headers.push((
+            "WWW-Authenticate".to_string(),
+            "Bearer realm=\"api\"".to_string(),
+        )),
+        405 => headers.push(("Allow".to_string(), "GET, HEAD, POST".to_string())),
+        429 | 503 => headers.push((
+            "Retry-After".to_string(),
+            rnd.random_range(1..=120).to_string(),
+        )),
+        _ => {}
+    }
+    if status.code == 200 && !connect {
+        let tag: [u8; 8] = rnd.random();
+        headers.push(("ETag".to_string(), format!("\"{}\"", to_hex(&tag))));
+        let value = gen_http_header_value("Cache-Control").unwrap_or_default();
+        headers.push(("Cache-Control".to_string(), value));
+    }
+
+    // successful CONNECT responses switch to a tunnel, 204 and 304 never have content
+    if connect || status.code == 204 || status.code == 304 {
+        return HttpResponse {
+            status,
+            headers,
+            body: String::new(),
+            chunked: false,
+        };
+    }
+    let kind = options
+        .body
+        .unwrap_or_else(|| match request.header("Accept") {
+            Some("application/json") => BodyKind::Json,
+            _ => BodyKind::Text,
+        });
+    let body = gen_response_body(kind, &status, &mut rnd);
+    if request.method == "HEAD" {
+        // the headers describe the representation a GET would return
+        headers.push(("Content-Type".to_string(), kind.content_type().to_string()));
+        headers.push(("Content-Length".to_string(), body.len().to_string()));
+        return HttpResponse {
+            status,
+            headers,
+            body: String::new(),
+            chunked: false,
+        };
+    }
+    push_body_headers(&mut headers, kind, &body, options.chunked);
+    HttpResponse {
+        status,
+        headers,
+        body,
+        chunked: options.chunked,
+    }
+}
+
+/// A request with its response and timings, an entry of a HAR file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpExchange {
+    pub request: HttpRequest,
+    pub response: HttpResponse,
+    /// Start of the request in milliseconds since the Unix epoch.
+    pub started: i64,
+    /// Milliseconds to send the request.
+    pub send: u32,
+    /// Milliseconds waiting for the first byte of the response.
+    pub wait: u32,
+    /// Milliseconds to receive the response.
+    pub receive: u32,
+}
+
+impl HttpExchange {
+    /// The total time of the exchange in milliseconds.
+    pub fn time(&self) -> u32 {
+        self.send + self.wait + self.receive
+    }
+}
+
+/// Returns a request with a matching response, started within the last hour.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::http::message::{gen_http_exchange, MessageOptions};
+/// let exchange = gen_http_exchange(&MessageOptions::default());
+/// assert_eq!(exchange.time(), exchange.send + exchange.wait + exchange.receive);
+/// ```
+pub fn gen_http_exchange(options: &MessageOptions) -> HttpExchange {
+    let mut rnd = rand::rng();
+    let request = gen_http_request_with(options);
+    let started = datetime::now() * 1000 - rnd.random_range(0..3_600_000);
+    let send = rnd.random_range(0..5);
+    let wait = rnd.random_range(5..800);
+    let receive = rnd.random_range(0..50);
+    let response = response_at(&request, options, (started + i64::from(send + wait)) / 1000);
+    HttpExchange {
+        request,
+        response,
+        started,
+        send,
+        wait,
+        receive,
+    }
+}
+
+/// HAR name/value objects for `headers`.
+fn har_headers(headers: &[(String, String)]) -> Value {
+    headers
+        .iter()
+        .map(|(name, value)| json!({ "name": name, "value": value }))
+        .collect()
+}
+
+/// Size of start line and headers in bytes, including the empty line.
+fn headers_size(start_line: &str, headers: &[(String, String)]) -> usize {
+    start_line.len()
+        + 2
+        + headers
+            .iter()
+            .map(|(name, value)| name.len() + value.len() + 4)
+            .sum::<usize>()
+        + 2
+}
+
+/// Size of the body as transferred, with chunked encoding if used.
+fn transfer_size(body: &str, chunked: bool) -> usize {
+    if chunked {
+        encode_chunked(body).len()
+    } else {
+        body.len()
+    }
+}
+
+/// Returns `exchanges` as HAR 1.2 document, which browser developer tools and proxies can import.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::http::message::{gen_http_exchange, to_har, MessageOptions};
+/// let exchanges: Vec<_> = (0..3).map(|_| gen_http_exchange(&MessageOptions::default())).collect();
+/// let har = to_har(&exchanges);
+/// // har.to_string() => {"log":{"creator":{...},"entries":[...],"version":"1.2"}}
+/// assert_eq!(har["log"]["entries"].as_array().unwrap().len(), 3);
+/// ```
+pub fn to_har(exchanges: &[HttpExchange]) -> Value {
+    let entries: Vec<Value> = exchanges
+        .iter()
+        .map(|exchange| {
+            let request = &exchange.request;
+            let response = &exchange.response;
+            let query: Vec<Value> = request
+                .url
+                .query
+                .as_deref()
+                .unwrap_or_default()
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| {
+                    let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    json!({ "name": name, "value": value })
+                })
+                .collect();
+            let mut har_request = json!({
+                "method": request.method,
+                "url": request.url.to_string(),
+                "httpVersion": "HTTP/1.1",
+                "cookies": [],
+                "headers": har_headers(&request.headers),
+                "queryString": query,
+                "headersSize": headers_size(&request.request_line(), &request.headers),
+                "bodySize": transfer_size(&request.body, request.chunked),
+            });
+            if !request.body.is_empty() {
+                har_request["postData"] = json!({
+                    "mimeType": request.header("Content-Type").unwrap_or_default(),
+                    "text": request.body,
+                });
+            }
+            json!({
+                "startedDateTime": datetime::rfc3339_millis(exchange.started),
+                "time": exchange.time(),
+                "request": har_request,
+                "response": {
+                    "status": response.status.code,
+                    "statusText": response.status.reason,
+                    "httpVersion": "HTTP/1.1",
+                    "cookies": [],
+                    "headers": har_headers(&response.headers),
+                    "content": {
+                        "size": response.body.len(),
+                        "mimeType": response.header("Content-Type").unwrap_or("x-unknown"),
+                        "text": response.body,
+                    },
+                    "redirectURL": response.header("Location").unwrap_or_default(),
+                    "headersSize": headers_size(&response.status_line(), &response.headers),
+                    "bodySize": transfer_size(&response.body, response.chunked),
+                },
+                "cache": {},
+                "timings": {
+                    "send": exchange.send,
+                    "wait": exchange.wait,
+                    "receive": exchange.receive,
+                },
+            })
+        })
+        .collect();
+    json!({
+        "log": {
+            "version": "1.2",
+            "creator": {
+                "name": env!("CARGO_PKG_NAME"),
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "entries": entries,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode a chunked body, returns `None` for invalid encodings.
+    fn decode_chunked(mut input: &str) -> Option<String> {
+        let mut body = String::new();
+        loop {
+            let (size, rest) = input.split_once("\r\n")?;
+            let size = usize::from_str_radix(size, 16).ok()?;
+            if size == 0 {
+                return (rest == "\r\n").then_some(body);
+            }
+            body.push_str(rest.get(..size)?);
+            input = rest[size..].strip_prefix("\r\n")?;
+        }
+    }
+
+    #[test]
+    fn test_chunked_encoding() {
+        let body = "ä".repeat(100);
+        assert_eq!(decode_chunked(&encode_chunked(&body)).unwrap(), body);
+        assert_eq!(encode_chunked(""), "0\r\n\r\n");
+        assert_eq!(encode_chunked("hello"), "5\r\nhello\r\n0\r\n\r\n");
+        assert_eq!(form_encode("a b&c"), "a+b%26c");
+    }
+
+    #[test]
+    fn test_messages() {
+        for chunked in [false, true] {
+            let options = MessageOptions::default().chunked(chunked);
+            for _ in 0..25 {
+                let request = gen_http_request_with(&options);
+                let raw = request.to_string();
+                let (head, body) = raw.split_once("\r\n\r\n").unwrap();
+                assert!(head.starts_with(&request.request_line()));
+                if request.method == "CONNECT" {
+                    assert_eq!(request.header("Host"), Some(request.target.as_str()));
+                } else {
+                    assert_eq!(
+                        request.header("Host"),
+                        Some(request.url.authority().as_str())
+                    );
+                }
+                match request.header("Content-Length") {
+                    Some(length) => assert_eq!(length.parse::<usize>().unwrap(), body.len()),
+                    None if request.chunked => {
+                        assert_eq!(decode_chunked(body).unwrap(), request.body)
+                    }
+                    None => assert!(body.is_empty()),
+                }
+
+                let response = gen_http_response(&request, &options);
+                let raw = response.to_string();
+                let (head, body) = raw.split_once("\r\n\r\n").unwrap();
+                assert!(head.starts_with("HTTP/1.1 "));
+                assert_ne!(response.status.class(), StatusClass::Informational);
+                if request.method == "HEAD" || [204, 304].contains(&response.status.code) {
+                    assert!(body.is_empty());
+                } else if response.chunked {
+                    assert_eq!(decode_chunked(body).unwrap(), response.body);
+                    assert!(response.header("Content-Length").is_none());
+                } else if request.method != "CONNECT" {
+                    let length: usize = response.header("Content-Length").unwrap().parse().unwrap();
+                    assert_eq!(length, body.len());
+                }
+                if (301..=303).contains(&response.status.code) {
+                    assert!(response.header("Location").is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_har() {
+        let exchanges: Vec<HttpExchange> = (0..20)
+            .map(|_| gen_http_exchange(&MessageOptions::default()))
+            .collect();
+        let har = to_har(&exchanges);
+        let entries = har["log"]["entries"].as_array().unwrap();
+        assert_eq!(har["log"]["version"], "1.2");
+        for (entry, exchange) in entries.iter().zip(&exchanges) {
+            assert_eq!(entry["request"]["method"], exchange.request.method.as_str());
+            assert_eq!(entry["response"]["status"], exchange.response.status.code);
+            assert_eq!(entry["time"], exchange.time());
+            let raw = exchange.request.to_string();
+            let head = raw.split_once("\r\n\r\n").unwrap().0;
+            assert_eq!(entry["request"]["headersSize"], head.len() + 4);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Module id provides the random identifiers several generators share, like UUIDs and hex ids.
+
+use rand::Rng;
+
+/// Lowercase hexadecimal representation of `bytes`.
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Returns `bytes` random bytes as lowercase hex, e.g. 16 bytes for a W3C trace id.
+pub(crate) fn random_hex(rnd: &mut impl Rng, bytes: usize) -> String {
+    let bytes: Vec<u8> = (0..bytes).map(|_| rnd.random()).collect();
+    to_hex(&bytes)
+}
+
+/// Returns `length` random decimal digits.
+pub(crate) fn random_digits(rnd: &mut impl Rng, length: usize) -> String {
+    (0..length)
+        .map(|_| char::from(b'0' + rnd.random_range(0..10u8)))
+        .collect()
+}
+
+/// Returns a random UUID version 4.
+pub(crate) fn uuid_v4(rnd: &mut impl Rng) -> String {
+    let mut bytes: [u8; 16] = rnd.random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = to_hex(&bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid_v4() {
+        let uuid = uuid_v4(&mut rand::rng());
+        let groups: Vec<&str> = uuid.split('-').collect();
+        assert_eq!(
+            groups.iter().map(|g| g.len()).collect::<Vec<_>>(),
+            [8, 4, 4, 4, 12]
+        );
+        assert!(groups[2].starts_with('4'));
+        assert!("89ab".contains(&groups[3][..1]));
+        assert_eq!(to_hex(&[0, 15, 255]), "000fff");
+        assert_eq!(random_hex(&mut rand::rng(), 8).len(), 16);
+    }
+}
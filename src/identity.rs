@@ -0,0 +1,444 @@
+//! Module identity provides syntactically valid national identifiers with correct check digits, and
+//! a validator for each format so tests can assert that corrupted values are rejected.
+//!
+//! The values are random: they are valid by format and checksum, but not registered to anyone.
+
+use rand::Rng;
+
+use crate::checksum::{digits, luhn_check_digit, luhn_valid};
+
+/// Letters used for the control letter of Spanish DNI and NIE numbers.
+const DNI_LETTERS: &[u8] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+
+/// Supported national identifier formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NationalIdKind {
+    /// US Social Security Number `AAA-GG-SSSS`, never using the invalid areas 000, 666 and 900-999.
+    UsSsn,
+    /// Canadian Social Insurance Number `NNN NNN NNN` with Luhn check digit.
+    CaSin,
+    /// UK National Insurance number `AB 12 34 56 C`.
+    UkNino,
+    /// German tax identification number (Steuer-ID), 11 digits with ISO 7064 MOD 11,10 check digit.
+    DeSteuerId,
+    /// Dutch citizen service number (BSN), 9 digits passing the 11-proef.
+    NlBsn,
+    /// Spanish national identity number (DNI), 8 digits and a control letter.
+    EsDni,
+    /// Spanish foreigner identity number (NIE), `X`, `Y` or `Z`, 7 digits and a control letter.
+    EsNie,
+    /// Brazilian individual taxpayer number (CPF) `NNN.NNN.NNN-NN`.
+    BrCpf,
+    /// Brazilian company number (CNPJ) `NN.NNN.NNN/NNNN-NN`.
+    BrCnpj,
+}
+
+impl NationalIdKind {
+    /// All supported formats.
+    pub const ALL: [NationalIdKind; 9] = [
+        NationalIdKind::UsSsn,
+        NationalIdKind::CaSin,
+        NationalIdKind::UkNino,
+        NationalIdKind::DeSteuerId,
+        NationalIdKind::NlBsn,
+        NationalIdKind::EsDni,
+        NationalIdKind::EsNie,
+        NationalIdKind::BrCpf,
+        NationalIdKind::BrCnpj,
+    ];
+
+    /// Generate an identifier of this kind in its usual written format.
+    pub fn generate(&self) -> String {
+        let mut rnd = rand::rng();
+        match self {
+            NationalIdKind::UsSsn => gen_us_ssn(&mut rnd),
+            NationalIdKind::CaSin => gen_ca_sin(&mut rnd),
+            NationalIdKind::UkNino => gen_uk_nino(&mut rnd),
+            NationalIdKind::DeSteuerId => gen_de_steuer_id(&mut rnd),
+            NationalIdKind::NlBsn => gen_nl_bsn(&mut rnd),
+            NationalIdKind::EsDni => gen_es_dni(&mut rnd),
+            NationalIdKind::EsNie => gen_es_nie(&mut rnd),
+            NationalIdKind::BrCpf => gen_br_cpf(&mut rnd),
+            NationalIdKind::BrCnpj => gen_br_cnpj(&mut rnd),
+        }
+    }
+
+    /// Returns `true` if `value` is a valid identifier of this kind. Spaces, dashes, dots and
+    /// slashes are ignored, so both formatted and plain values are accepted.
+    pub fn validate(&self, value: &str) -> bool {
+        let clean: String = value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '.' | '/'))
+            .collect::<String>()
+            .to_uppercase();
+        match self {
+            NationalIdKind::UsSsn => valid_us_ssn(&clean),
+            NationalIdKind::CaSin => valid_ca_sin(&clean),
+            NationalIdKind::UkNino => valid_uk_nino(&clean),
+            NationalIdKind::DeSteuerId => valid_de_steuer_id(&clean),
+            NationalIdKind::NlBsn => valid_nl_bsn(&clean),
+            NationalIdKind::EsDni => valid_es_dni(&clean),
+            NationalIdKind::EsNie => valid_es_nie(&clean),
+            NationalIdKind::BrCpf => valid_br_cpf(&clean),
+            NationalIdKind::BrCnpj => valid_br_cnpj(&clean),
+        }
+    }
+}
+
+/// Returns a random national identifier of the given kind.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_national_id;
+/// use fakedata_generator::identity::NationalIdKind;
+/// let ssn: String = gen_national_id(NationalIdKind::UsSsn);
+/// // ssn => "219-09-9999"
+/// assert!(NationalIdKind::UsSsn.validate(&ssn));
+/// ```
+pub fn gen_national_id(kind: NationalIdKind) -> String {
+    kind.generate()
+}
+
+/// Returns `true` if `value` is a valid national identifier of the given kind.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::identity::{validate_national_id, NationalIdKind};
+/// assert!(validate_national_id(NationalIdKind::NlBsn, "111222333"));
+/// assert!(!validate_national_id(NationalIdKind::NlBsn, "111222334"));
+/// ```
+pub fn validate_national_id(kind: NationalIdKind, value: &str) -> bool {
+    kind.validate(value)
+}
+
+fn random_digits(n: usize, rnd: &mut impl Rng) -> Vec<u32> {
+    (0..n).map(|_| rnd.random_range(0..10)).collect()
+}
+
+fn join_digits(digits: &[u32]) -> String {
+    digits.iter().map(|d| d.to_string()).collect()
+}
+
+fn all_digits(value: &str, len: usize) -> bool {
+    value.len() == len && value.chars().all(|c| c.is_ascii_digit())
+}
+
+// US SSN
+
+fn gen_us_ssn(rnd: &mut impl Rng) -> String {
+    let mut area = rnd.random_range(1..900);
+    while area == 666 {
+        area = rnd.random_range(1..900);
+    }
+    format!(
+        "{:03}-{:02}-{:04}",
+        area,
+        rnd.random_range(1..100),
+        rnd.random_range(1..10000)
+    )
+}
+
+fn valid_us_ssn(value: &str) -> bool {
+    if !all_digits(value, 9) {
+        return false;
+    }
+    let area: u32 = value[0..3].parse().unwrap_or(0);
+    let group: u32 = value[3..5].parse().unwrap_or(0);
+    let serial: u32 = value[5..9].parse().unwrap_or(0);
+    area != 0 && area != 666 && area < 900 && group != 0 && serial != 0
+}
+
+// Canadian SIN
+
+fn gen_ca_sin(rnd: &mut impl Rng) -> String {
+    // 0 is not assigned and 8 is used for business numbers
+    let first = [1, 2, 3, 4, 5, 6, 7, 9][rnd.random_range(0..8)];
+    let mut sin = format!("{}{}", first, join_digits(&random_digits(7, rnd)));
+    sin.push(char::from(b'0' + luhn_check_digit(&sin)));
+    format!("{} {} {}", &sin[0..3], &sin[3..6], &sin[6..9])
+}
+
+fn valid_ca_sin(value: &str) -> bool {
+    all_digits(value, 9) && !value.starts_with(['0', '8']) && luhn_valid(value)
+}
+
+// UK National Insurance number
+
+const NINO_FIRST: &[u8] = b"ABCEGHJKLMNOPRSTWXYZ";
+const NINO_SECOND: &[u8] = b"ABCEGHJKLMNPRSTWXYZ";
+const NINO_INVALID_PREFIXES: [&str; 7] = ["BG", "GB", "NK", "KN", "TN", "NT", "ZZ"];
+
+fn gen_uk_nino(rnd: &mut impl Rng) -> String {
+    let prefix = loop {
+        let prefix = format!(
+            "{}{}",
+            char::from(NINO_FIRST[rnd.random_range(0..NINO_FIRST.len())]),
+            char::from(NINO_SECOND[rnd.random_range(0..NINO_SECOND.len())])
+        );
+        if !NINO_INVALID_PREFIXES.contains(&prefix.as_str()) {
+            break prefix;
+        }
+    };
+    let d = join_digits(&random_digits(6, rnd));
+    let suffix = char::from(b'A' + rnd.random_range(0..4u8));
+    format!(
+        "{} {} {} {} {}",
+        prefix,
+        &d[0..2],
+        &d[2..4],
+        &d[4..6],
+        suffix
+    )
+}
+
+fn valid_uk_nino(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    value.len() == 9
+        && value.is_ascii()
+        && NINO_FIRST.contains(&bytes[0])
+        && NINO_SECOND.contains(&bytes[1])
+        && !NINO_INVALID_PREFIXES.contains(&&value[0..2])
+        && all_digits(&value[2..8], 6)
+        && (b'A'..=b'D').contains(&bytes[8])
+}
+
+// German Steuer-ID
+
+/// ISO 7064 MOD 11,10 check digit as used by the German Steuer-ID.
+fn mod11_10_check_digit(payload: &[u32]) -> u32 {
+    let mut product = 10;
+    for d in payload {
+        let mut sum = (d + product) % 10;
+        if sum == 0 {
+            sum = 10;
+        }
+        product = (sum * 2) % 11;
+    }
+    let check = 11 - product;
+    if check == 10 { 0 } else { check }
+}
+
+fn gen_de_steuer_id(rnd: &mut impl Rng) -> String {
+    // the first 10 digits use 9 different digits, one of them twice, and never start with 0
+    let mut pool: Vec<u32> = (0..10).collect();
+    pool.remove(rnd.random_range(0..10));
+    let twice = pool[rnd.random_range(0..pool.len())];
+    pool.push(twice);
+    loop {
+        for i in (1..pool.len()).rev() {
+            pool.swap(i, rnd.random_range(0..=i));
+        }
+        if pool[0] != 0 {
+            break;
+        }
+    }
+    let check = mod11_10_check_digit(&pool);
+    format!("{}{}", join_digits(&pool), check)
+}
+
+fn valid_de_steuer_id(value: &str) -> bool {
+    if !all_digits(value, 11) || value.starts_with('0') {
+        return false;
+    }
+    let d: Vec<u32> = digits(value).collect();
+    let mut counts = [0; 10];
+    for x in &d[..10] {
+        counts[*x as usize] += 1;
+    }
+    // exactly one digit appears two or three times, all others at most once
+    let repeated: Vec<usize> = counts.iter().copied().filter(|c| *c > 1).collect();
+    if repeated.len() != 1 || repeated[0] > 3 {
+        return false;
+    }
+    // a digit used three times may not appear three times in a row
+    if repeated[0] == 3 && d[..10].windows(3).any(|w| w[0] == w[1] && w[1] == w[2]) {
+        return false;
+    }
+    mod11_10_check_digit(&d[..10]) == d[10]
+}
+
+// Dutch BSN
+
+fn bsn_weighted_sum(d: &[u32]) -> u32 {
+    d.iter().zip((2..=9).rev()).map(|(d, w)| d * w).sum()
+}
+
+fn gen_nl_bsn(rnd: &mut impl Rng) -> String {
+    loop {
+        let mut d = random_digits(8, rnd);
+        d[0] = rnd.random_range(1..10);
+        let check = bsn_weighted_sum(&d) % 11;
+        if check < 10 {
+            d.push(check);
+            return join_digits(&d);
+        }
+    }
+}
+
+fn valid_nl_bsn(value: &str) -> bool {
+    if !all_digits(value, 9) {
+        return false;
+    }
+    let d: Vec<u32> = digits(value).collect();
+    let sum = bsn_weighted_sum(&d[..8]) as i64 - d[8] as i64;
+    sum > 0 && sum % 11 == 0
+}
+
+// Spanish DNI and NIE
+
+fn dni_letter(number: u32) -> char {
+    char::from(DNI_LETTERS[(number % 23) as usize])
+}
+
+fn gen_es_dni(rnd: &mut impl Rng) -> String {
+    let number = rnd.random_range(0..100_000_000);
+    format!("{:08}{}", number, dni_letter(number))
+}
+
+fn valid_es_dni(value: &str) -> bool {
+    if value.len() != 9 || !all_digits(&value[..8], 8) {
+        return false;
+    }
+    let number: u32 = value[..8].parse().unwrap_or(0);
+    value.ends_with(dni_letter(number))
+}
+
+fn gen_es_nie(rnd: &mut impl Rng) -> String {
+    let prefix = rnd.random_range(0..3);
+    let number = rnd.random_range(0..10_000_000);
+    let letter = dni_letter(prefix * 10_000_000 + number);
+    format!("{}{:07}{}", char::from(b'X' + prefix as u8), number, letter)
+}
+
+fn valid_es_nie(value: &str) -> bool {
+    if value.len() != 9 || !value.is_ascii() || !all_digits(&value[1..8], 7) {
+        return false;
+    }
+    let prefix = match value.as_bytes()[0] {
+        b'X' => 0,
+        b'Y' => 1,
+        b'Z' => 2,
+        _ => return false,
+    };
+    let number: u32 = value[1..8].parse().unwrap_or(0);
+    value.ends_with(dni_letter(prefix * 10_000_000 + number))
+}
+
+// Brazilian CPF and CNPJ
+
+/// Check digit used by CPF and CNPJ: weighted sum mod 11, `0` for remainders below 2.
+fn br_check_digit(d: &[u32], weights: &[u32]) -> u32 {
+    let sum: u32 = d.iter().zip(weights).map(|(d, w)| d * w).sum();
+    let rem = sum % 11;
+    if rem < 2 { 0 } else { 11 - rem }
+}
+
+const CPF_WEIGHTS: [u32; 10] = [11, 10, 9, 8, 7, 6, 5, 4, 3, 2];
+const CNPJ_WEIGHTS: [u32; 13] = [6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+fn gen_br_cpf(rnd: &mut impl Rng) -> String {
+    let mut d = random_digits(9, rnd);
+    // numbers with all digits equal are invalid
+    d[8] = (d[0] + 1 + rnd.random_range(0..9)) % 10;
+    d.push(br_check_digit(&d, &CPF_WEIGHTS[1..]));
+    d.push(br_check_digit(&d, &CPF_WEIGHTS));
+    let s = join_digits(&d);
+    format!("{}.{}.{}-{}", &s[0..3], &s[3..6], &s[6..9], &s[9..11])
+}
+
+fn valid_br_cpf(value: &str) -> bool {
+    if !all_digits(value, 11) {
+        return false;
+    }
+    let d: Vec<u32> = digits(value).collect();
+    d.iter().any(|x| *x != d[0])
+        && br_check_digit(&d[..9], &CPF_WEIGHTS[1..]) == d[9]
+        && br_check_digit(&d[..10], &CPF_WEIGHTS) == d[10]
+}
+
+fn gen_br_cnpj(rnd: &mut impl Rng) -> String {
+    let mut d = random_digits(8, rnd);
+    d[7] = (d[0] + 1 + rnd.random_range(0..9)) % 10;
+    // branch number, 0001 is the head office
+    d.extend_from_slice(&[0, 0, 0, rnd.random_range(1..10)]);
+    d.push(br_check_digit(&d, &CNPJ_WEIGHTS[1..]));
+    d.push(br_check_digit(&d, &CNPJ_WEIGHTS));
+    let s = join_digits(&d);
+    format!(
+        "{}.{}.{}/{}-{}",
+        &s[0..2],
+        &s[2..5],
+        &s[5..8],
+        &s[8..12],
+        &s[12..14]
+    )
+}
+
+fn valid_br_cnpj(value: &str) -> bool {
+    if !all_digits(value, 14) {
+        return false;
+    }
+    let d: Vec<u32> = digits(value).collect();
+    d.iter().any(|x| *x != d[0])
+        && br_check_digit(&d[..12], &CNPJ_WEIGHTS[1..]) == d[12]
+        && br_check_digit(&d[..13], &CNPJ_WEIGHTS) == d[13]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_ids_are_valid() {
+        for kind in NationalIdKind::ALL {
+            for _ in 0..200 {
+                let id = gen_national_id(kind);
+                assert!(kind.validate(&id), "{:?} {}", kind, id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_corrupted_ids_are_rejected() {
+        // SSN and NINO have no check digit
+        let kinds = NationalIdKind::ALL
+            .into_iter()
+            .filter(|k| *k != NationalIdKind::UsSsn && *k != NationalIdKind::UkNino);
+        for kind in kinds {
+            for _ in 0..50 {
+                let id = gen_national_id(kind);
+                let mut chars: Vec<char> = id.chars().collect();
+                let last = chars.len() - 1;
+                chars[last] = match chars[last] {
+                    '9' => '0',
+                    'T' => 'R',
+                    c if c.is_ascii_digit() => char::from(c as u8 + 1),
+                    _ => 'T',
+                };
+                let corrupted: String = chars.into_iter().collect();
+                assert!(!kind.validate(&corrupted), "{:?} {}", kind, corrupted);
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_values() {
+        assert!(NationalIdKind::UsSsn.validate("078-05-1120"));
+        assert!(!NationalIdKind::UsSsn.validate("666-05-1120"));
+        assert!(!NationalIdKind::UsSsn.validate("900-05-1120"));
+        assert!(!NationalIdKind::UsSsn.validate("000-05-1120"));
+        assert!(NationalIdKind::CaSin.validate("130 210 008"));
+        assert!(!NationalIdKind::CaSin.validate("046 454 286"));
+        assert!(NationalIdKind::UkNino.validate("AB 12 34 56 C"));
+        assert!(!NationalIdKind::UkNino.validate("QQ 12 34 56 C"));
+        assert!(!NationalIdKind::UkNino.validate("GB 12 34 56 C"));
+        assert!(NationalIdKind::DeSteuerId.validate("86095742719"));
+        assert!(!NationalIdKind::DeSteuerId.validate("86095742718"));
+        assert!(NationalIdKind::NlBsn.validate("111222333"));
+        assert!(NationalIdKind::EsDni.validate("12345678Z"));
+        assert!(NationalIdKind::EsNie.validate("X1234567L"));
+        assert!(NationalIdKind::BrCpf.validate("111.444.777-35"));
+        assert!(!NationalIdKind::BrCpf.validate("111.111.111-11"));
+        assert!(NationalIdKind::BrCnpj.validate("11.222.333/0001-81"));
+    }
+}
@@ -2,7 +2,39 @@ extern crate rand;
 use passt::Passt;
 use rand::Rng;
 
+pub mod address;
+pub mod checksum;
+pub mod company;
+pub mod context;
 pub mod data;
+mod datetime;
+pub mod domain;
+pub mod finance;
+pub mod geo;
+pub mod http;
+mod id;
+pub mod identity;
+pub mod logs;
+pub mod metrics;
+pub mod password;
+pub mod phone;
+pub mod product;
+pub mod text;
+pub mod trace;
+pub mod url;
+
+pub use address::gen_address;
+pub use company::{gen_company_name, gen_department, gen_job_title};
+pub use finance::bank::gen_iban;
+pub use finance::card::gen_payment_card;
+pub use finance::currency::gen_amount;
+pub use http::{gen_http_header, gen_http_status, gen_mime_type, gen_user_agent};
+pub use identity::gen_national_id;
+pub use password::{gen_passphrase, gen_password_with_policy};
+pub use phone::gen_phone_number;
+pub use product::gen_isbn;
+pub use text::{gen_paragraph, gen_sentence, gen_text, gen_words};
+pub use url::gen_url;
 
 fn parse_args_to_vec(input: &str) -> Vec<&str> {
     let args: Vec<&str> = input.split(",").clone().collect();
@@ -52,34 +84,27 @@ pub fn gen_password_with_special_chars(password_length: usize) -> String {
     return password;
 }
 
-/// Generate a random domain name from a small list of predefined values
-///
-/// Name values:
-/// * some
-/// * random
-/// * names
-/// * we
-/// * make
-/// * up
-/// * for
-/// * testing
-///
-/// Possible Top-Level Domains (TLDs): list based on <https://data.iana.org/TLD/tlds-alpha-by-domain.txt>
+/// Generate a random domain name from the corpora words with a generic or country code TLD taken
+/// from <https://data.iana.org/TLD/tlds-alpha-by-domain.txt>. See the [`domain`] module for
+/// subdomains, other TLD categories and hostnames. While safe mode is enabled with
+/// [`context::set_safe_mode`] only reserved names like `example.com` are returned.
 ///
 /// ## Example
 /// ```rust
 /// use fakedata_generator::gen_domain;
 /// let domain = gen_domain();
-/// // domain => names.eu
+/// // domain => amber-fig.eu
+/// assert!(fakedata_generator::domain::is_valid_hostname(&domain));
 /// ```
 pub fn gen_domain() -> String {
-    let tld = gen_enum(data::gen_switch("tlds".to_string()));
-    let domain = gen_enum(String::from("some,random,names,we,make,up,for,testing"));
-    return format!("{}.{}", &domain, &tld);
+    domain::gen_domain_with(&domain::DomainOptions::default())
 }
 
 /// Return a randomly generated e-Mail address. This generator uses the `gen_username` generator.
 ///
+/// The domains may belong to real mail hosts. While safe mode is enabled with
+/// [`context::set_safe_mode`] only reserved domains are used, like with [`gen_safe_email`].
+///
 /// ## Example
 /// ```rust
 /// use fakedata_generator::gen_email;
@@ -88,6 +113,9 @@ pub fn gen_domain() -> String {
 /// assert!(email.contains("@"));
 /// ```
 pub fn gen_email() -> String {
+    if context::safe_mode() {
+        return gen_safe_email();
+    }
     let user = gen_username();
     let tld: String = gen_enum("de,org,com,net,io,email,dev".to_string());
     let domain: String = gen_enum(
@@ -99,6 +127,22 @@ pub fn gen_email() -> String {
     return format!("{}@{}", &user, &email);
 }
 
+/// Return a randomly generated e-Mail address at a domain reserved by RFC 2606 and RFC 6761, like
+/// `example.org` or `amber-fig.test`, which can never be delivered to a real mailbox.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_safe_email;
+/// let email: String = gen_safe_email();
+/// // email => "ribbon@amber-fig.test"
+/// let (_, domain) = email.split_once('@').unwrap();
+/// assert!(fakedata_generator::domain::is_reserved(domain));
+/// ```
+pub fn gen_safe_email() -> String {
+    let domain = domain::gen_domain_with(&domain::DomainOptions::default().safe(true));
+    format!("{}@{}", gen_username(), domain)
+}
+
 /// Return random string from set of specified strings. Specify a comma separated list as argument.
 ///
 /// ## Example
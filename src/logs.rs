@@ -0,0 +1,19 @@
+//! Module logs provides log lines in the formats observability pipelines and SIEMs ingest.
+
+pub mod access;
+pub mod security;
+pub mod structured;
+
+/// Names of the services which write logs, shared by the log and trace generators.
+pub(crate) const SERVICE_NAMES: [&str; 10] = [
+    "api-gateway",
+    "auth",
+    "checkout",
+    "payments",
+    "inventory",
+    "search",
+    "notifications",
+    "orders",
+    "users",
+    "recommendations",
+];
@@ -0,0 +1,571 @@
+//! Module access provides web server and load balancer access logs: nginx `log_format main`, IIS
+//! W3C Extended, AWS Application and Classic Load Balancer, and Google Cloud HTTP(S) Load
+//! Balancing.
+//!
+//! An [`AccessLogEntry`] holds one request independent of the format. Its times add up: the
+//! response is logged when the request started plus the time spent in the load balancer and the
+//! backend, and sent bytes are the response headers plus the body.
+
+use rand::Rng;
+use serde_json::json;
+
+use crate::datetime;
+use crate::domain::to_label;
+use crate::http::{HttpStatus, StatusClass, gen_http_status, gen_user_agent};
+use crate::id::random_hex;
+use crate::url::gen_request_path;
+use crate::{gen_domain, gen_http_method, gen_ipv4, gen_private_ipv4, gen_username};
+
+/// TLS protocol and OpenSSL cipher names as logged by load balancers.
+const TLS_CIPHERS: [(&str, &str); 6] = [
+    ("TLSv1.3", "TLS_AES_128_GCM_SHA256"),
+    ("TLSv1.3", "TLS_AES_256_GCM_SHA384"),
+    ("TLSv1.3", "TLS_CHACHA20_POLY1305_SHA256"),
+    ("TLSv1.2", "ECDHE-RSA-AES128-GCM-SHA256"),
+    ("TLSv1.2", "ECDHE-ECDSA-AES256-GCM-SHA384"),
+    ("TLSv1.2", "ECDHE-RSA-CHACHA20-POLY1305"),
+];
+
+const AWS_REGIONS: [&str; 6] = [
+    "us-east-1",
+    "us-east-2",
+    "us-west-2",
+    "eu-west-1",
+    "eu-central-1",
+    "ap-southeast-2",
+];
+
+/// The `#Fields:` directive of [`AccessLogFormat::IisW3c`] entries.
+pub const IIS_FIELDS: &str = "date time s-ip cs-method cs-uri-stem cs-uri-query s-port cs-username c-ip cs(User-Agent) cs(Referer) sc-status sc-substatus sc-win32-status sc-bytes cs-bytes time-taken";
+
+/// Access log formats for [`AccessLogEntry::format`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AccessLogFormat {
+    /// The `main` format of the default `nginx.conf`.
+    #[default]
+    Nginx,
+    /// IIS W3C Extended Log File Format with the fields of [`IIS_FIELDS`].
+    IisW3c,
+    /// AWS Application Load Balancer.
+    AwsAlb,
+    /// AWS Classic Load Balancer (ELB), which speaks HTTP/1.1 and only counts the bytes of the
+    /// bodies.
+    AwsElb,
+    /// Google Cloud HTTP(S) Load Balancing request log as Cloud Logging JSON entry.
+    GcpLb,
+}
+
+/// One request as seen by a web server or load balancer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessLogEntry {
+    /// Time the request was received in microseconds since the Unix epoch.
+    pub received: i64,
+    /// Microseconds the load balancer spent before forwarding the request.
+    pub request_time: i64,
+    /// Microseconds the backend needed to answer, `None` if no backend answered.
+    pub backend_time: Option<i64>,
+    /// Microseconds the load balancer spent sending the response.
+    pub response_time: i64,
+    pub client_ip: String,
+    pub client_port: u16,
+    /// Backend address as `ip:port`.
+    pub backend: String,
+    /// Authenticated user, if any.
+    pub user: Option<String>,
+    pub method: String,
+    pub host: String,
+    /// Path and query of the request.
+    pub target: String,
+    /// `HTTP/1.1` or `HTTP/2.0`.
+    pub protocol: &'static str,
+    pub status: HttpStatus,
+    /// Bytes of the request, headers and body.
+    pub request_bytes: u64,
+    /// Bytes of the request body, included in `request_bytes`.
+    pub request_body_bytes: u64,
+    /// Bytes of the response headers.
+    pub header_bytes: u64,
+    /// Bytes of the response body.
+    pub body_bytes: u64,
+    pub user_agent: String,
+    pub referer: Option<String>,
+    /// TLS protocol and cipher for HTTPS requests.
+    pub tls: Option<(&'static str, &'static str)>,
+    /// Seed for the load balancer names and ids, so every format of the entry uses the same.
+    trace: String,
+}
+
+impl AccessLogEntry {
+    /// Total microseconds from receiving the request to the last byte of the response.
+    pub fn duration(&self) -> i64 {
+        self.request_time + self.backend_time.unwrap_or(0) + self.response_time
+    }
+
+    /// Time of the last byte of the response in microseconds since the Unix epoch.
+    pub fn completed(&self) -> i64 {
+        self.received + self.duration()
+    }
+
+    /// Bytes sent to the client, headers and body.
+    pub fn sent_bytes(&self) -> u64 {
+        self.header_bytes + self.body_bytes
+    }
+
+    fn scheme(&self) -> &'static str {
+        if self.tls.is_some() { "https" } else { "http" }
+    }
+
+    fn port(&self) -> u16 {
+        if self.tls.is_some() { 443 } else { 80 }
+    }
+
+    /// The request line: `GET /herbs/basil HTTP/1.1`.
+    pub fn request_line(&self) -> String {
+        format!("{} {} {}", self.method, self.target, self.protocol)
+    }
+
+    /// The entry as one log line in `format`. See [`gen_access_log`] for IIS logs with their
+    /// header directives.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use fakedata_generator::logs::access::{gen_access_log_entry, AccessLogFormat};
+    /// let entry = gen_access_log_entry();
+    /// let line = entry.format(AccessLogFormat::Nginx);
+    /// // line => "81.2.69.142 - - [14/Nov/2023:22:13:20 +0000] \"GET /herbs/basil HTTP/1.1\" 200 5120 \"-\" \"curl/8.5.0\" \"-\""
+    /// assert!(line.starts_with(&entry.client_ip));
+    /// ```
+    pub fn format(&self, format: AccessLogFormat) -> String {
+        match format {
+            AccessLogFormat::Nginx => format!(
+                "{} - {} [{}] \"{}\" {} {} \"{}\" \"{}\" \"-\"",
+                self.client_ip,
+                self.user.as_deref().unwrap_or("-"),
+                datetime::clf_date(self.completed().div_euclid(1_000_000)),
+                self.request_line(),
+                self.status.code,
+                self.body_bytes,
+                self.referer.as_deref().unwrap_or("-"),
+                self.user_agent
+            ),
+            AccessLogFormat::IisW3c => {
+                let timestamp = datetime::rfc3339(self.completed().div_euclid(1_000_000));
+                let (stem, query) = self
+                    .target
+                    .split_once('?')
+                    .unwrap_or((self.target.as_str(), "-"));
+                [
+                    &timestamp[..10],
+                    &timestamp[11..19],
+                    self.backend.split(':').next().unwrap_or("-"),
+                    &self.method,
+                    stem,
+                    query,
+                    &self.port().to_string(),
+                    self.user.as_deref().unwrap_or("-"),
+                    &self.client_ip,
+                    // W3C fields are separated by spaces, so IIS writes them as '+'
+                    &self.user_agent.replace(' ', "+"),
+                    self.referer.as_deref().unwrap_or("-"),
+                    &self.status.code.to_string(),
+                    "0",
+                    "0",
+                    &self.sent_bytes().to_string(),
+                    &self.request_bytes.to_string(),
+                    &(self.duration() / 1000).to_string(),
+                ]
+                .join(" ")
+            }
+            AccessLogFormat::AwsAlb => {
+                let (protocol, cipher) = self.tls.unwrap_or(("-", "-"));
+                let kind = match (self.tls, self.protocol) {
+                    (Some(_), "HTTP/2.0") => "h2",
+                    (Some(_), _) => "https",
+                    _ => "http",
+                };
+                let (region, account, balancer, group) = self.aws_names();
+                let target_status = self.target_status();
+                let certificate = match self.tls {
+                    Some(_) => format!(
+                        "arn:aws:acm:{}:{}:certificate/{}-{}-{}-{}-{}",
+                        region,
+                        account,
+                        &self.trace[..8],
+                        &self.trace[8..12],
+                        &self.trace[12..16],
+                        &self.trace[16..20],
+                        &self.trace[20..32]
+                    ),
+                    None => String::from("-"),
+                };
+                format!(
+                    "{} {} app/{}/{} {}:{} {} {:.3} {} {} {} {} {} {} \"{} {}://{}:{}{} {}\" \"{}\" {} {} arn:aws:elasticloadbalancing:{}:{}:targetgroup/{}/{} \"Root=1-{:08x}-{}\" \"{}\" \"{}\" 0 {} \"forward\" \"-\" \"-\" \"{}\" \"{}\" \"-\" \"-\" TID_{}",
+                    kind,
+                    datetime::rfc3339_micros(self.completed()),
+                    balancer,
+                    &self.trace[..16],
+                    self.client_ip,
+                    self.client_port,
+                    self.backend_or_dash(),
+                    self.request_time as f64 / 1e6,
+                    aws_seconds(self.backend_time, 3),
+                    aws_seconds(self.backend_time.map(|_| self.response_time), 3),
+                    self.status.code,
+                    target_status,
+                    self.request_bytes,
+                    self.sent_bytes(),
+                    self.method,
+                    self.scheme(),
+                    self.host,
+                    self.port(),
+                    self.target,
+                    self.protocol,
+                    self.user_agent,
+                    cipher,
+                    protocol,
+                    region,
+                    account,
+                    group,
+                    &self.trace[16..32],
+                    self.received.div_euclid(1_000_000),
+                    &self.trace[..24],
+                    self.host,
+                    certificate,
+                    datetime::rfc3339_micros(self.received),
+                    self.backend_or_dash(),
+                    target_status,
+                    &self.trace
+                )
+            }
+            AccessLogFormat::AwsElb => {
+                let (protocol, cipher) = self.tls.unwrap_or(("-", "-"));
+                let (_, _, balancer, _) = self.aws_names();
+                // a Classic Load Balancer only speaks HTTP/1.1 and counts the bytes of the bodies
+                format!(
+                    "{} {} {}:{} {} {:.6} {} {} {} {} {} {} \"{} {}://{}:{}{} HTTP/1.1\" \"{}\" {} {}",
+                    datetime::rfc3339_micros(self.completed()),
+                    balancer,
+                    self.client_ip,
+                    self.client_port,
+                    self.backend_or_dash(),
+                    self.request_time as f64 / 1e6,
+                    aws_seconds(self.backend_time, 6),
+                    aws_seconds(self.backend_time.map(|_| self.response_time), 6),
+                    self.status.code,
+                    self.target_status(),
+                    self.request_body_bytes,
+                    self.body_bytes,
+                    self.method,
+                    self.scheme(),
+                    self.host,
+                    self.port(),
+                    self.target,
+                    self.user_agent,
+                    cipher,
+                    protocol
+                )
+            }
+            AccessLogFormat::GcpLb => {
+                let project = self.gcp_project();
+                let severity = match self.status.code {
+                    500.. => "ERROR",
+                    400.. => "WARNING",
+                    _ => "INFO",
+                };
+                let mut entry = json!({
+                    "insertId": &self.trace[..14],
+                    "jsonPayload": {
+                        "@type": "type.googleapis.com/google.cloud.loadbalancing.type.LoadBalancerLogEntry",
+                        "statusDetails": match self.backend_time {
+                            Some(_) => "response_sent_by_backend",
+                            None => "failed_to_pick_backend",
+                        },
+                        "backendTargetProjectNumber": format!("projects/{}", u64::from_str_radix(&self.trace[..10], 16).unwrap_or(0)),
+                    },
+                    "httpRequest": {
+                        "requestMethod": self.method,
+                        "requestUrl": format!("{}://{}{}", self.scheme(), self.host, self.target),
+                        "requestSize": self.request_bytes.to_string(),
+                        "status": self.status.code,
+                        "responseSize": self.sent_bytes().to_string(),
+                        "userAgent": self.user_agent,
+                        "remoteIp": self.client_ip,
+                        "serverIp": self.backend.split(':').next(),
+                        "latency": format!("{:.6}s", self.duration() as f64 / 1e6),
+                        "protocol": self.protocol,
+                    },
+                    "resource": {
+                        "type": "http_load_balancer",
+                        "labels": {
+                            "project_id": project,
+                            "zone": "global",
+                            "url_map_name": format!("{}-url-map", project),
+                            "forwarding_rule_name": format!("{}-{}-forwarding-rule", project, self.scheme()),
+                            "target_proxy_name": format!("{}-target-proxy", project),
+                            "backend_service_name": format!("{}-backend", project),
+                        },
+                    },
+                    "timestamp": datetime::rfc3339_micros(self.received),
+                    "receiveTimestamp": datetime::rfc3339_micros(self.completed() + 1_000),
+                    "severity": severity,
+                    "logName": format!("projects/{}/logs/requests", project),
+                    "trace": format!("projects/{}/traces/{}", project, &self.trace),
+                    "spanId": &self.trace[16..32],
+                });
+                if let Some(referer) = &self.referer {
+                    entry["httpRequest"]["referer"] = json!(referer);
+                }
+                if let Some((protocol, cipher)) = self.tls {
+                    entry["jsonPayload"]["tls"] = json!({ "protocol": protocol, "cipher": cipher });
+                }
+                entry.to_string()
+            }
+        }
+    }
+
+    fn backend_or_dash(&self) -> &str {
+        match self.backend_time {
+            Some(_) => &self.backend,
+            None => "-",
+        }
+    }
+
+    fn target_status(&self) -> String {
+        match self.backend_time {
+            Some(_) => self.status.code.to_string(),
+            None => String::from("-"),
+        }
+    }
+
+    /// Region, account id, load balancer and target group name, derived from the trace id.
+    fn aws_names(&self) -> (&'static str, String, String, String) {
+        let seed = u64::from_str_radix(&self.trace[..12], 16).unwrap_or(0);
+        let region = AWS_REGIONS[seed as usize % AWS_REGIONS.len()];
+        let account = format!("{:012}", seed % 1_000_000_000_000);
+        let name = self.host_label();
+        (
+            region,
+            account,
+            format!("{}-lb", name),
+            format!("{}-targets", name),
+        )
+    }
+
+    fn gcp_project(&self) -> String {
+        format!("{}-{}", self.host_label(), &self.trace[26..32])
+    }
+
+    /// First label of the host, used to name the load balancer resources.
+    fn host_label(&self) -> String {
+        match to_label(self.host.split('.').next().unwrap_or_default()) {
+            label if label.is_empty() => String::from("web"),
+            // resource names have to start with a letter
+            label if label.starts_with(|c: char| c.is_ascii_digit()) => {
+                format!("web-{}", label.chars().take(16).collect::<String>())
+            }
+            label => label.chars().take(20).collect(),
+        }
+    }
+}
+
+/// Microseconds as seconds with `precision` decimals, or `-1` as in the AWS load balancer logs when
+/// no backend answered.
+fn aws_seconds(time: Option<i64>, precision: usize) -> String {
+    match time {
+        Some(time) => format!("{:.*}", precision, time as f64 / 1e6),
+        None => String::from("-1"),
+    }
+}
+
+fn entry_at(received: i64, rnd: &mut impl Rng) -> AccessLogEntry {
+    let mut method = gen_http_method();
+    // load balancers and web servers reject CONNECT, it never shows up as a request
+    while method == "CONNECT" {
+        method = gen_http_method();
+    }
+    // 1xx responses are interim, servers only log the final status
+    let mut status = gen_http_status();
+    while status.class() == StatusClass::Informational {
+        status = gen_http_status();
+    }
+    let tls = if rnd.random_bool(0.8) {
+        Some(TLS_CIPHERS[rnd.random_range(0..TLS_CIPHERS.len())])
+    } else {
+        None
+    };
+    let protocol = if tls.is_some() && rnd.random_bool(0.5) {
+        "HTTP/2.0"
+    } else {
+        "HTTP/1.1"
+    };
+    let host = gen_domain();
+    // the load balancer answers 502, 503 and 504 itself when no backend responds
+    let backend_time = match status.code {
+        502..=504 => None,
+        _ => Some(rnd.random_range(500..400_000)),
+    };
+    let has_body = method != "HEAD" && !matches!(status.code, 204 | 304);
+    let request_body_bytes = match method.as_str() {
+        "POST" | "PUT" | "PATCH" => rnd.random_range(200..19_000),
+        _ => 0,
+    };
+    AccessLogEntry {
+        received,
+        request_time: rnd.random_range(0..2_000),
+        backend_time,
+        response_time: rnd.random_range(0..2_000),
+        client_ip: gen_ipv4(),
+        client_port: rnd.random_range(1024..=65_535),
+        backend: format!(
+            "{}:{}",
+            gen_private_ipv4(10),
+            [80, 8080][rnd.random_range(0..2)]
+        ),
+        user: if rnd.random_bool(0.1) {
+            Some(gen_username())
+        } else {
+            None
+        },
+        method,
+        target: gen_request_path(4),
+        protocol,
+        status,
+        request_bytes: rnd.random_range(80..1_200) + request_body_bytes,
+        request_body_bytes,
+        header_bytes: rnd.random_range(150..600),
+        body_bytes: if has_body {
+            rnd.random_range(0..250_000)
+        } else {
+            0
+        },
+        user_agent: gen_user_agent(),
+        referer: if rnd.random_bool(0.4) {
+            Some(format!("https://{}/", host))
+        } else {
+            None
+        },
+        host,
+        tls,
+        trace: random_hex(rnd, 16),
+    }
+}
+
+/// Returns an access log entry for a request received now.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::logs::access::gen_access_log_entry;
+/// let entry = gen_access_log_entry();
+/// // entry.request_line() => "GET /herbs/basil HTTP/1.1"
+/// assert_eq!(entry.completed(), entry.received + entry.duration());
+/// ```
+pub fn gen_access_log_entry() -> AccessLogEntry {
+    let mut rnd = rand::rng();
+    entry_at(datetime::now() * 1_000_000, &mut rnd)
+}
+
+/// Returns `n` access log lines in `format`, with requests received during the last hour in
+/// increasing order. IIS logs start with the `#Software`, `#Version`, `#Date` and `#Fields`
+/// directives, which are not counted in `n`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::logs::access::{gen_access_log, AccessLogFormat};
+/// let lines: Vec<String> = gen_access_log(3, AccessLogFormat::IisW3c);
+/// // lines[4] => "2023-11-14 22:13:20 10.12.4.2 GET /herbs/basil - 443 - 81.2.69.142 curl/8.5.0 - 200 0 0 5470 312 35"
+/// assert_eq!(lines.len(), 7);
+/// assert!(lines[3].starts_with("#Fields: date time"));
+/// assert!(lines[4].starts_with("20"));
+/// ```
+pub fn gen_access_log(n: usize, format: AccessLogFormat) -> Vec<String> {
+    let mut rnd = rand::rng();
+    let mut received = (datetime::now() - 3600) * 1_000_000;
+    let mut lines = Vec::with_capacity(n + 4);
+    if format == AccessLogFormat::IisW3c {
+        let date = datetime::rfc3339(received.div_euclid(1_000_000));
+        lines.push(String::from(
+            "#Software: Microsoft Internet Information Services 10.0",
+        ));
+        lines.push(String::from("#Version: 1.0"));
+        lines.push(format!("#Date: {} {}", &date[..10], &date[11..19]));
+        lines.push(format!("#Fields: {}", IIS_FIELDS));
+    }
+    for _ in 0..n {
+        received += rnd.random_range(0..1_000_000);
+        lines.push(entry_at(received, &mut rnd).format(format));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consistency() {
+        for _ in 0..50 {
+            let entry = gen_access_log_entry();
+            assert!(entry.completed() > entry.received);
+            assert_ne!(entry.status.class(), StatusClass::Informational);
+            assert_eq!(
+                entry.backend_time.is_none(),
+                (502..=504).contains(&entry.status.code)
+            );
+            if entry.method == "HEAD" || entry.status.code == 304 {
+                assert_eq!(entry.body_bytes, 0);
+            }
+            assert!(entry.sent_bytes() > entry.body_bytes);
+            if entry.protocol == "HTTP/2.0" {
+                assert!(entry.tls.is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn test_formats() {
+        let entry = gen_access_log_entry();
+
+        let nginx = entry.format(AccessLogFormat::Nginx);
+        assert!(nginx.contains(&format!("\"{}\"", entry.request_line())));
+        assert!(nginx.contains(&format!(" {} {} ", entry.status.code, entry.body_bytes)));
+
+        let iis = entry.format(AccessLogFormat::IisW3c);
+        let fields: Vec<&str> = iis.split(' ').collect();
+        assert_eq!(fields.len(), IIS_FIELDS.split(' ').count());
+        assert_eq!(fields[16], (entry.duration() / 1000).to_string());
+
+        let alb = entry.format(AccessLogFormat::AwsAlb);
+        let quoted: Vec<&str> = alb.split('"').collect();
+        let fields: Vec<&str> = quoted[0].split(' ').collect();
+        assert_eq!(fields[11], entry.sent_bytes().to_string());
+        let tls: Vec<&str> = quoted[4].split(' ').collect();
+        match entry.tls {
+            Some((protocol, cipher)) => assert_eq!((tls[1], tls[2]), (cipher, protocol)),
+            None => assert_eq!((tls[1], tls[2]), ("-", "-")),
+        }
+
+        let elb = entry.format(AccessLogFormat::AwsElb);
+        assert!(elb.starts_with(&datetime::rfc3339_micros(entry.completed())));
+        let quoted: Vec<&str> = elb.split('"').collect();
+        let fields: Vec<&str> = quoted[0].split(' ').collect();
+        assert_eq!(fields[9], entry.request_body_bytes.to_string());
+        assert_eq!(fields[10], entry.body_bytes.to_string());
+        assert!(quoted[1].ends_with(" HTTP/1.1"));
+
+        // without a backend both the target and the response processing time are -1
+        let mut failed = entry.clone();
+        failed.backend_time = None;
+        let alb = failed.format(AccessLogFormat::AwsAlb);
+        let fields: Vec<&str> = alb.split(' ').collect();
+        assert_eq!((fields[6], fields[7]), ("-1", "-1"));
+        let elb = failed.format(AccessLogFormat::AwsElb);
+        let fields: Vec<&str> = elb.split(' ').collect();
+        assert_eq!((fields[5], fields[6]), ("-1", "-1"));
+
+        let gcp: serde_json::Value =
+            serde_json::from_str(&entry.format(AccessLogFormat::GcpLb)).unwrap();
+        assert_eq!(gcp["httpRequest"]["status"], entry.status.code);
+        assert_eq!(gcp["jsonPayload"]["tls"].is_object(), entry.tls.is_some());
+        assert!(gcp["trace"].as_str().unwrap().ends_with(&entry.trace));
+    }
+}
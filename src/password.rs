@@ -0,0 +1,629 @@
+//! Module password provides generators for passphrases built from the embedded corpora datasets and
+//! for passwords that follow a [`PasswordPolicy`].
+//!
+//! All randomness comes from `rand::rng()`, which is a cryptographically secure generator seeded by
+//! the operating system.
+
+use std::collections::BTreeSet;
+
+use rand::Rng;
+
+use crate::data;
+
+#[cfg(feature = "hash")]
+pub mod hash;
+
+/// Datasets used for passphrase words if no other datasets are given.
+const DEFAULT_PASSPHRASE_DATASETS: [&str; 8] = [
+    "objects",
+    "fruits",
+    "cats",
+    "dogs",
+    "donkeys",
+    "horses",
+    "dinosaurs",
+    "moods",
+];
+
+/// Symbols appended to passphrases by [`PassphraseOptions::symbols`].
+const PASSPHRASE_SYMBOLS: &str = "!#$%&*+-=?@^_~";
+
+/// How the words of a passphrase are capitalised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Capitalization {
+    /// all lowercase: `apple-kiwi`
+    #[default]
+    Lower,
+    /// all uppercase: `APPLE-KIWI`
+    Upper,
+    /// first letter uppercase: `Apple-Kiwi`
+    Title,
+    /// each word is randomly lowercase or title case, adding one bit of entropy per word
+    Random,
+}
+
+/// Options for [`gen_passphrase`]. Created with `PassphraseOptions::default()` (6 lowercase words
+/// separated by `-`) and changed with the builder methods.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::password::{gen_passphrase, Capitalization, PassphraseOptions};
+/// let options = PassphraseOptions::default()
+///     .words(4)
+///     .separator(".")
+///     .capitalization(Capitalization::Title)
+///     .digits(2)
+///     .symbols(1);
+/// let passphrase = gen_passphrase(&options);
+/// // passphrase.phrase => "Banana.Velociraptor.Grumpy.Kettle42!"
+/// assert_eq!(passphrase.phrase.split('.').count(), 4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassphraseOptions {
+    words: usize,
+    separator: String,
+    capitalization: Capitalization,
+    digits: usize,
+    symbols: usize,
+    datasets: Vec<String>,
+}
+
+impl Default for PassphraseOptions {
+    fn default() -> Self {
+        PassphraseOptions {
+            words: 6,
+            separator: "-".to_string(),
+            capitalization: Capitalization::Lower,
+            digits: 0,
+            symbols: 0,
+            datasets: DEFAULT_PASSPHRASE_DATASETS
+                .iter()
+                .map(|d| d.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl PassphraseOptions {
+    /// Number of words in the passphrase.
+    pub fn words(mut self, words: usize) -> Self {
+        self.words = words;
+        self
+    }
+
+    /// Separator placed between words.
+    pub fn separator(mut self, separator: impl ToString) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Capitalisation of the words.
+    pub fn capitalization(mut self, capitalization: Capitalization) -> Self {
+        self.capitalization = capitalization;
+        self
+    }
+
+    /// Number of random digits appended after the last word.
+    pub fn digits(mut self, digits: usize) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Number of random symbols appended at the very end.
+    pub fn symbols(mut self, symbols: usize) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Corpora datasets the words are taken from, using the same names as `data::gen_switch`.
+    /// Only entries consisting of ASCII letters are used.
+    pub fn datasets(mut self, datasets: &[&str]) -> Self {
+        self.datasets = datasets.iter().map(|d| d.to_string()).collect();
+        self
+    }
+}
+
+/// A generated passphrase together with its estimated entropy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Passphrase {
+    /// The passphrase itself.
+    pub phrase: String,
+    /// Estimated entropy in bits, assuming the attacker knows the word list and the options.
+    pub entropy_bits: f64,
+}
+
+/// Build the deduplicated word list for the given datasets.
+fn passphrase_words(datasets: &[String]) -> Vec<String> {
+    let words: BTreeSet<String> = datasets
+        .iter()
+        .flat_map(|name| data::get_words(name))
+        .filter(|w| !w.is_empty() && w.chars().all(|c| c.is_ascii_alphabetic()))
+        .map(|w| w.to_lowercase())
+        .collect();
+    words.into_iter().collect()
+}
+
+/// Generate a diceware-style passphrase from the embedded corpora words.
+///
+/// The entropy is calculated from the size of the word list and the options, e.g. 6 words from a
+/// list of 2048 words have 66 bits of entropy. If none of the datasets exist the passphrase only
+/// consists of the appended digits and symbols.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::password::{gen_passphrase, PassphraseOptions};
+/// let passphrase = gen_passphrase(&PassphraseOptions::default());
+/// // passphrase.phrase => "kettle-grumpy-apple-triceratops-lamp-kiwi"
+/// assert!(passphrase.entropy_bits > 60.0);
+/// ```
+pub fn gen_passphrase(options: &PassphraseOptions) -> Passphrase {
+    let mut rnd = rand::rng();
+    let list = passphrase_words(&options.datasets);
+    let symbols: Vec<char> = PASSPHRASE_SYMBOLS.chars().collect();
+
+    let mut entropy_bits = 0.0;
+    let mut words: Vec<String> = Vec::with_capacity(options.words);
+    if !list.is_empty() {
+        for _ in 0..options.words {
+            let word = &list[rnd.random_range(0..list.len())];
+            let word = match options.capitalization {
+                Capitalization::Lower => word.clone(),
+                Capitalization::Upper => word.to_uppercase(),
+                Capitalization::Title => title_case(word),
+                Capitalization::Random => {
+                    if rnd.random_bool(0.5) {
+                        title_case(word)
+                    } else {
+                        word.clone()
+                    }
+                }
+            };
+            words.push(word);
+        }
+        entropy_bits += options.words as f64 * (list.len() as f64).log2();
+        if options.capitalization == Capitalization::Random {
+            entropy_bits += options.words as f64;
+        }
+    }
+
+    let mut phrase = words.join(&options.separator);
+    for _ in 0..options.digits {
+        phrase.push(char::from(b'0' + rnd.random_range(0..10u8)));
+    }
+    for _ in 0..options.symbols {
+        phrase.push(symbols[rnd.random_range(0..symbols.len())]);
+    }
+    entropy_bits += options.digits as f64 * 10f64.log2();
+    entropy_bits += options.symbols as f64 * (symbols.len() as f64).log2();
+
+    Passphrase {
+        phrase,
+        entropy_bits,
+    }
+}
+
+fn title_case(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Characters that are easily confused with each other, removed by
+/// [`PasswordPolicy::exclude_ambiguous`].
+const AMBIGUOUS_CHARS: &str = "0Oo1lI|";
+
+/// Default symbol set of a [`PasswordPolicy`].
+const DEFAULT_POLICY_SYMBOLS: &str = "!#$%&()*+,-./:;<=>?@[]^_{|}~";
+
+/// How often generation is retried when `no_repeats` is set before giving up.
+const POLICY_ATTEMPTS: usize = 100;
+
+/// Errors returned when a [`PasswordPolicy`] can't be satisfied or a password violates it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordPolicyError {
+    /// The policy contradicts itself, e.g. the minimum counts add up to more than `max_length`.
+    Unsatisfiable(String),
+    /// The password is shorter than `min_length` or longer than `max_length`.
+    Length(usize),
+    /// The password contains fewer characters of a class than required.
+    MissingClass(&'static str),
+    /// The password contains a character that is not allowed by the policy.
+    InvalidChar(char),
+    /// The password contains the same character twice in a row.
+    RepeatedChar(char),
+}
+
+impl std::fmt::Display for PasswordPolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PasswordPolicyError::Unsatisfiable(reason) => {
+                write!(f, "password policy can't be satisfied: {}", reason)
+            }
+            PasswordPolicyError::Length(len) => write!(f, "invalid password length {}", len),
+            PasswordPolicyError::MissingClass(class) => {
+                write!(f, "not enough {} characters", class)
+            }
+            PasswordPolicyError::InvalidChar(c) => write!(f, "character '{}' is not allowed", c),
+            PasswordPolicyError::RepeatedChar(c) => write!(f, "character '{}' is repeated", c),
+        }
+    }
+}
+
+impl std::error::Error for PasswordPolicyError {}
+
+/// `PasswordPolicy` describes the rules a generated password must follow. Create it with
+/// `PasswordPolicy::default()` and adjust it with the builder methods. The default policy creates
+/// passwords of 12 to 16 characters with at least one lowercase letter, uppercase letter and digit.
+///
+/// Symbols are only used when `min_symbols` is greater than `0`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::password::PasswordPolicy;
+/// let policy = PasswordPolicy::default()
+///     .min_length(20)
+///     .max_length(24)
+///     .min_uppercase(2)
+///     .min_digits(2)
+///     .min_symbols(1)
+///     .symbols("!?#")
+///     .exclude_ambiguous(true)
+///     .no_repeats(true);
+/// let pw: String = policy.generate().unwrap();
+/// // pw => "hT7x!pKw3ZmqEr9aYdfN"
+/// assert!(policy.validate(&pw).is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    min_length: usize,
+    max_length: usize,
+    min_lowercase: usize,
+    min_uppercase: usize,
+    min_digits: usize,
+    min_symbols: usize,
+    symbols: String,
+    exclude_ambiguous: bool,
+    no_repeats: bool,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        PasswordPolicy {
+            min_length: 12,
+            max_length: 16,
+            min_lowercase: 1,
+            min_uppercase: 1,
+            min_digits: 1,
+            min_symbols: 0,
+            symbols: DEFAULT_POLICY_SYMBOLS.to_string(),
+            exclude_ambiguous: false,
+            no_repeats: false,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Minimum length of the password.
+    pub fn min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    /// Maximum length of the password.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Minimum number of lowercase letters.
+    pub fn min_lowercase(mut self, count: usize) -> Self {
+        self.min_lowercase = count;
+        self
+    }
+
+    /// Minimum number of uppercase letters.
+    pub fn min_uppercase(mut self, count: usize) -> Self {
+        self.min_uppercase = count;
+        self
+    }
+
+    /// Minimum number of digits.
+    pub fn min_digits(mut self, count: usize) -> Self {
+        self.min_digits = count;
+        self
+    }
+
+    /// Minimum number of symbols.
+    pub fn min_symbols(mut self, count: usize) -> Self {
+        self.min_symbols = count;
+        self
+    }
+
+    /// Set of symbols to use instead of the default `!#$%&()*+,-./:;<=>?@[]^_{|}~`.
+    pub fn symbols(mut self, symbols: impl ToString) -> Self {
+        self.symbols = symbols.to_string();
+        self
+    }
+
+    /// Exclude characters that are easily confused, like `0`/`O` and `l`/`1`.
+    pub fn exclude_ambiguous(mut self, exclude: bool) -> Self {
+        self.exclude_ambiguous = exclude;
+        self
+    }
+
+    /// Never put the same character twice in a row.
+    pub fn no_repeats(mut self, no_repeats: bool) -> Self {
+        self.no_repeats = no_repeats;
+        self
+    }
+
+    /// The character classes of this policy as (name, characters, minimum count).
+    fn classes(&self) -> [(&'static str, Vec<char>, usize); 4] {
+        let filter = |chars: &str| -> Vec<char> {
+            let mut set: Vec<char> = chars
+                .chars()
+                .filter(|c| !(self.exclude_ambiguous && AMBIGUOUS_CHARS.contains(*c)))
+                .collect();
+            set.sort_unstable();
+            set.dedup();
+            set
+        };
+        let symbols: String = self
+            .symbols
+            .chars()
+            .filter(|c| !c.is_ascii_alphanumeric() && !c.is_whitespace())
+            .collect();
+        [
+            (
+                "lowercase",
+                filter("abcdefghijklmnopqrstuvwxyz"),
+                self.min_lowercase,
+            ),
+            (
+                "uppercase",
+                filter("ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+                self.min_uppercase,
+            ),
+            ("digit", filter("0123456789"), self.min_digits),
+            ("symbol", filter(&symbols), self.min_symbols),
+        ]
+    }
+
+    /// Check if `password` satisfies this policy.
+    pub fn validate(&self, password: &str) -> Result<(), PasswordPolicyError> {
+        let chars: Vec<char> = password.chars().collect();
+        if chars.len() < self.min_length || chars.len() > self.max_length {
+            return Err(PasswordPolicyError::Length(chars.len()));
+        }
+        let classes = self.classes();
+        for c in &chars {
+            let allowed = classes
+                .iter()
+                .any(|(name, set, min)| set.contains(c) && (*name != "symbol" || *min > 0));
+            if !allowed {
+                return Err(PasswordPolicyError::InvalidChar(*c));
+            }
+        }
+        for (name, set, min) in &classes {
+            if chars.iter().filter(|c| set.contains(c)).count() < *min {
+                return Err(PasswordPolicyError::MissingClass(name));
+            }
+        }
+        if self.no_repeats {
+            if let Some(pair) = chars.windows(2).find(|pair| pair[0] == pair[1]) {
+                return Err(PasswordPolicyError::RepeatedChar(pair[0]));
+            }
+        }
+        Ok(())
+    }
+
+    /// Generate a password that satisfies this policy. Returns an error if the policy contradicts
+    /// itself. The password is checked with [`PasswordPolicy::validate`] before it is returned.
+    pub fn generate(&self) -> Result<String, PasswordPolicyError> {
+        let classes = self.classes();
+        let required: usize = classes.iter().map(|(_, _, min)| min).sum();
+        if self.min_length > self.max_length {
+            return Err(PasswordPolicyError::Unsatisfiable(
+                "min_length is greater than max_length".to_string(),
+            ));
+        }
+        if required > self.max_length {
+            return Err(PasswordPolicyError::Unsatisfiable(
+                "minimum counts exceed max_length".to_string(),
+            ));
+        }
+        if let Some((name, _, _)) = classes
+            .iter()
+            .find(|(_, set, min)| *min > 0 && set.is_empty())
+        {
+            return Err(PasswordPolicyError::Unsatisfiable(format!(
+                "no {} characters available",
+                name
+            )));
+        }
+        // classes used to fill the password up to its length
+        let fillers: Vec<usize> = (0..classes.len())
+            .filter(|i| !classes[*i].1.is_empty() && (*i != 3 || classes[*i].2 > 0))
+            .collect();
+        if fillers.is_empty() && self.max_length > 0 {
+            return Err(PasswordPolicyError::Unsatisfiable(
+                "no characters available".to_string(),
+            ));
+        }
+
+        let mut rnd = rand::rng();
+        for _ in 0..POLICY_ATTEMPTS {
+            let length = rnd.random_range(self.min_length.max(required)..=self.max_length);
+            // decide the class of every position first, then pick characters
+            let mut slots: Vec<usize> = Vec::with_capacity(length);
+            for (i, (_, _, min)) in classes.iter().enumerate() {
+                slots.extend(std::iter::repeat_n(i, *min));
+            }
+            while slots.len() < length {
+                slots.push(fillers[rnd.random_range(0..fillers.len())]);
+            }
+            shuffle(&mut slots, &mut rnd);
+
+            let mut password = String::with_capacity(length);
+            let mut previous: Option<char> = None;
+            for slot in slots {
+                let set = &classes[slot].1;
+                let mut c = set[rnd.random_range(0..set.len())];
+                if self.no_repeats && previous == Some(c) && set.len() > 1 {
+                    while previous == Some(c) {
+                        c = set[rnd.random_range(0..set.len())];
+                    }
+                }
+                password.push(c);
+                previous = Some(c);
+            }
+
+            if self.validate(&password).is_ok() {
+                return Ok(password);
+            }
+        }
+
+        Err(PasswordPolicyError::Unsatisfiable(
+            "no valid password found, the character sets are too small".to_string(),
+        ))
+    }
+}
+
+/// Fisher-Yates shuffle.
+fn shuffle<T>(items: &mut [T], rnd: &mut impl Rng) {
+    for i in (1..items.len()).rev() {
+        let j = rnd.random_range(0..=i);
+        items.swap(i, j);
+    }
+}
+
+/// Returns a random password that satisfies the given [`PasswordPolicy`].
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_password_with_policy;
+/// use fakedata_generator::password::PasswordPolicy;
+/// let policy = PasswordPolicy::default().min_symbols(2);
+/// let pw: String = gen_password_with_policy(&policy).unwrap();
+/// // pw => "u%4Kdw]RbxE7q"
+/// assert!(policy.validate(&pw).is_ok());
+/// ```
+pub fn gen_password_with_policy(policy: &PasswordPolicy) -> Result<String, PasswordPolicyError> {
+    policy.generate()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passphrase_options() {
+        let options = PassphraseOptions::default()
+            .words(5)
+            .separator(" ")
+            .capitalization(Capitalization::Upper)
+            .digits(3)
+            .symbols(2);
+        let passphrase = gen_passphrase(&options);
+        let phrase = passphrase.phrase;
+        assert_eq!(phrase.split(' ').count(), 5);
+        assert_eq!(phrase, phrase.to_uppercase());
+
+        let tail: Vec<char> = phrase.chars().rev().take(5).collect();
+        assert!(tail[..2].iter().all(|c| PASSPHRASE_SYMBOLS.contains(*c)));
+        assert!(tail[2..].iter().all(|c| c.is_ascii_digit()));
+    }
+
+    #[test]
+    fn test_passphrase_entropy() {
+        let options = PassphraseOptions::default().datasets(&["fruits"]).words(4);
+        let list_len = passphrase_words(&options.datasets).len() as f64;
+        let passphrase = gen_passphrase(&options);
+        assert!((passphrase.entropy_bits - 4.0 * list_len.log2()).abs() < 1e-9);
+
+        let with_extras = gen_passphrase(&options.clone().digits(2).symbols(1));
+        assert!(with_extras.entropy_bits > passphrase.entropy_bits + 6.0);
+    }
+
+    #[test]
+    fn test_passphrase_unknown_dataset() {
+        let options = PassphraseOptions::default()
+            .datasets(&["does-not-exist"])
+            .digits(4);
+        let passphrase = gen_passphrase(&options);
+        assert_eq!(passphrase.phrase.len(), 4);
+    }
+
+    #[test]
+    fn test_password_policy_generates_valid_passwords() {
+        let policies = [
+            PasswordPolicy::default(),
+            PasswordPolicy::default()
+                .min_length(8)
+                .max_length(8)
+                .min_lowercase(2)
+                .min_uppercase(2)
+                .min_digits(2)
+                .min_symbols(2)
+                .exclude_ambiguous(true)
+                .no_repeats(true),
+            PasswordPolicy::default()
+                .min_length(30)
+                .max_length(40)
+                .min_symbols(10)
+                .symbols("!?")
+                .no_repeats(true),
+        ];
+        for policy in policies {
+            for _ in 0..200 {
+                let pw = policy.generate().unwrap();
+                assert_eq!(policy.validate(&pw), Ok(()), "{}", pw);
+                if policy.exclude_ambiguous {
+                    assert!(!pw.chars().any(|c| AMBIGUOUS_CHARS.contains(c)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_password_policy_unsatisfiable() {
+        let too_short = PasswordPolicy::default().max_length(4).min_symbols(2);
+        assert!(matches!(
+            too_short.generate(),
+            Err(PasswordPolicyError::Unsatisfiable(_))
+        ));
+
+        let no_symbols = PasswordPolicy::default().symbols("").min_symbols(1);
+        assert!(matches!(
+            no_symbols.generate(),
+            Err(PasswordPolicyError::Unsatisfiable(_))
+        ));
+
+        let min_above_max = PasswordPolicy::default().min_length(20).max_length(10);
+        assert!(min_above_max.generate().is_err());
+    }
+
+    #[test]
+    fn test_password_policy_validate() {
+        let policy = PasswordPolicy::default()
+            .min_length(6)
+            .max_length(10)
+            .no_repeats(true);
+        assert_eq!(policy.validate("abC123"), Ok(()));
+        assert_eq!(policy.validate("aB1"), Err(PasswordPolicyError::Length(3)));
+        assert_eq!(
+            policy.validate("abcdef1"),
+            Err(PasswordPolicyError::MissingClass("uppercase"))
+        );
+        assert_eq!(
+            policy.validate("abC12!"),
+            Err(PasswordPolicyError::InvalidChar('!'))
+        );
+        assert_eq!(
+            policy.validate("abCC12"),
+            Err(PasswordPolicyError::RepeatedChar('C'))
+        );
+    }
+}
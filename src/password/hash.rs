@@ -0,0 +1,212 @@
+//! Module hash creates password hashes in common storage formats, so generated passwords can be used
+//! to seed user tables. Only available with the `hash` feature:
+//!
+//! ```toml
+//! fakedata_generator = { version = "0.7", features = ["hash"] }
+//! ```
+//!
+//! The work factors are lower than what production systems should use, to keep seeding fast. The
+//! hashes are still valid and verify with the real libraries, which read the work factor from the
+//! hash.
+
+use std::error::Error;
+
+use argon2::password_hash::{PasswordHasher, SaltString};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use rand::Rng;
+use sha1::{Digest, Sha1};
+use sha2::Sha256;
+
+use super::{PassphraseOptions, PasswordPolicy, gen_passphrase};
+
+/// bcrypt cost used for [`HashFormat::Bcrypt`].
+pub const BCRYPT_COST: u32 = 10;
+
+/// Iterations used for [`HashFormat::DjangoPbkdf2Sha256`]. Django itself uses 600 000 or more.
+pub const PBKDF2_ITERATIONS: u32 = 1_000;
+
+/// Characters used for the Django salt, same as Django's `get_random_string`.
+const DJANGO_SALT_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// The supported password hash formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashFormat {
+    /// bcrypt with the `$2b$` prefix, e.g. `$2b$10$...`
+    Bcrypt,
+    /// Argon2id PHC string with the default parameters, e.g. `$argon2id$v=19$m=19456,t=2,p=1$...`
+    Argon2id,
+    /// PBKDF2-SHA256 as stored by Django, e.g. `pbkdf2_sha256$1000$<salt>$<hash>`
+    DjangoPbkdf2Sha256,
+    /// Salted SHA-1 as used by LDAP `userPassword` attributes, e.g. `{SSHA}...`
+    LdapSsha,
+}
+
+/// A plaintext password together with its hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashedPassword {
+    /// The plaintext password.
+    pub password: String,
+    /// The hash of `password` in the requested format.
+    pub hash: String,
+    /// The format of `hash`.
+    pub format: HashFormat,
+}
+
+/// Hash `password` with a random salt in the given format.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::password::hash::{hash_password, HashFormat};
+/// let hash: String = hash_password("hunter2", HashFormat::LdapSsha).unwrap();
+/// // hash => "{SSHA}Y2nLe0rQWmxGzRYh1ySi34V1jTxnTzZ0TDRFN3c="
+/// assert!(hash.starts_with("{SSHA}"));
+/// ```
+pub fn hash_password(password: &str, format: HashFormat) -> Result<String, Box<dyn Error>> {
+    let mut rnd = rand::rng();
+    let hash = match format {
+        HashFormat::Bcrypt => bcrypt::hash_with_result(password, BCRYPT_COST)?
+            .format_for_version(bcrypt::Version::TwoB),
+        HashFormat::Argon2id => {
+            let salt_bytes: [u8; 16] = rnd.random();
+            let salt = SaltString::encode_b64(&salt_bytes)?;
+            argon2::Argon2::default()
+                .hash_password(password.as_bytes(), &salt)?
+                .to_string()
+        }
+        HashFormat::DjangoPbkdf2Sha256 => {
+            let salt: String = (0..22)
+                .map(|_| {
+                    char::from(DJANGO_SALT_CHARS[rnd.random_range(0..DJANGO_SALT_CHARS.len())])
+                })
+                .collect();
+            django_pbkdf2(password, &salt, PBKDF2_ITERATIONS)
+        }
+        HashFormat::LdapSsha => {
+            let salt: [u8; 8] = rnd.random();
+            let mut hasher = Sha1::new();
+            hasher.update(password.as_bytes());
+            hasher.update(salt);
+            let mut digest = hasher.finalize().to_vec();
+            digest.extend_from_slice(&salt);
+            format!("{{SSHA}}{}", STANDARD.encode(digest))
+        }
+    };
+    Ok(hash)
+}
+
+/// PBKDF2-SHA256 hash of `password` in Django's `pbkdf2_sha256$<iterations>$<salt>$<hash>` format.
+fn django_pbkdf2(password: &str, salt: &str, iterations: u32) -> String {
+    let mut out = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), salt.as_bytes(), iterations, &mut out);
+    format!(
+        "pbkdf2_sha256${}${}${}",
+        iterations,
+        salt,
+        STANDARD.encode(out)
+    )
+}
+
+/// Returns a random password like [`crate::gen_password`] together with its hash.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::password::hash::{gen_password_with_hash, HashFormat};
+/// let hashed = gen_password_with_hash(16, HashFormat::Bcrypt).unwrap();
+/// // hashed.password => "gXPMWpCYRbMexDxR"
+/// // hashed.hash => "$2b$10$..."
+/// assert!(hashed.hash.starts_with("$2b$10$"));
+/// ```
+pub fn gen_password_with_hash(
+    password_length: usize,
+    format: HashFormat,
+) -> Result<HashedPassword, Box<dyn Error>> {
+    hashed(crate::gen_password(password_length), format)
+}
+
+/// Returns a passphrase generated with [`gen_passphrase`] together with its hash.
+pub fn gen_passphrase_with_hash(
+    options: &PassphraseOptions,
+    format: HashFormat,
+) -> Result<HashedPassword, Box<dyn Error>> {
+    hashed(gen_passphrase(options).phrase, format)
+}
+
+impl PasswordPolicy {
+    /// Generate a password that satisfies this policy together with its hash.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use fakedata_generator::password::PasswordPolicy;
+    /// use fakedata_generator::password::hash::HashFormat;
+    /// let hashed = PasswordPolicy::default().generate_with_hash(HashFormat::Argon2id).unwrap();
+    /// assert!(hashed.hash.starts_with("$argon2id$"));
+    /// ```
+    pub fn generate_with_hash(&self, format: HashFormat) -> Result<HashedPassword, Box<dyn Error>> {
+        hashed(self.generate()?, format)
+    }
+}
+
+fn hashed(password: String, format: HashFormat) -> Result<HashedPassword, Box<dyn Error>> {
+    let hash = hash_password(&password, format)?;
+    Ok(HashedPassword {
+        password,
+        hash,
+        format,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use argon2::password_hash::{PasswordHash, PasswordVerifier};
+
+    use super::*;
+
+    #[test]
+    fn test_bcrypt_and_argon2_verify() {
+        let hashed = gen_password_with_hash(20, HashFormat::Bcrypt).unwrap();
+        assert!(hashed.hash.starts_with("$2b$10$"));
+        assert!(bcrypt::verify(&hashed.password, &hashed.hash).unwrap());
+
+        let hashed = gen_password_with_hash(20, HashFormat::Argon2id).unwrap();
+        let parsed = PasswordHash::new(&hashed.hash).unwrap();
+        assert!(
+            argon2::Argon2::default()
+                .verify_password(hashed.password.as_bytes(), &parsed)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_django_pbkdf2_format() {
+        let hash = hash_password("secret", HashFormat::DjangoPbkdf2Sha256).unwrap();
+        let parts: Vec<&str> = hash.split('$').collect();
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[0], "pbkdf2_sha256");
+        assert_eq!(parts[1], PBKDF2_ITERATIONS.to_string());
+        assert_eq!(parts[2].len(), 22);
+        assert_eq!(STANDARD.decode(parts[3]).unwrap().len(), 32);
+
+        // the published PBKDF2-HMAC-SHA256 vectors for the inputs of RFC 6070
+        assert_eq!(
+            django_pbkdf2("password", "salt", 1),
+            "pbkdf2_sha256$1$salt$Eg+2z/z4syxD5yJSVsT4N6hlSMkszDVICAWYfLcL4Xs="
+        );
+        assert_eq!(
+            django_pbkdf2("password", "salt", 4096),
+            "pbkdf2_sha256$4096$salt$xeR41ZKIyEGqUw22hFxMjZYok6ABzk4RpJY4c6qYE0o="
+        );
+    }
+
+    #[test]
+    fn test_ldap_ssha_format() {
+        let hash = hash_password("secret", HashFormat::LdapSsha).unwrap();
+        let raw = STANDARD.decode(hash.trim_start_matches("{SSHA}")).unwrap();
+        assert_eq!(raw.len(), 28);
+        let (digest, salt) = raw.split_at(20);
+        let mut hasher = Sha1::new();
+        hasher.update(b"secret");
+        hasher.update(salt);
+        assert_eq!(digest, hasher.finalize().as_slice());
+    }
+}
@@ -0,0 +1,378 @@
+//! Module phone provides phone numbers with valid country calling codes and plausible national
+//! number lengths and prefixes, rendered in E.164, international or national format.
+//!
+//! [`gen_fictional_phone_number`] only uses the ranges regulators reserve for films, TV and
+//! fixtures (NANP 555-01XX, Ofcom drama numbers, ACMA fictitious numbers), so it never produces
+//! the number of a real person.
+
+use rand::Rng;
+
+use crate::data::countries::country_code;
+use crate::id::random_digits;
+
+/// Number templates: `#` is any digit, `N` a digit from 2 to 9, spaces and dashes separate groups.
+/// The templates contain the national significant number without trunk prefix.
+struct PhoneFormat {
+    country: &'static str,
+    calling_code: u16,
+    trunk_prefix: &'static str,
+    templates: &'static [&'static str],
+    fictional: &'static [&'static str],
+}
+
+const PHONE_FORMATS: [PhoneFormat; 10] = [
+    PhoneFormat {
+        country: "US",
+        calling_code: 1,
+        trunk_prefix: "1",
+        templates: &[
+            "201-N##-####",
+            "212-N##-####",
+            "213-N##-####",
+            "305-N##-####",
+            "312-N##-####",
+            "404-N##-####",
+            "415-N##-####",
+            "503-N##-####",
+            "512-N##-####",
+            "617-N##-####",
+            "702-N##-####",
+            "713-N##-####",
+            "808-N##-####",
+            "917-N##-####",
+        ],
+        fictional: &[
+            "201-555-01##",
+            "212-555-01##",
+            "312-555-01##",
+            "415-555-01##",
+            "617-555-01##",
+            "702-555-01##",
+            "713-555-01##",
+            "917-555-01##",
+        ],
+    },
+    PhoneFormat {
+        country: "CA",
+        calling_code: 1,
+        trunk_prefix: "1",
+        templates: &[
+            "403-N##-####",
+            "416-N##-####",
+            "514-N##-####",
+            "604-N##-####",
+            "613-N##-####",
+            "902-N##-####",
+        ],
+        fictional: &[
+            "403-555-01##",
+            "416-555-01##",
+            "514-555-01##",
+            "604-555-01##",
+            "613-555-01##",
+            "902-555-01##",
+        ],
+    },
+    PhoneFormat {
+        country: "GB",
+        calling_code: 44,
+        trunk_prefix: "0",
+        templates: &[
+            "7### ######",
+            "20 N### ####",
+            "121 N## ####",
+            "161 N## ####",
+            "113 N## ####",
+            "131 N## ####",
+            "141 N## ####",
+            "1### ######",
+        ],
+        fictional: &[
+            "20 7946 0###",
+            "113 496 0###",
+            "114 496 0###",
+            "115 496 0###",
+            "117 496 0###",
+            "121 496 0###",
+            "131 496 0###",
+            "141 496 0###",
+            "151 496 0###",
+            "161 496 0###",
+            "191 498 0###",
+            "28 9649 6###",
+            "29 2018 0###",
+            "1632 960###",
+            "7700 900###",
+            "808 157 0###",
+            "909 879 0###",
+            "306 999 0###",
+        ],
+    },
+    PhoneFormat {
+        country: "DE",
+        calling_code: 49,
+        trunk_prefix: "0",
+        templates: &[
+            "151 ########",
+            "160 #######",
+            "170 #######",
+            "176 ########",
+            "30 N#######",
+            "40 N#######",
+            "89 N#######",
+            "221 N######",
+            "69 N#######",
+        ],
+        fictional: &[],
+    },
+    PhoneFormat {
+        country: "FR",
+        calling_code: 33,
+        trunk_prefix: "0",
+        templates: &[
+            "1 ## ## ## ##",
+            "2 ## ## ## ##",
+            "3 ## ## ## ##",
+            "4 ## ## ## ##",
+            "5 ## ## ## ##",
+            "6 ## ## ## ##",
+            "7 ## ## ## ##",
+        ],
+        fictional: &[],
+    },
+    PhoneFormat {
+        country: "JP",
+        calling_code: 81,
+        trunk_prefix: "0",
+        templates: &[
+            "3-N###-####",
+            "6-N###-####",
+            "70-####-####",
+            "80-####-####",
+            "90-####-####",
+            "45-N##-####",
+            "52-N##-####",
+        ],
+        fictional: &[],
+    },
+    PhoneFormat {
+        country: "AU",
+        calling_code: 61,
+        trunk_prefix: "0",
+        templates: &[
+            "4## ### ###",
+            "2 N### ####",
+            "3 N### ####",
+            "7 N### ####",
+            "8 N### ####",
+        ],
+        fictional: &[
+            "2 5550 ####",
+            "3 5550 ####",
+            "7 5550 ####",
+            "8 5550 ####",
+            "2 7010 ####",
+            "3 7010 ####",
+            "7 7010 ####",
+            "8 7010 ####",
+        ],
+    },
+    PhoneFormat {
+        country: "ES",
+        calling_code: 34,
+        trunk_prefix: "",
+        templates: &[
+            "6## ## ## ##",
+            "7## ## ## ##",
+            "91# ## ## ##",
+            "93# ## ## ##",
+        ],
+        fictional: &[],
+    },
+    PhoneFormat {
+        country: "IT",
+        calling_code: 39,
+        trunk_prefix: "",
+        templates: &["3## ### ####", "06 #### ####", "02 #### ####", "055 ######"],
+        fictional: &[],
+    },
+    PhoneFormat {
+        country: "NL",
+        calling_code: 31,
+        trunk_prefix: "0",
+        templates: &["6 ########", "20 N## ####", "10 N## ####", "70 N## ####"],
+        fictional: &[],
+    },
+];
+
+/// A generated phone number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneNumber {
+    /// ISO 3166-1 alpha-2 code of the country.
+    pub country_code: String,
+    /// Country calling code, e.g. `44` for the UK.
+    pub calling_code: u16,
+    /// National significant number, digits only and without trunk prefix.
+    pub national_number: String,
+    trunk_prefix: &'static str,
+    grouped: String,
+}
+
+impl PhoneNumber {
+    /// The number in E.164 format: `+442079460123`.
+    pub fn e164(&self) -> String {
+        format!("+{}{}", self.calling_code, self.national_number)
+    }
+
+    /// The number in international format: `+44 20 7946 0123`.
+    pub fn international(&self) -> String {
+        format!("+{} {}", self.calling_code, self.grouped)
+    }
+
+    /// The number as dialled within the country: `020 7946 0123`, or `(212) 555-0123` for NANP
+    /// countries.
+    pub fn national(&self) -> String {
+        if self.calling_code == 1 {
+            let n = &self.national_number;
+            return format!("({}) {}-{}", &n[..3], &n[3..6], &n[6..]);
+        }
+        format!("{}{}", self.trunk_prefix, self.grouped)
+    }
+}
+
+/// Returns the ISO codes of the countries [`gen_phone_number`] supports.
+pub fn phone_countries() -> Vec<&'static str> {
+    PHONE_FORMATS.iter().map(|f| f.country).collect()
+}
+
+/// Find the format for `country`, given as ISO code or as name from the `countries` dataset.
+fn find_format(country: &str) -> Option<&'static PhoneFormat> {
+    let code = country_code(country)?;
+    PHONE_FORMATS.iter().find(|f| f.country == code)
+}
+
+/// Fill in a template with random digits.
+fn from_template(format: &PhoneFormat, template: &str, rnd: &mut impl Rng) -> PhoneNumber {
+    let grouped: String = template
+        .chars()
+        .map(|c| match c {
+            '#' => random_digits(rnd, 1),
+            'N' => rnd.random_range(2..10).to_string(),
+            _ => c.to_string(),
+        })
+        .collect();
+    PhoneNumber {
+        country_code: format.country.to_string(),
+        calling_code: format.calling_code,
+        national_number: grouped.chars().filter(|c| c.is_ascii_digit()).collect(),
+        trunk_prefix: format.trunk_prefix,
+        grouped,
+    }
+}
+
+/// Returns a phone number for `country`, given as ISO code or as name from the `countries`
+/// dataset. Mobile and landline numbers are mixed. Returns `None` for unsupported countries, see
+/// [`phone_countries`].
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_phone_number;
+/// let number = gen_phone_number("France").unwrap();
+/// // number.e164() => "+33612345678"
+/// // number.international() => "+33 6 12 34 56 78"
+/// // number.national() => "06 12 34 56 78"
+/// assert!(number.e164().starts_with("+33"));
+/// ```
+pub fn gen_phone_number(country: &str) -> Option<PhoneNumber> {
+    let format = find_format(country)?;
+    let mut rnd = rand::rng();
+    let template = format.templates[rnd.random_range(0..format.templates.len())];
+    Some(from_template(format, template, &mut rnd))
+}
+
+/// Returns a phone number from a range reserved for fictional use: NANP 555-0100 to 555-0199 for
+/// the US and Canada, Ofcom drama numbers for the UK and ACMA fictitious numbers for Australia.
+/// Returns `None` for other countries.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::phone::gen_fictional_phone_number;
+/// let number = gen_fictional_phone_number("GB").unwrap();
+/// // number.national() => "020 7946 0123"
+/// let number = gen_fictional_phone_number("US").unwrap();
+/// // number.national() => "(212) 555-0123"
+/// assert_eq!(&number.national_number[3..8], "55501");
+/// ```
+pub fn gen_fictional_phone_number(country: &str) -> Option<PhoneNumber> {
+    let format = find_format(country)?;
+    if format.fictional.is_empty() {
+        return None;
+    }
+    let mut rnd = rand::rng();
+    let template = format.fictional[rnd.random_range(0..format.fictional.len())];
+    Some(from_template(format, template, &mut rnd))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gen_phone_number() {
+        for code in phone_countries() {
+            for _ in 0..20 {
+                let number = gen_phone_number(code).unwrap();
+                let e164 = number.e164();
+                // E.164 numbers have at most 15 digits
+                assert!(e164.len() <= 16, "{}", e164);
+                assert!(e164[1..].chars().all(|c| c.is_ascii_digit()));
+                let international: String = number
+                    .international()
+                    .chars()
+                    .filter(|c| *c == '+' || c.is_ascii_digit())
+                    .collect();
+                assert_eq!(international, e164);
+                let national: String = number
+                    .national()
+                    .chars()
+                    .filter(|c| c.is_ascii_digit())
+                    .collect();
+                assert!(national.ends_with(&number.national_number));
+            }
+        }
+        assert!(gen_phone_number("Germany").is_some());
+        assert!(gen_phone_number("Atlantis").is_none());
+    }
+
+    #[test]
+    fn test_nanp_format() {
+        for _ in 0..50 {
+            let number = gen_phone_number("US").unwrap();
+            assert_eq!(number.national_number.len(), 10);
+            let exchange = number.national_number.chars().nth(3).unwrap();
+            assert!(exchange >= '2');
+            assert_eq!(number.national().len(), 14);
+        }
+    }
+
+    #[test]
+    fn test_fictional_numbers() {
+        for _ in 0..50 {
+            let us = gen_fictional_phone_number("US").unwrap();
+            let line: u32 = us.national_number[6..].parse().unwrap();
+            assert_eq!(&us.national_number[3..6], "555");
+            assert!((100..=199).contains(&line));
+
+            let uk = gen_fictional_phone_number("GB").unwrap();
+            assert!(uk.national().starts_with('0'));
+            assert!(uk.e164().starts_with("+44"));
+
+            let au = gen_fictional_phone_number("AU").unwrap();
+            assert!(
+                au.national_number[1..].starts_with("5550")
+                    || au.national_number[1..].starts_with("7010")
+            );
+        }
+        assert!(gen_fictional_phone_number("DE").is_none());
+    }
+}
@@ -0,0 +1,365 @@
+//! Module product provides product identifiers with valid check digits: ISBN-10 and ISBN-13, EAN-8,
+//! EAN-13, UPC-A, GTIN-14 and ISSN, plus fake book and retail product records.
+//!
+//! EAN, UPC and GTIN codes use the restricted circulation prefixes GS1 reserves for in-store use,
+//! so they never collide with the codes of real products. ISBNs use the English language
+//! registration groups `0` and `1` with their real registrant ranges, so hyphenation is correct.
+
+use rand::Rng;
+
+use crate::checksum::{gs1_check_digit, mod11_check_char};
+use crate::data::pick_word;
+use crate::id::random_digits;
+use crate::text::{capitalize, title_case};
+
+/// A range of registrant elements as `(from, to, length)`.
+type RegistrantRange = (u32, u32, usize);
+
+/// Registrant ranges of the ISBN registration groups `0` and `1`.
+const ISBN_GROUPS: [(&str, &[RegistrantRange]); 2] = [
+    (
+        "0",
+        &[
+            (0, 19, 2),
+            (200, 699, 3),
+            (7000, 8499, 4),
+            (85000, 89999, 5),
+            (900000, 949999, 6),
+            (9500000, 9999999, 7),
+        ],
+    ),
+    (
+        "1",
+        &[
+            (0, 9, 2),
+            (100, 399, 3),
+            (4000, 5499, 4),
+            (55000, 86979, 5),
+            (869800, 998999, 6),
+            (9990000, 9999999, 7),
+        ],
+    ),
+];
+
+/// How ISBNs are formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IsbnFormat {
+    /// only digits: `9780306406157`
+    Plain,
+    /// hyphens between prefix, group, registrant, publication and check digit: `978-0-306-40615-7`
+    #[default]
+    Hyphenated,
+    /// hyphenated with the `ISBN` label: `ISBN 978-0-306-40615-7`
+    Labeled,
+}
+
+/// The parts of an ISBN, which can be rendered as ISBN-10 or ISBN-13.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Isbn {
+    /// Registration group, `0` or `1`.
+    pub group: String,
+    /// Registrant (publisher) element.
+    pub registrant: String,
+    /// Publication element.
+    pub publication: String,
+}
+
+impl Isbn {
+    /// The ISBN-10 with its MOD 11 check character, which may be `X`.
+    pub fn isbn10(&self, format: IsbnFormat) -> String {
+        let body = format!("{}{}{}", self.group, self.registrant, self.publication);
+        let check = mod11_check_char(&body).to_string();
+        self.render(None, &check, format)
+    }
+
+    /// The ISBN-13 with the `978` prefix and its GS1 check digit.
+    pub fn isbn13(&self, format: IsbnFormat) -> String {
+        let body = format!("978{}{}{}", self.group, self.registrant, self.publication);
+        let check = gs1_check_digit(&body).to_string();
+        self.render(Some("978"), &check, format)
+    }
+
+    fn render(&self, prefix: Option<&str>, check: &str, format: IsbnFormat) -> String {
+        let mut parts: Vec<&str> = prefix.into_iter().collect();
+        parts.extend([
+            self.group.as_str(),
+            self.registrant.as_str(),
+            self.publication.as_str(),
+            check,
+        ]);
+        match format {
+            IsbnFormat::Plain => parts.concat(),
+            IsbnFormat::Hyphenated => parts.join("-"),
+            IsbnFormat::Labeled => format!("ISBN {}", parts.join("-")),
+        }
+    }
+}
+
+/// Append the GS1 check digit to `payload`.
+fn with_gs1_check(payload: String) -> String {
+    let check = gs1_check_digit(&payload);
+    format!("{}{}", payload, check)
+}
+
+/// Returns a random ISBN which can be rendered as ISBN-10 or ISBN-13.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_isbn;
+/// use fakedata_generator::product::IsbnFormat;
+/// let isbn = gen_isbn();
+/// // isbn.isbn13(IsbnFormat::Hyphenated) => "978-0-306-40615-7"
+/// // isbn.isbn10(IsbnFormat::Plain) => "0306406152"
+/// assert_eq!(isbn.isbn13(IsbnFormat::Plain).len(), 13);
+/// ```
+pub fn gen_isbn() -> Isbn {
+    let mut rnd = rand::rng();
+    let (group, ranges) = ISBN_GROUPS[rnd.random_range(0..ISBN_GROUPS.len())];
+    let (from, to, length) = ranges[rnd.random_range(0..ranges.len())];
+    let registrant = format!("{:0width$}", rnd.random_range(from..=to), width = length);
+    // group, registrant and publication always add up to nine digits
+    let publication = random_digits(&mut rnd, 9 - group.len() - length);
+    Isbn {
+        group: group.to_string(),
+        registrant,
+        publication,
+    }
+}
+
+/// Returns an ISBN-10 with a valid check character.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::mod11_valid;
+/// use fakedata_generator::product::{gen_isbn10, IsbnFormat};
+/// let isbn: String = gen_isbn10(IsbnFormat::Hyphenated);
+/// // isbn => "0-306-40615-2"
+/// assert!(mod11_valid(&isbn));
+/// ```
+pub fn gen_isbn10(format: IsbnFormat) -> String {
+    gen_isbn().isbn10(format)
+}
+
+/// Returns an ISBN-13 with a valid check digit.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::gs1_valid;
+/// use fakedata_generator::product::{gen_isbn13, IsbnFormat};
+/// let isbn: String = gen_isbn13(IsbnFormat::Hyphenated);
+/// // isbn => "978-1-86197-876-9"
+/// assert!(gs1_valid(&isbn));
+/// ```
+pub fn gen_isbn13(format: IsbnFormat) -> String {
+    gen_isbn().isbn13(format)
+}
+
+/// Returns an EAN-8 from the restricted circulation range (prefix `2`).
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::product::gen_ean8;
+/// let ean: String = gen_ean8();
+/// // ean => "20123457"
+/// assert_eq!(ean.len(), 8);
+/// ```
+pub fn gen_ean8() -> String {
+    let mut rnd = rand::rng();
+    with_gs1_check(format!("2{}", random_digits(&mut rnd, 6)))
+}
+
+/// Returns an EAN-13 from the restricted circulation range (prefixes `20` to `29`).
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::product::gen_ean13;
+/// let ean: String = gen_ean13();
+/// // ean => "2400638133391"
+/// assert_eq!(ean.len(), 13);
+/// ```
+pub fn gen_ean13() -> String {
+    let mut rnd = rand::rng();
+    with_gs1_check(format!(
+        "2{}{}",
+        rnd.random_range(0..10u8),
+        random_digits(&mut rnd, 10)
+    ))
+}
+
+/// Returns a UPC-A with number system `2` or `4`, which are reserved for in-store use.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::product::gen_upc_a;
+/// let upc: String = gen_upc_a();
+/// // upc => "436000291459"
+/// assert_eq!(upc.len(), 12);
+/// ```
+pub fn gen_upc_a() -> String {
+    let mut rnd = rand::rng();
+    let system = if rnd.random_bool(0.5) { '2' } else { '4' };
+    with_gs1_check(format!("{}{}", system, random_digits(&mut rnd, 10)))
+}
+
+/// Returns a GTIN-14 for a trade unit: a packaging indicator digit (`1` to `8`) followed by a
+/// restricted circulation EAN-13 and a new check digit.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::product::gen_gtin14;
+/// let gtin: String = gen_gtin14();
+/// // gtin => "12400638133398"
+/// assert_eq!(gtin.len(), 14);
+/// ```
+pub fn gen_gtin14() -> String {
+    let mut rnd = rand::rng();
+    let ean = gen_ean13();
+    with_gs1_check(format!("{}{}", rnd.random_range(1..=8u8), &ean[..12]))
+}
+
+/// Returns an ISSN formatted as `NNNN-NNNC`, where the check character may be `X`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::checksum::mod11_valid;
+/// use fakedata_generator::product::gen_issn;
+/// let issn: String = gen_issn();
+/// // issn => "0317-8471"
+/// assert!(mod11_valid(&issn));
+/// ```
+pub fn gen_issn() -> String {
+    let mut rnd = rand::rng();
+    let body = random_digits(&mut rnd, 7);
+    format!("{}-{}{}", &body[..4], &body[4..], mod11_check_char(&body))
+}
+
+/// A fake book record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Book {
+    pub title: String,
+    /// First name and an author's last name from the `authors` dataset.
+    pub author: String,
+    pub isbn: Isbn,
+}
+
+/// A fake retail product record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Product {
+    /// Product name from the `objects` dataset.
+    pub name: String,
+    /// Packaging material from the `packaging` dataset.
+    pub packaging: String,
+    /// Restricted circulation EAN-13.
+    pub ean: String,
+}
+
+/// Returns a book with a title, an author and an ISBN.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::product::{gen_book, IsbnFormat};
+/// let book = gen_book();
+/// // book.title => "The Curious Acorn"
+/// // book.author => "Maria Achebe"
+/// // book.isbn.isbn13(IsbnFormat::Hyphenated) => "978-0-306-40615-7"
+/// assert!(!book.title.is_empty());
+/// ```
+pub fn gen_book() -> Book {
+    let mut rnd = rand::rng();
+    let object = title_case(&pick_word("objects", &mut rnd));
+    let title = match rnd.random_range(0..4) {
+        0 => format!(
+            "The {} {}",
+            title_case(&pick_word("moods", &mut rnd)),
+            object
+        ),
+        1 => format!("The {} of {}", object, pick_word("firstnames", &mut rnd)),
+        2 => format!("A {} in {}", object, pick_word("countries", &mut rnd)),
+        _ => format!("{} and the {}", pick_word("firstnames", &mut rnd), object),
+    };
+    Book {
+        title,
+        author: format!(
+            "{} {}",
+            pick_word("firstnames", &mut rnd),
+            pick_word("authors", &mut rnd)
+        ),
+        isbn: gen_isbn(),
+    }
+}
+
+/// Returns a retail product with a name, packaging and an EAN-13.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::product::gen_product;
+/// let product = gen_product();
+/// // product.name => "Bag of cotton balls"
+/// // product.packaging => "bubble wrap"
+/// // product.ean => "2400638133391"
+/// assert_eq!(product.ean.len(), 13);
+/// ```
+pub fn gen_product() -> Product {
+    let mut rnd = rand::rng();
+    Product {
+        name: capitalize(&pick_word("objects", &mut rnd)),
+        packaging: pick_word("packaging", &mut rnd),
+        ean: gen_ean13(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum::{gs1_valid, mod11_valid};
+
+    #[test]
+    fn test_isbn() {
+        for _ in 0..100 {
+            let isbn = gen_isbn();
+            let isbn10 = isbn.isbn10(IsbnFormat::Hyphenated);
+            let isbn13 = isbn.isbn13(IsbnFormat::Hyphenated);
+            assert!(mod11_valid(&isbn10), "{}", isbn10);
+            assert!(gs1_valid(&isbn13), "{}", isbn13);
+            assert_eq!(isbn10.split('-').count(), 4);
+            assert_eq!(isbn13.split('-').count(), 5);
+            assert_eq!(isbn.isbn10(IsbnFormat::Plain).len(), 10);
+            assert!(isbn.isbn13(IsbnFormat::Labeled).starts_with("ISBN 978-"));
+        }
+        let known = Isbn {
+            group: "0".to_string(),
+            registrant: "306".to_string(),
+            publication: "40615".to_string(),
+        };
+        assert_eq!(known.isbn10(IsbnFormat::Hyphenated), "0-306-40615-2");
+        assert_eq!(known.isbn13(IsbnFormat::Plain), "9780306406157");
+    }
+
+    #[test]
+    fn test_gs1_codes() {
+        for _ in 0..100 {
+            for (code, length) in [
+                (gen_ean8(), 8),
+                (gen_ean13(), 13),
+                (gen_upc_a(), 12),
+                (gen_gtin14(), 14),
+            ] {
+                assert_eq!(code.len(), length);
+                assert!(gs1_valid(&code), "{}", code);
+            }
+            let issn = gen_issn();
+            assert_eq!(issn.len(), 9);
+            assert!(mod11_valid(&issn), "{}", issn);
+        }
+    }
+
+    #[test]
+    fn test_records() {
+        let book = gen_book();
+        assert!(!book.title.is_empty());
+        assert!(book.author.split(' ').count() >= 2);
+        let product = gen_product();
+        assert!(!product.name.is_empty() && !product.packaging.is_empty());
+        assert!(gs1_valid(&product.ean));
+    }
+}
@@ -0,0 +1,376 @@
+//! Module text provides generators for words, sentences, paragraphs and longer texts. Texts are
+//! either classic Lorem Ipsum or "themed" text built from one or more corpora datasets, see
+//! [`TextStyle`].
+
+use rand::Rng;
+
+use crate::data;
+
+pub mod markov;
+
+/// The words used for Lorem Ipsum text.
+const LOREM_WORDS: [&str; 64] = [
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "ut",
+    "labore",
+    "et",
+    "dolore",
+    "magna",
+    "aliqua",
+    "enim",
+    "ad",
+    "minim",
+    "veniam",
+    "quis",
+    "nostrud",
+    "exercitation",
+    "ullamco",
+    "laboris",
+    "nisi",
+    "aliquip",
+    "ex",
+    "ea",
+    "commodo",
+    "consequat",
+    "duis",
+    "aute",
+    "irure",
+    "in",
+    "reprehenderit",
+    "voluptate",
+    "velit",
+    "esse",
+    "cillum",
+    "eu",
+    "fugiat",
+    "nulla",
+    "pariatur",
+    "excepteur",
+    "sint",
+    "occaecat",
+    "cupidatat",
+    "non",
+    "proident",
+    "sunt",
+    "culpa",
+    "qui",
+    "officia",
+    "deserunt",
+    "mollit",
+    "anim",
+    "id",
+    "est",
+    "laborum",
+    "at",
+];
+
+/// Short filler words mixed into themed text so it reads a bit more like a sentence.
+const CONNECTORS: [&str; 16] = [
+    "the", "a", "and", "with", "of", "in", "under", "near", "like", "without", "for", "over", "is",
+    "was", "then", "but",
+];
+
+/// Sentence length (in words) used by `gen_sentence`.
+const SENTENCE_WORDS: std::ops::RangeInclusive<usize> = 4..=14;
+/// Paragraph length (in sentences) used by `gen_paragraph`.
+const PARAGRAPH_SENTENCES: std::ops::RangeInclusive<usize> = 3..=7;
+
+/// `TextStyle` selects where the words of generated text come from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum TextStyle {
+    /// Classic Lorem Ipsum.
+    #[default]
+    LoremIpsum,
+    /// Words taken from the given corpora datasets, using the same names as `data::gen_switch`,
+    /// e.g. `moods`, `objects` and `weather_conditions`. Short connector words are mixed in.
+    Themed(Vec<String>),
+}
+
+impl TextStyle {
+    /// Shortcut for creating a themed style from dataset names.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use fakedata_generator::text::TextStyle;
+    /// let style = TextStyle::themed(&["moods", "objects", "weather_conditions"]);
+    /// ```
+    pub fn themed(datasets: &[&str]) -> TextStyle {
+        TextStyle::Themed(datasets.iter().map(|d| d.to_string()).collect())
+    }
+}
+
+/// A pool of words to draw from, built once per generator call.
+struct WordPool {
+    words: Vec<String>,
+    themed: bool,
+}
+
+impl WordPool {
+    fn new(style: &TextStyle) -> WordPool {
+        match style {
+            TextStyle::LoremIpsum => WordPool {
+                words: LOREM_WORDS.iter().map(|w| w.to_string()).collect(),
+                themed: false,
+            },
+            TextStyle::Themed(datasets) => {
+                let words: Vec<String> = datasets
+                    .iter()
+                    .flat_map(|name| data::get_words(name))
+                    .map(|w| w.to_lowercase())
+                    .collect();
+                // fall back to Lorem Ipsum if none of the datasets exist
+                if words.is_empty() {
+                    return WordPool::new(&TextStyle::LoremIpsum);
+                }
+                WordPool {
+                    words,
+                    themed: true,
+                }
+            }
+        }
+    }
+
+    fn word(&self, rnd: &mut impl Rng) -> &str {
+        if self.themed && rnd.random_bool(0.3) {
+            return CONNECTORS[rnd.random_range(0..CONNECTORS.len())];
+        }
+        &self.words[rnd.random_range(0..self.words.len())]
+    }
+
+    fn words(&self, n: usize, rnd: &mut impl Rng) -> Vec<String> {
+        (0..n).map(|_| self.word(rnd).to_string()).collect()
+    }
+
+    fn sentence(&self, n: usize, rnd: &mut impl Rng) -> String {
+        let mut words = self.words(n, rnd);
+        // never start a themed sentence with a connector followed by nothing useful
+        if self.themed && words.len() > 1 && CONNECTORS.contains(&words[0].as_str()) {
+            words[0] = self.words[rnd.random_range(0..self.words.len())].clone();
+        }
+        // a comma somewhere in the middle of longer sentences
+        if words.len() > 6 && rnd.random_bool(0.5) {
+            let at = rnd.random_range(2..words.len() - 2);
+            words[at].push(',');
+        }
+        let punctuation = match rnd.random_range(0..10) {
+            0 => '?',
+            1 => '!',
+            _ => '.',
+        };
+        let mut sentence = capitalize(&words.join(" "));
+        sentence.push(punctuation);
+        sentence
+    }
+}
+
+/// Uppercase the first character of `input`.
+pub(crate) fn capitalize(input: &str) -> String {
+    let mut chars = input.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Uppercase the first character of every word.
+pub(crate) fn title_case(input: &str) -> String {
+    input
+        .split(' ')
+        .map(capitalize)
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Returns `n` random Lorem Ipsum words separated by spaces, without capitalisation or punctuation.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_words;
+/// let words: String = gen_words(5);
+/// // words => "dolor magna ut sed elit"
+/// assert_eq!(words.split(' ').count(), 5);
+/// ```
+pub fn gen_words(n: usize) -> String {
+    gen_words_with_style(n, &TextStyle::LoremIpsum)
+}
+
+/// Returns `n` random words of the given [`TextStyle`] separated by spaces. Themed words may consist
+/// of more than one word, e.g. `bag of popcorn`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::text::{gen_words_with_style, TextStyle};
+/// let words: String = gen_words_with_style(3, &TextStyle::themed(&["fruits"]));
+/// // words => "apple kiwi with"
+/// assert!(!words.is_empty());
+/// ```
+pub fn gen_words_with_style(n: usize, style: &TextStyle) -> String {
+    let mut rnd = rand::rng();
+    WordPool::new(style).words(n, &mut rnd).join(" ")
+}
+
+/// Returns a single Lorem Ipsum sentence, starting with an uppercase letter and ending with `.`, `?`
+/// or `!`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_sentence;
+/// let sentence: String = gen_sentence();
+/// // sentence => "Tempor quis nulla, dolor laborum ex."
+/// assert!(sentence.ends_with(['.', '?', '!']));
+/// ```
+pub fn gen_sentence() -> String {
+    gen_sentence_with_style(&TextStyle::LoremIpsum)
+}
+
+/// Returns a single sentence of the given [`TextStyle`].
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::text::{gen_sentence_with_style, TextStyle};
+/// let style = TextStyle::themed(&["moods", "objects", "weather_conditions"]);
+/// let sentence: String = gen_sentence_with_style(&style);
+/// // sentence => "Grumpy balloon under the light drizzle."
+/// assert!(sentence.ends_with(['.', '?', '!']));
+/// ```
+pub fn gen_sentence_with_style(style: &TextStyle) -> String {
+    let mut rnd = rand::rng();
+    let n = rnd.random_range(SENTENCE_WORDS);
+    WordPool::new(style).sentence(n, &mut rnd)
+}
+
+/// Returns a Lorem Ipsum paragraph made of 3 to 7 sentences.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_paragraph;
+/// let paragraph: String = gen_paragraph();
+/// assert!(paragraph.len() > 10);
+/// ```
+pub fn gen_paragraph() -> String {
+    gen_paragraph_with_style(&TextStyle::LoremIpsum)
+}
+
+/// Returns a paragraph of the given [`TextStyle`] made of 3 to 7 sentences.
+pub fn gen_paragraph_with_style(style: &TextStyle) -> String {
+    let mut rnd = rand::rng();
+    let pool = WordPool::new(style);
+    let sentences = rnd.random_range(PARAGRAPH_SENTENCES);
+    (0..sentences)
+        .map(|_| {
+            let n = rnd.random_range(SENTENCE_WORDS);
+            pool.sentence(n, &mut rnd)
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Returns Lorem Ipsum text of at most `max_chars` characters. The text always ends with a
+/// complete sentence, so it may be shorter than `max_chars`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_text;
+/// let text: String = gen_text(280);
+/// assert!(text.chars().count() <= 280);
+/// ```
+pub fn gen_text(max_chars: usize) -> String {
+    gen_text_with_style(max_chars, &TextStyle::LoremIpsum)
+}
+
+/// Returns text of the given [`TextStyle`] with at most `max_chars` characters.
+pub fn gen_text_with_style(max_chars: usize, style: &TextStyle) -> String {
+    let mut rnd = rand::rng();
+    let pool = WordPool::new(style);
+    let mut text = String::new();
+    let mut len = 0;
+
+    // a few attempts to fill the remaining space before giving up
+    let mut misses = 0;
+    while misses < 8 {
+        let separator = if len == 0 { 0 } else { 1 };
+        let remaining = max_chars.saturating_sub(len + separator);
+        if remaining < 2 {
+            break;
+        }
+        let mut n = rnd.random_range(SENTENCE_WORDS);
+        let mut sentence = pool.sentence(n, &mut rnd);
+        // shrink the sentence word by word until it fits
+        while sentence.chars().count() > remaining && n > 1 {
+            n -= 1;
+            sentence = pool.sentence(n, &mut rnd);
+        }
+        let sentence_len = sentence.chars().count();
+        if sentence_len > remaining {
+            misses += 1;
+            continue;
+        }
+        if separator == 1 {
+            text.push(' ');
+        }
+        text.push_str(&sentence);
+        len += separator + sentence_len;
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gen_words() {
+        for n in 0..20 {
+            let words = gen_words(n);
+            let count = words.split(' ').filter(|w| !w.is_empty()).count();
+            assert_eq!(count, n);
+        }
+    }
+
+    #[test]
+    fn test_gen_sentence_format() {
+        let styles = [
+            TextStyle::LoremIpsum,
+            TextStyle::themed(&["moods", "objects", "weather_conditions"]),
+        ];
+        for style in styles {
+            for _ in 0..50 {
+                let sentence = gen_sentence_with_style(&style);
+                let first = sentence.chars().next().unwrap();
+                assert!(!first.is_lowercase(), "{}", sentence);
+                assert!(sentence.ends_with(['.', '?', '!']), "{}", sentence);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gen_text_max_chars() {
+        let style = TextStyle::themed(&["objects", "teas"]);
+        for max in [0, 1, 5, 20, 80, 280, 1000] {
+            assert!(gen_text(max).chars().count() <= max);
+            assert!(gen_text_with_style(max, &style).chars().count() <= max);
+        }
+        assert!(gen_text(280).ends_with(['.', '?', '!']));
+    }
+
+    #[test]
+    fn test_unknown_dataset_falls_back_to_lorem() {
+        let words = gen_words_with_style(10, &TextStyle::themed(&["does-not-exist"]));
+        for word in words.split(' ') {
+            assert!(LOREM_WORDS.contains(&word));
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Module markov provides [`MarkovText`], a generator that learns word sequences from a text corpus
+//! and produces sentences that statistically resemble it.
+
+use std::collections::BTreeMap;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::data::texts;
+
+/// Upper bound for the words of a single generated sentence, used in case the chain never reaches
+/// the end of a sentence.
+const MAX_SENTENCE_WORDS: usize = 40;
+
+/// `MarkovText` is a word-level Markov chain of configurable order. The order is the number of
+/// previous words used to choose the next one: higher orders stick closer to the training text.
+///
+/// The model can be trained at runtime with [`MarkovText::train`], or created from the bundled
+/// public-domain text with [`MarkovText::bundled`]. It implements `Serialize` and `Deserialize` so
+/// a trained model can be stored (e.g. as JSON with `serde_json`) and loaded later.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::text::markov::MarkovText;
+/// let mut model = MarkovText::new(2);
+/// model.train("The cat sat on the mat. The dog sat on the rug. The cat ran away.");
+/// let sentence: String = model.gen_sentence();
+/// // sentence => "The dog sat on the mat."
+/// assert!(sentence.starts_with("The"));
+///
+/// let json = serde_json::to_string(&model).unwrap();
+/// let restored: MarkovText = serde_json::from_str(&json).unwrap();
+/// assert_eq!(model, restored);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarkovText {
+    order: usize,
+    /// Word sequences (joined by a single space) that start a sentence, repeated by frequency.
+    starts: Vec<String>,
+    /// For each word sequence the words that followed it and how often.
+    transitions: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+impl MarkovText {
+    /// Create an empty model of the given order. An order of `0` is treated as `1`.
+    pub fn new(order: usize) -> MarkovText {
+        MarkovText {
+            order: order.max(1),
+            starts: Vec::new(),
+            transitions: BTreeMap::new(),
+        }
+    }
+
+    /// Create a model of the given order trained on the bundled public-domain text (the opening of
+    /// "Alice's Adventures in Wonderland").
+    ///
+    /// ## Example
+    /// ```rust
+    /// use fakedata_generator::text::markov::MarkovText;
+    /// let model = MarkovText::bundled(2);
+    /// let sentence: String = model.gen_sentence();
+    /// // sentence => "Alice had no idea what Latitude was, or Longitude either."
+    /// assert!(!sentence.is_empty());
+    /// ```
+    pub fn bundled(order: usize) -> MarkovText {
+        let mut model = MarkovText::new(order);
+        model.train(texts::DATA_ALICE);
+        model
+    }
+
+    /// Train the model on `corpus`. Can be called multiple times to add more text. Words are split
+    /// on whitespace, quotes and brackets are removed and a word ending in `.`, `!` or `?` ends a
+    /// sentence.
+    pub fn train(&mut self, corpus: &str) {
+        let words: Vec<String> = corpus
+            .split_whitespace()
+            .map(|w| w.replace(['"', '(', ')'], ""))
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        let mut sentence_start = 0;
+        for i in 0..words.len() {
+            if i == sentence_start && i + self.order <= words.len() {
+                self.starts.push(words[i..i + self.order].join(" "));
+            }
+            if i + self.order < words.len() {
+                let key = words[i..i + self.order].join(" ");
+                *self
+                    .transitions
+                    .entry(key)
+                    .or_default()
+                    .entry(words[i + self.order].clone())
+                    .or_insert(0) += 1;
+            }
+            if ends_sentence(&words[i]) {
+                sentence_start = i + 1;
+            }
+        }
+    }
+
+    /// The order of the model.
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Returns `true` if the model has not been trained yet.
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+
+    /// Generate a single sentence. An untrained model returns an empty `String`.
+    pub fn gen_sentence(&self) -> String {
+        if self.is_empty() {
+            return String::new();
+        }
+        let mut rnd = rand::rng();
+        let start = &self.starts[rnd.random_range(0..self.starts.len())];
+        let mut words: Vec<String> = start.split(' ').map(|w| w.to_string()).collect();
+
+        while !words.last().is_some_and(|w| ends_sentence(w)) && words.len() < MAX_SENTENCE_WORDS {
+            let key = words[words.len().saturating_sub(self.order)..].join(" ");
+            match self.transitions.get(&key) {
+                Some(next) => words.push(pick_weighted(next, &mut rnd)),
+                None => break,
+            }
+        }
+
+        let mut sentence = words.join(" ");
+        sentence = sentence.trim_end_matches([',', ';', ':', '-']).to_string();
+        if !ends_sentence(&sentence) {
+            sentence.push('.');
+        }
+        let mut chars = sentence.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => sentence,
+        }
+    }
+
+    /// Generate text of at most `max_chars` characters made of complete sentences. Sentences that
+    /// don't fit are skipped, so the text may be shorter than `max_chars` or even empty.
+    pub fn gen_text(&self, max_chars: usize) -> String {
+        let mut text = String::new();
+        let mut len = 0;
+        let mut misses = 0;
+        while misses < 8 && !self.is_empty() {
+            let sentence = self.gen_sentence();
+            let separator = if len == 0 { 0 } else { 1 };
+            let sentence_len = sentence.chars().count();
+            if len + separator + sentence_len > max_chars {
+                misses += 1;
+                continue;
+            }
+            if separator == 1 {
+                text.push(' ');
+            }
+            text.push_str(&sentence);
+            len += separator + sentence_len;
+        }
+        text
+    }
+}
+
+fn ends_sentence(word: &str) -> bool {
+    word.ends_with(['.', '!', '?'])
+}
+
+fn pick_weighted(candidates: &BTreeMap<String, u32>, rnd: &mut impl Rng) -> String {
+    let total: u32 = candidates.values().sum();
+    if total == 0 {
+        return candidates.keys().next().cloned().unwrap_or_default();
+    }
+    let mut pick = rnd.random_range(0..total);
+    for (word, count) in candidates {
+        if pick < *count {
+            return word.clone();
+        }
+        pick -= count;
+    }
+    // unreachable as long as the counts add up
+    candidates.keys().next().cloned().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_untrained_model() {
+        let model = MarkovText::new(2);
+        assert!(model.is_empty());
+        assert_eq!(model.gen_sentence(), "");
+        assert_eq!(model.gen_text(100), "");
+    }
+
+    #[test]
+    fn test_generated_words_come_from_corpus() {
+        let corpus = "one two three four. four three two one! two three four one?";
+        for order in 1..=3 {
+            let mut model = MarkovText::new(order);
+            model.train(corpus);
+            assert_eq!(model.order(), order);
+            for _ in 0..20 {
+                let sentence = model.gen_sentence();
+                assert!(sentence.ends_with(['.', '!', '?']), "{}", sentence);
+                for word in sentence.split(' ') {
+                    let word = word.trim_end_matches(['.', '!', '?']).to_lowercase();
+                    assert!(corpus.contains(&word), "{}", word);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bundled_model_roundtrip() {
+        let model = MarkovText::bundled(2);
+        assert!(!model.is_empty());
+        assert!(model.gen_text(500).chars().count() <= 500);
+
+        let json = serde_json::to_string(&model).unwrap();
+        let restored: MarkovText = serde_json::from_str(&json).unwrap();
+        assert_eq!(model, restored);
+    }
+}
@@ -0,0 +1,781 @@
+//! Module trace provides distributed traces for testing trace ingestion and sampling: trees of
+//! spans with W3C Trace Context ids, as OTLP, Jaeger or Zipkin v2 JSON.
+//!
+//! A trace starts with a server span of the API gateway. Spans call other services over HTTP
+//! (a client span with a nested server span of the callee), query a database or do internal work.
+//! Every child span lies within the time window of its parent.
+
+use rand::Rng;
+use serde_json::{Value, json};
+
+use crate::datetime;
+use crate::id::random_hex;
+use crate::logs::SERVICE_NAMES;
+use crate::url::gen_request_path;
+use crate::{gen_domain, gen_http_method, gen_ipv4};
+
+const TABLES: [&str; 6] = [
+    "users",
+    "orders",
+    "products",
+    "payments",
+    "sessions",
+    "inventory",
+];
+
+const DB_OPERATIONS: [&str; 4] = ["SELECT", "INSERT", "UPDATE", "DELETE"];
+
+const INTERNAL_OPERATIONS: [&str; 5] = [
+    "validate request",
+    "check permissions",
+    "load configuration",
+    "render template",
+    "serialize response",
+];
+
+/// The role of a span in a call, as defined by OpenTelemetry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpanKind {
+    Internal,
+    Server,
+    Client,
+    Producer,
+    Consumer,
+}
+
+impl SpanKind {
+    /// `SpanKind` enum value of OTLP.
+    fn otlp(&self) -> u8 {
+        match self {
+            SpanKind::Internal => 1,
+            SpanKind::Server => 2,
+            SpanKind::Client => 3,
+            SpanKind::Producer => 4,
+            SpanKind::Consumer => 5,
+        }
+    }
+
+    /// Lowercase name as in the `span.kind` tag of Jaeger.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpanKind::Internal => "internal",
+            SpanKind::Server => "server",
+            SpanKind::Client => "client",
+            SpanKind::Producer => "producer",
+            SpanKind::Consumer => "consumer",
+        }
+    }
+}
+
+/// The value of a span attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AttributeValue {
+    String(String),
+    Int(i64),
+    Bool(bool),
+}
+
+impl AttributeValue {
+    fn otlp(&self) -> Value {
+        match self {
+            AttributeValue::String(value) => json!({ "stringValue": value }),
+            // OTLP JSON encodes 64 bit integers as strings
+            AttributeValue::Int(value) => json!({ "intValue": value.to_string() }),
+            AttributeValue::Bool(value) => json!({ "boolValue": value }),
+        }
+    }
+
+    fn jaeger(&self, key: &str) -> Value {
+        match self {
+            AttributeValue::String(value) => {
+                json!({ "key": key, "type": "string", "value": value })
+            }
+            AttributeValue::Int(value) => json!({ "key": key, "type": "int64", "value": value }),
+            AttributeValue::Bool(value) => json!({ "key": key, "type": "bool", "value": value }),
+        }
+    }
+}
+
+impl std::fmt::Display for AttributeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttributeValue::String(value) => write!(f, "{}", value),
+            AttributeValue::Int(value) => write!(f, "{}", value),
+            AttributeValue::Bool(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// One operation of a trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    /// W3C trace id, 32 hex characters.
+    pub trace_id: String,
+    /// W3C span id, 16 hex characters.
+    pub span_id: String,
+    pub parent_span_id: Option<String>,
+    /// Operation name like `GET /herbs/basil` or `SELECT orders`.
+    pub name: String,
+    pub service: String,
+    pub kind: SpanKind,
+    /// Start in microseconds since the Unix epoch.
+    pub start: i64,
+    /// End in microseconds since the Unix epoch.
+    pub end: i64,
+    pub error: bool,
+    pub attributes: Vec<(String, AttributeValue)>,
+}
+
+impl Span {
+    /// Duration in microseconds.
+    pub fn duration(&self) -> i64 {
+        self.end - self.start
+    }
+
+    /// The `traceparent` header which propagates this span, with the sampled flag set:
+    /// `00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01`.
+    pub fn traceparent(&self) -> String {
+        format!("00-{}-{}-01", self.trace_id, self.span_id)
+    }
+
+    fn is_http(&self) -> bool {
+        self.attributes
+            .iter()
+            .any(|(key, _)| key == "http.request.method")
+    }
+
+    /// The `http.response.status_code` attribute, if the span has one.
+    pub fn status_code(&self) -> Option<i64> {
+        self.attributes.iter().find_map(|(key, value)| match value {
+            AttributeValue::Int(code) if key == "http.response.status_code" => Some(*code),
+            _ => None,
+        })
+    }
+
+    /// Replace or remove the `http.response.status_code` attribute.
+    fn set_status_code(&mut self, code: Option<i64>) {
+        self.attributes
+            .retain(|(key, _)| key != "http.response.status_code");
+        if let Some(code) = code {
+            self.attributes.push((
+                String::from("http.response.status_code"),
+                AttributeValue::Int(code),
+            ));
+        }
+    }
+}
+
+/// A successful status code, shared by both sides of a call.
+fn gen_success_status(rnd: &mut impl Rng) -> AttributeValue {
+    AttributeValue::Int([200, 200, 200, 201, 204][rnd.random_range(0..5)])
+}
+
+/// A tree of spans. Parents always come before their children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub spans: Vec<Span>,
+}
+
+impl Trace {
+    /// The root span.
+    pub fn root(&self) -> &Span {
+        &self.spans[0]
+    }
+
+    /// The direct children of `span`.
+    pub fn children(&self, span: &Span) -> Vec<&Span> {
+        self.spans
+            .iter()
+            .filter(|child| child.parent_span_id.as_deref() == Some(span.span_id.as_str()))
+            .collect()
+    }
+}
+
+/// Output formats for [`format_traces`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TraceFormat {
+    /// OTLP JSON, as sent to the `/v1/traces` endpoint of an OpenTelemetry collector.
+    #[default]
+    Otlp,
+    /// JSON of the Jaeger query API, as used by the Jaeger UI to import traces.
+    Jaeger,
+    /// Zipkin v2 JSON, as sent to `/api/v2/spans`.
+    Zipkin,
+}
+
+/// Options for [`gen_trace_with`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceOptions {
+    max_depth: usize,
+    max_children: usize,
+    error_rate: f64,
+    attributes: Vec<(String, AttributeValue)>,
+}
+
+impl Default for TraceOptions {
+    /// Up to 3 levels of calls with up to 3 children each, 5% errors.
+    fn default() -> Self {
+        TraceOptions {
+            max_depth: 3,
+            max_children: 3,
+            error_rate: 0.05,
+            attributes: Vec::new(),
+        }
+    }
+}
+
+impl TraceOptions {
+    /// Maximum number of nested calls below the root span.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Maximum number of children of a span.
+    pub fn max_children(mut self, max_children: usize) -> Self {
+        self.max_children = max_children;
+        self
+    }
+
+    /// Probability between 0 and 1 that a span fails on its own. Errors of a server span also
+    /// fail the client span which called it.
+    pub fn error_rate(mut self, error_rate: f64) -> Self {
+        self.error_rate = error_rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Add an attribute to every span, e.g. `deployment.environment.name`.
+    pub fn attribute(mut self, key: &str, value: AttributeValue) -> Self {
+        self.attributes.push((key.to_string(), value));
+        self
+    }
+}
+
+/// Method and path of an HTTP operation.
+fn gen_operation() -> (String, String) {
+    let mut method = gen_http_method();
+    // CONNECT opens a tunnel and is never traced as a request
+    while method == "CONNECT" {
+        method = gen_http_method();
+    }
+    let path = gen_request_path(3);
+    let path = path.split('?').next().unwrap_or("/").to_string();
+    (method, path)
+}
+
+fn string(value: &str) -> AttributeValue {
+    AttributeValue::String(value.to_string())
+}
+
+/// Builds the spans of one trace.
+struct TraceBuilder<'a, R: Rng> {
+    trace_id: String,
+    options: &'a TraceOptions,
+    rnd: &'a mut R,
+    spans: Vec<Span>,
+}
+
+impl<R: Rng> TraceBuilder<'_, R> {
+    /// Add a span and return its index.
+    fn push(
+        &mut self,
+        parent: Option<usize>,
+        name: String,
+        service: &str,
+        kind: SpanKind,
+        window: (i64, i64),
+        attributes: Vec<(String, AttributeValue)>,
+    ) -> usize {
+        self.spans.push(Span {
+            trace_id: self.trace_id.clone(),
+            span_id: random_hex(self.rnd, 8),
+            parent_span_id: parent.map(|parent| self.spans[parent].span_id.clone()),
+            name,
+            service: service.to_string(),
+            kind,
+            start: window.0,
+            end: window.1,
+            error: false,
+            attributes,
+        });
+        self.spans.len() - 1
+    }
+
+    /// Add the children of the span at `parent`, one after another within its window.
+    fn add_children(&mut self, parent: usize, depth: usize) {
+        if depth > self.options.max_depth {
+            return;
+        }
+        let (start, end) = (self.spans[parent].start, self.spans[parent].end);
+        let service = self.spans[parent].service.clone();
+        let count = self.rnd.random_range(0..=self.options.max_children);
+        let mut cursor = start + (end - start) / 20;
+        for _ in 0..count {
+            // spans shorter than 100µs are not worth splitting
+            if end - cursor < 100 {
+                break;
+            }
+            let child_start = self.rnd.random_range(cursor..end - 50);
+            let child_end = self.rnd.random_range(child_start + 50..=end);
+            cursor = child_end;
+            match self.rnd.random_range(0..4) {
+                0 | 1 => {
+                    let (method, path) = gen_operation();
+                    // the API gateway is only the entry point, services never call themselves
+                    let mut index = self.rnd.random_range(1..SERVICE_NAMES.len());
+                    if SERVICE_NAMES[index] == service {
+                        index = index % (SERVICE_NAMES.len() - 1) + 1;
+                    }
+                    let callee = SERVICE_NAMES[index];
+                    let host = format!("{}.internal", callee);
+                    // both sides of the call see the same response
+                    let status = gen_success_status(self.rnd);
+                    let client = self.push(
+                        Some(parent),
+                        format!("{} {}", method, path),
+                        &service,
+                        SpanKind::Client,
+                        (child_start, child_end),
+                        vec![
+                            (String::from("http.request.method"), string(&method)),
+                            (
+                                String::from("url.full"),
+                                string(&format!("http://{}{}", host, path)),
+                            ),
+                            (String::from("server.address"), string(&host)),
+                            (String::from("http.response.status_code"), status.clone()),
+                        ],
+                    );
+                    // the network takes up to 10% of the call on each way
+                    let latency = self.rnd.random_range(0..=(child_end - child_start) / 10);
+                    let server = self.push(
+                        Some(client),
+                        format!("{} {}", method, path),
+                        callee,
+                        SpanKind::Server,
+                        (child_start + latency, child_end - latency),
+                        vec![
+                            (String::from("http.request.method"), string(&method)),
+                            (String::from("url.path"), string(&path)),
+                            (String::from("http.route"), string(&path)),
+                            (String::from("http.response.status_code"), status),
+                        ],
+                    );
+                    self.add_children(server, depth + 1);
+                }
+                2 => {
+                    let operation = DB_OPERATIONS[self.rnd.random_range(0..DB_OPERATIONS.len())];
+                    let table = TABLES[self.rnd.random_range(0..TABLES.len())];
+                    self.push(
+                        Some(parent),
+                        format!("{} {}", operation, table),
+                        &service,
+                        SpanKind::Client,
+                        (child_start, child_end),
+                        vec![
+                            (String::from("db.system.name"), string("postgresql")),
+                            (String::from("db.operation.name"), string(operation)),
+                            (String::from("db.collection.name"), string(table)),
+                        ],
+                    );
+                }
+                _ => {
+                    let name =
+                        INTERNAL_OPERATIONS[self.rnd.random_range(0..INTERNAL_OPERATIONS.len())];
+                    let internal = self.push(
+                        Some(parent),
+                        name.to_string(),
+                        &service,
+                        SpanKind::Internal,
+                        (child_start, child_end),
+                        Vec::new(),
+                    );
+                    self.add_children(internal, depth + 1);
+                }
+            }
+        }
+    }
+
+    /// Decide the errors from the leaves up, then fix the status codes of failed calls and add
+    /// the common attributes.
+    fn finish(mut self) -> Trace {
+        for i in (0..self.spans.len()).rev() {
+            if self.rnd.random_bool(self.options.error_rate) {
+                self.spans[i].error = true;
+            }
+            if !self.spans[i].error {
+                continue;
+            }
+            let parent = self.spans[i].parent_span_id.clone();
+            if let Some(parent) = self.spans[..i]
+                .iter_mut()
+                .find(|s| Some(&s.span_id) == parent.as_ref())
+            {
+                // a failed response fails the call, callers handle half of the failed calls
+                if parent.kind == SpanKind::Client || self.rnd.random_bool(0.5) {
+                    parent.error = true;
+                }
+            }
+        }
+        for i in 0..self.spans.len() {
+            if self.spans[i].kind != SpanKind::Server || !self.spans[i].error {
+                continue;
+            }
+            self.spans[i].set_status_code(Some(500));
+            // the client receives the same error response
+            let parent = self.spans[i].parent_span_id.clone();
+            if let Some(client) = self.spans[..i]
+                .iter_mut()
+                .find(|s| Some(&s.span_id) == parent.as_ref())
+            {
+                client.set_status_code(Some(500));
+            }
+        }
+        for span in &mut self.spans {
+            if span.error {
+                let error_type = match span.status_code() {
+                    Some(500) => String::from("500"),
+                    // a client which failed while the server answered gave up waiting
+                    _ if span.is_http() => {
+                        span.set_status_code(None);
+                        String::from("timeout")
+                    }
+                    _ => String::from("exception"),
+                };
+                span.attributes.push((
+                    String::from("error.type"),
+                    AttributeValue::String(error_type),
+                ));
+            }
+            span.attributes
+                .extend(self.options.attributes.iter().cloned());
+        }
+        Trace { spans: self.spans }
+    }
+}
+
+/// Returns a trace with the default options.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::trace::gen_trace;
+/// let trace = gen_trace();
+/// // trace.root().name => "GET /herbs/basil"
+/// // trace.root().traceparent() => "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+/// assert!(trace.root().parent_span_id.is_none());
+/// ```
+pub fn gen_trace() -> Trace {
+    gen_trace_with(&TraceOptions::default())
+}
+
+/// Returns a trace built with `options`, which ended now.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::trace::{gen_trace_with, AttributeValue, TraceOptions};
+/// let options = TraceOptions::default()
+///     .max_depth(4)
+///     .error_rate(0.0)
+///     .attribute("deployment.environment.name", AttributeValue::String(String::from("staging")));
+/// let trace = gen_trace_with(&options);
+/// assert!(trace.spans.iter().all(|span| !span.error));
+/// for span in &trace.spans {
+///     for child in trace.children(span) {
+///         assert!(span.start <= child.start && child.end <= span.end);
+///     }
+/// }
+/// ```
+pub fn gen_trace_with(options: &TraceOptions) -> Trace {
+    let mut rnd = rand::rng();
+    let end = datetime::now() * 1_000_000;
+    let start = end - rnd.random_range(5_000..2_000_000);
+    let (method, path) = gen_operation();
+    let host = gen_domain();
+    let status = gen_success_status(&mut rnd);
+    let mut builder = TraceBuilder {
+        trace_id: random_hex(&mut rnd, 16),
+        options,
+        rnd: &mut rnd,
+        spans: Vec::new(),
+    };
+    let root = builder.push(
+        None,
+        format!("{} {}", method, path),
+        SERVICE_NAMES[0],
+        SpanKind::Server,
+        (start, end),
+        vec![
+            (String::from("http.request.method"), string(&method)),
+            (String::from("url.path"), string(&path)),
+            (String::from("url.scheme"), string("https")),
+            (String::from("server.address"), string(&host)),
+            (String::from("client.address"), string(&gen_ipv4())),
+            (String::from("http.response.status_code"), status),
+        ],
+    );
+    builder.add_children(root, 1);
+    builder.finish()
+}
+
+fn otlp(traces: &[Trace]) -> Value {
+    // OTLP groups spans by the service which emitted them
+    let mut services: Vec<&str> = traces
+        .iter()
+        .flat_map(|trace| trace.spans.iter().map(|span| span.service.as_str()))
+        .collect();
+    services.sort();
+    services.dedup();
+    let resource_spans: Vec<Value> = services
+        .into_iter()
+        .map(|service| {
+            let spans: Vec<Value> = traces
+                .iter()
+                .flat_map(|trace| &trace.spans)
+                .filter(|span| span.service == service)
+                .map(|span| {
+                    let mut value = json!({
+                        "traceId": span.trace_id,
+                        "spanId": span.span_id,
+                        "name": span.name,
+                        "kind": span.kind.otlp(),
+                        "startTimeUnixNano": (span.start * 1000).to_string(),
+                        "endTimeUnixNano": (span.end * 1000).to_string(),
+                        "attributes": span
+                            .attributes
+                            .iter()
+                            .map(|(key, value)| json!({ "key": key, "value": value.otlp() }))
+                            .collect::<Vec<Value>>(),
+                        // STATUS_CODE_ERROR is 2, STATUS_CODE_UNSET is 0
+                        "status": if span.error { json!({ "code": 2 }) } else { json!({}) },
+                    });
+                    if let Some(parent) = &span.parent_span_id {
+                        value["parentSpanId"] = json!(parent);
+                    }
+                    value
+                })
+                .collect();
+            json!({
+                "resource": {
+                    "attributes": [{ "key": "service.name", "value": { "stringValue": service } }],
+                },
+                "scopeSpans": [{
+                    "scope": { "name": "fakedata_generator", "version": env!("CARGO_PKG_VERSION") },
+                    "spans": spans,
+                }],
+            })
+        })
+        .collect();
+    json!({ "resourceSpans": resource_spans })
+}
+
+fn jaeger(traces: &[Trace]) -> Value {
+    let data: Vec<Value> = traces
+        .iter()
+        .map(|trace| {
+            let mut services: Vec<&str> = trace.spans.iter().map(|span| span.service.as_str()).collect();
+            services.sort();
+            services.dedup();
+            let process_id = |service: &str| {
+                format!("p{}", services.iter().position(|s| *s == service).unwrap_or(0) + 1)
+            };
+            let spans: Vec<Value> = trace
+                .spans
+                .iter()
+                .map(|span| {
+                    let mut tags: Vec<Value> = span
+                        .attributes
+                        .iter()
+                        .map(|(key, value)| value.jaeger(key))
+                        .collect();
+                    tags.push(json!({ "key": "span.kind", "type": "string", "value": span.kind.as_str() }));
+                    if span.error {
+                        tags.push(json!({ "key": "error", "type": "bool", "value": true }));
+                    }
+                    let references: Vec<Value> = span
+                        .parent_span_id
+                        .iter()
+                        .map(|parent| {
+                            json!({ "refType": "CHILD_OF", "traceID": span.trace_id, "spanID": parent })
+                        })
+                        .collect();
+                    json!({
+                        "traceID": span.trace_id,
+                        "spanID": span.span_id,
+                        "operationName": span.name,
+                        "references": references,
+                        "startTime": span.start,
+                        "duration": span.duration(),
+                        "tags": tags,
+                        "logs": [],
+                        "processID": process_id(&span.service),
+                    })
+                })
+                .collect();
+            let processes: serde_json::Map<String, Value> = services
+                .iter()
+                .map(|service| (process_id(service), json!({ "serviceName": service, "tags": [] })))
+                .collect();
+            json!({ "traceID": trace.root().trace_id, "spans": spans, "processes": processes })
+        })
+        .collect();
+    json!({ "data": data })
+}
+
+fn zipkin(traces: &[Trace]) -> Value {
+    traces
+        .iter()
+        .flat_map(|trace| &trace.spans)
+        .map(|span| {
+            // Zipkin tags are always strings
+            let mut tags: serde_json::Map<String, Value> = span
+                .attributes
+                .iter()
+                .map(|(key, value)| (key.clone(), json!(value.to_string())))
+                .collect();
+            if span.error {
+                let message = match span.attributes.iter().find(|(key, _)| key == "error.type") {
+                    Some((_, error_type)) => error_type.to_string(),
+                    None => String::from("exception"),
+                };
+                tags.insert(String::from("error"), json!(message));
+            }
+            let mut value = json!({
+                "traceId": span.trace_id,
+                "id": span.span_id,
+                "name": span.name.to_lowercase(),
+                "timestamp": span.start,
+                "duration": span.duration(),
+                "localEndpoint": { "serviceName": span.service },
+                "tags": tags,
+            });
+            if let Some(parent) = &span.parent_span_id {
+                value["parentId"] = json!(parent);
+            }
+            // Zipkin has no kind for internal spans
+            if span.kind != SpanKind::Internal {
+                value["kind"] = json!(span.kind.as_str().to_uppercase());
+            }
+            value
+        })
+        .collect()
+}
+
+/// Returns `traces` as JSON in `format`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::trace::{format_traces, gen_trace, TraceFormat};
+/// let traces = vec![gen_trace(), gen_trace()];
+/// let zipkin = format_traces(&traces, TraceFormat::Zipkin);
+/// // zipkin => [{"duration":183042,"id":"00f067aa0ba902b7","kind":"SERVER","localEndpoint":{"serviceName":"api-gateway"},"name":"get /herbs/basil",...}]
+/// let spans: serde_json::Value = serde_json::from_str(&zipkin).unwrap();
+/// assert_eq!(spans.as_array().unwrap().len(), traces[0].spans.len() + traces[1].spans.len());
+/// ```
+pub fn format_traces(traces: &[Trace], format: TraceFormat) -> String {
+    match format {
+        TraceFormat::Otlp => otlp(traces),
+        TraceFormat::Jaeger => jaeger(traces),
+        TraceFormat::Zipkin => zipkin(traces),
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_tree() {
+        for _ in 0..20 {
+            let trace = gen_trace_with(&TraceOptions::default().max_depth(4));
+            let root = trace.root();
+            assert_eq!(root.kind, SpanKind::Server);
+            assert!(root.parent_span_id.is_none());
+            for (i, span) in trace.spans.iter().enumerate() {
+                assert_eq!(span.trace_id, root.trace_id);
+                assert!(span.start < span.end);
+                if let Some(parent_id) = &span.parent_span_id {
+                    // parents come first and enclose their children
+                    let parent = trace.spans[..i]
+                        .iter()
+                        .find(|parent| &parent.span_id == parent_id)
+                        .unwrap();
+                    assert!(parent.start <= span.start && span.end <= parent.end);
+                    if span.kind == SpanKind::Server {
+                        assert_eq!(parent.kind, SpanKind::Client);
+                        assert_eq!(parent.name, span.name);
+                        // both sides of a call agree on the response, unless the client
+                        // timed out before it arrived
+                        match parent.status_code() {
+                            Some(code) => assert_eq!(Some(code), span.status_code()),
+                            None => assert!(
+                                parent
+                                    .attributes
+                                    .contains(&(String::from("error.type"), string("timeout")))
+                            ),
+                        }
+                        // a failed response fails the call
+                        if span.error {
+                            assert!(parent.error);
+                        }
+                    }
+                }
+            }
+            let traceparent = root.traceparent();
+            let parts: Vec<&str> = traceparent.split('-').collect();
+            assert_eq!((parts[0], parts[3]), ("00", "01"));
+            assert_eq!((parts[1].len(), parts[2].len()), (32, 16));
+        }
+    }
+
+    #[test]
+    fn test_errors_and_attributes() {
+        let options = TraceOptions::default()
+            .error_rate(1.0)
+            .attribute("tenant.id", AttributeValue::Int(42));
+        let trace = gen_trace_with(&options);
+        for span in &trace.spans {
+            assert!(span.error);
+            assert!(
+                span.attributes
+                    .contains(&(String::from("tenant.id"), AttributeValue::Int(42)))
+            );
+        }
+        let status = trace
+            .root()
+            .attributes
+            .iter()
+            .find(|(key, _)| key == "http.response.status_code");
+        assert_eq!(status.unwrap().1, AttributeValue::Int(500));
+    }
+
+    #[test]
+    fn test_formats() {
+        let traces = vec![gen_trace(), gen_trace()];
+        let count = traces[0].spans.len() + traces[1].spans.len();
+
+        let otlp: Value = serde_json::from_str(&format_traces(&traces, TraceFormat::Otlp)).unwrap();
+        let spans: usize = otlp["resourceSpans"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|resource| resource["scopeSpans"][0]["spans"].as_array().unwrap().len())
+            .sum();
+        assert_eq!(spans, count);
+
+        let jaeger: Value =
+            serde_json::from_str(&format_traces(&traces, TraceFormat::Jaeger)).unwrap();
+        let data = jaeger["data"].as_array().unwrap();
+        assert_eq!(data.len(), 2);
+        let root = &data[0]["spans"][0];
+        assert_eq!(root["references"].as_array().unwrap().len(), 0);
+        let process = root["processID"].as_str().unwrap();
+        assert_eq!(data[0]["processes"][process]["serviceName"], "api-gateway");
+
+        let zipkin: Value =
+            serde_json::from_str(&format_traces(&traces, TraceFormat::Zipkin)).unwrap();
+        assert_eq!(zipkin.as_array().unwrap().len(), count);
+        assert_eq!(zipkin[0]["kind"], "SERVER");
+        assert!(zipkin[0].get("parentId").is_none());
+    }
+}
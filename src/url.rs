@@ -0,0 +1,493 @@
+//! Module url provides URLs according to RFC 3986, with hosts from the [`crate::domain`] module or
+//! IP literals, paths and query strings made of corpora words, and percent-encoding.
+//!
+//! [`UrlOptions::fuzz`] produces URLs which are valid but unusual, like mixed case schemes, dot
+//! segments, empty ports or IPv6 zone identifiers, to test URL parsers. While safe mode is enabled
+//! (see [`crate::context::set_safe_mode`]) hosts are reserved domains or documentation addresses.
+
+use std::fmt;
+
+use rand::Rng;
+
+use crate::context::safe_mode;
+use crate::data::pick_word;
+use crate::domain::{DomainOptions, gen_domain_with, to_label};
+use crate::gen_username;
+
+/// Datasets path segments, query parameters and fragments are taken from.
+const PATH_DATASETS: [&str; 6] = ["objects", "fruits", "herbs", "moods", "metals", "flowers"];
+
+const QUERY_KEYS: [&str; 12] = [
+    "q",
+    "id",
+    "page",
+    "sort",
+    "lang",
+    "ref",
+    "utm_source",
+    "filter",
+    "limit",
+    "offset",
+    "tag",
+    "search",
+];
+
+const FILE_EXTENSIONS: [&str; 6] = ["html", "json", "png", "pdf", "css", "js"];
+
+const PORTS: [u16; 7] = [8080, 8443, 3000, 5000, 8000, 8888, 9000];
+
+/// Options for [`gen_url_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UrlOptions {
+    https_only: bool,
+    max_depth: usize,
+    fuzz: bool,
+}
+
+impl Default for UrlOptions {
+    /// HTTP and HTTPS, paths of up to 4 segments, no fuzzing.
+    fn default() -> Self {
+        UrlOptions {
+            https_only: false,
+            max_depth: 4,
+            fuzz: false,
+        }
+    }
+}
+
+impl UrlOptions {
+    /// Only use the `https` scheme.
+    pub fn https_only(mut self, https_only: bool) -> Self {
+        self.https_only = https_only;
+        self
+    }
+
+    /// The maximum number of path segments.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Produce unusual but valid URLs for parser fuzzing.
+    pub fn fuzz(mut self, fuzz: bool) -> Self {
+        self.fuzz = fuzz;
+        self
+    }
+}
+
+/// A generated URL. All parts are already percent-encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Url {
+    pub scheme: String,
+    /// `user:password` in front of the host.
+    pub userinfo: Option<String>,
+    /// Domain, IPv4 address or IPv6 literal in brackets.
+    pub host: String,
+    /// The port as written after the colon, which may be empty for fuzzed URLs.
+    pub port: Option<String>,
+    /// The path, starting with `/`.
+    pub path: String,
+    /// The query without the leading `?`.
+    pub query: Option<String>,
+    /// The fragment without the leading `#`.
+    pub fragment: Option<String>,
+}
+
+impl Url {
+    /// Path and query as used in the request line of HTTP requests: `/fruits/fig?page=2`.
+    pub fn request_target(&self) -> String {
+        match &self.query {
+            Some(query) => format!("{}?{}", self.path, query),
+            None => self.path.clone(),
+        }
+    }
+
+    /// Host and port as used in the `Host` header: `amber-fig.com:8080`.
+    pub fn authority(&self) -> String {
+        match &self.port {
+            Some(port) => format!("{}:{}", self.host, port),
+            None => self.host.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Url {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://", self.scheme)?;
+        if let Some(userinfo) = &self.userinfo {
+            write!(f, "{}@", userinfo)?;
+        }
+        write!(f, "{}{}", self.authority(), self.request_target())?;
+        if let Some(fragment) = &self.fragment {
+            write!(f, "#{}", fragment)?;
+        }
+        Ok(())
+    }
+}
+
+/// Percent-encode every byte of `input` except the unreserved characters of RFC 3986: ASCII
+/// letters, digits, `-`, `.`, `_` and `~`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::url::percent_encode;
+/// assert_eq!(percent_encode("crème brûlée"), "cr%C3%A8me%20br%C3%BBl%C3%A9e");
+/// ```
+pub fn percent_encode(input: &str) -> String {
+    let mut encoded = String::new();
+    for byte in input.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// A random corpora word.
+fn pick_path_word(rnd: &mut impl Rng) -> String {
+    let dataset = PATH_DATASETS[rnd.random_range(0..PATH_DATASETS.len())];
+    pick_word(dataset, rnd)
+}
+
+/// A path segment: a slug, a numeric id or a percent-encoded word.
+fn gen_segment(rnd: &mut impl Rng) -> String {
+    match rnd.random_range(0..6) {
+        0 => rnd.random_range(1..100_000).to_string(),
+        1 => percent_encode(&pick_path_word(rnd)),
+        _ => {
+            let segment = to_label(&pick_path_word(rnd));
+            if segment.is_empty() {
+                String::from("index")
+            } else {
+                segment
+            }
+        }
+    }
+}
+
+/// A path of up to `max_depth` segments, sometimes ending with a file name.
+fn gen_path(max_depth: usize, rnd: &mut impl Rng) -> String {
+    let depth = rnd.random_range(0..=max_depth);
+    let mut segments: Vec<String> = (0..depth).map(|_| gen_segment(rnd)).collect();
+    if let Some(last) = segments.last_mut()
+        && rnd.random_range(0..4) == 0
+    {
+        let extension = FILE_EXTENSIONS[rnd.random_range(0..FILE_EXTENSIONS.len())];
+        last.push('.');
+        last.push_str(extension);
+    }
+    format!("/{}", segments.join("/"))
+}
+
+/// A query of one to four percent-encoded parameters.
+fn gen_query(rnd: &mut impl Rng) -> String {
+    let count = rnd.random_range(1..=4);
+    (0..count)
+        .map(|_| {
+            let key = QUERY_KEYS[rnd.random_range(0..QUERY_KEYS.len())];
+            let value = match rnd.random_range(0..3) {
+                0 => rnd.random_range(1..1000).to_string(),
+                _ => percent_encode(&pick_path_word(rnd)),
+            };
+            format!("{}={}", key, value)
+        })
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+/// Eight hexadecimal groups of an IPv6 address, with the longest run of zero groups compressed.
+fn format_ipv6(groups: [u16; 8]) -> String {
+    let (mut best_start, mut best_length) = (0, 0);
+    let mut start = 0;
+    while start < 8 {
+        let length = groups[start..].iter().take_while(|g| **g == 0).count();
+        if length > best_length {
+            (best_start, best_length) = (start, length);
+        }
+        start += length.max(1);
+    }
+    let hex = |groups: &[u16]| {
+        groups
+            .iter()
+            .map(|g| format!("{:x}", g))
+            .collect::<Vec<String>>()
+            .join(":")
+    };
+    if best_length < 2 {
+        return hex(&groups);
+    }
+    format!(
+        "{}::{}",
+        hex(&groups[..best_start]),
+        hex(&groups[best_start + best_length..])
+    )
+}
+
+/// An IPv6 address, from the documentation prefix `2001:db8::/32` in safe mode.
+fn gen_ipv6(rnd: &mut impl Rng) -> String {
+    let mut groups: [u16; 8] = rnd.random();
+    if safe_mode() {
+        groups[0] = 0x2001;
+        groups[1] = 0x0db8;
+    } else {
+        // global unicast addresses start with 2 or 3
+        groups[0] = 0x2000 | (groups[0] & 0x1fff);
+    }
+    // many addresses end with a small interface id
+    if rnd.random_bool(0.5) {
+        groups[4..7].fill(0);
+        groups[7] = rnd.random_range(1..0x100);
+    }
+    format_ipv6(groups)
+}
+
+/// An IPv4 address, from the documentation networks of RFC 5737 in safe mode.
+fn gen_host_ipv4(rnd: &mut impl Rng) -> String {
+    if safe_mode() {
+        let network = ["192.0.2", "198.51.100", "203.0.113"][rnd.random_range(0..3)];
+        return format!("{}.{}", network, rnd.random_range(1..255));
+    }
+    crate::gen_ipv4()
+}
+
+/// A host: mostly domains, sometimes IP addresses.
+fn gen_host(rnd: &mut impl Rng) -> String {
+    match rnd.random_range(0..10) {
+        0 => gen_host_ipv4(rnd),
+        1 => format!("[{}]", gen_ipv6(rnd)),
+        _ => {
+            let subdomains = rnd.random_range(0..=2);
+            gen_domain_with(&DomainOptions::default().subdomains(subdomains))
+        }
+    }
+}
+
+/// Randomly change the case of ASCII letters.
+fn mix_case(input: &str, rnd: &mut impl Rng) -> String {
+    input
+        .chars()
+        .map(|c| {
+            if rnd.random_bool(0.5) {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Apply unusual but valid variations to `url`.
+fn fuzz_url(url: &mut Url, options: &UrlOptions, rnd: &mut impl Rng) {
+    if rnd.random_bool(0.3) {
+        url.scheme = mix_case(&url.scheme, rnd);
+    }
+    url.host = match rnd.random_range(0..8) {
+        0 => mix_case(&url.host, rnd),
+        1 if !url.host.starts_with('[') => format!("{}.", url.host),
+        2 => String::from("[fe80::1%25eth0]"),
+        3 => format!("[::ffff:{}]", gen_host_ipv4(rnd)),
+        4 => format!("[v1.{:x}]", rnd.random::<u16>()),
+        _ => url.host.clone(),
+    };
+    url.port = match rnd.random_range(0..6) {
+        0 => Some(String::new()),
+        1 => Some(String::from(if url.scheme.eq_ignore_ascii_case("https") {
+            "443"
+        } else {
+            "80"
+        })),
+        2 => Some(rnd.random_range(1..=65535u16).to_string()),
+        3 => Some(format!("0{}", rnd.random_range(1..=9999))),
+        _ => url.port.take(),
+    };
+    url.userinfo = match rnd.random_range(0..5) {
+        0 => Some(String::new()),
+        1 => Some(format!("{}:", percent_encode(&gen_username()))),
+        2 => Some(format!(
+            "user%40{}:p%3Ass!$&'()*+,;=",
+            rnd.random_range(1..100)
+        )),
+        _ => url.userinfo.take(),
+    };
+    if options.max_depth > 0 {
+        let weird = ["", ".", "..", "%7Euser", "a%2Fb", "@:!$&'()*+,;="];
+        let segment = weird[rnd.random_range(0..weird.len())];
+        // insert at the start so the depth stays within the limit when the path is cut
+        let mut segments: Vec<&str> = url.path[1..].split('/').collect();
+        segments.insert(0, segment);
+        segments.truncate(options.max_depth);
+        url.path = format!("/{}", segments.join("/"));
+    }
+    url.query = match rnd.random_range(0..5) {
+        0 => Some(String::new()),
+        1 => Some(String::from("&&=&a=&=b")),
+        2 => Some(format!(
+            "q=a+b&q=%20c&redirect=/path?x=1:{}",
+            rnd.random_range(1..100)
+        )),
+        _ => url.query.take(),
+    };
+    url.fragment = match rnd.random_range(0..4) {
+        0 => Some(String::new()),
+        1 => Some(String::from("/section?x=1")),
+        _ => url.fragment.take(),
+    };
+}
+
+/// Returns a URL with the default options, see [`UrlOptions`].
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::gen_url;
+/// let url: String = gen_url();
+/// // url => "https://amber-fig.com/herbs/basil?q=fig"
+/// assert!(url.starts_with("http"));
+/// ```
+pub fn gen_url() -> String {
+    gen_url_with(&UrlOptions::default()).to_string()
+}
+
+/// Returns a URL built with `options`.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::url::{gen_url_with, UrlOptions};
+/// let options = UrlOptions::default().https_only(true).max_depth(2);
+/// let url = gen_url_with(&options);
+/// // url.to_string() => "https://www.amber-fig.com/fruits/fig.html?page=2#ruby"
+/// // url.request_target() => "/fruits/fig.html?page=2"
+/// assert_eq!(url.scheme, "https");
+/// assert!(url.path.matches('/').count() <= 2);
+/// ```
+pub fn gen_url_with(options: &UrlOptions) -> Url {
+    let mut rnd = rand::rng();
+    let scheme = if options.https_only || rnd.random_bool(0.8) {
+        "https"
+    } else {
+        "http"
+    };
+    let mut url = Url {
+        scheme: scheme.to_string(),
+        userinfo: None,
+        host: gen_host(&mut rnd),
+        port: None,
+        path: gen_path(options.max_depth, &mut rnd),
+        query: None,
+        fragment: None,
+    };
+    if rnd.random_range(0..20) == 0 {
+        let password: String = crate::gen_password(8);
+        url.userinfo = Some(format!(
+            "{}:{}",
+            percent_encode(&gen_username()),
+            percent_encode(&password)
+        ));
+    }
+    if rnd.random_range(0..5) == 0 {
+        url.port = Some(PORTS[rnd.random_range(0..PORTS.len())].to_string());
+    }
+    if rnd.random_bool(0.4) {
+        url.query = Some(gen_query(&mut rnd));
+    }
+    if rnd.random_range(0..5) == 0 {
+        url.fragment = Some(to_label(&pick_path_word(&mut rnd)));
+    }
+    if options.fuzz {
+        fuzz_url(&mut url, options, &mut rnd);
+    }
+    url
+}
+
+/// Returns a request path with up to `max_depth` segments and sometimes a query, as found in the
+/// request line of HTTP requests and in access logs.
+///
+/// ## Example
+/// ```rust
+/// use fakedata_generator::url::gen_request_path;
+/// let path: String = gen_request_path(3);
+/// // path => "/herbs/basil?page=2"
+/// assert!(path.starts_with('/'));
+/// ```
+pub fn gen_request_path(max_depth: usize) -> String {
+    let mut rnd = rand::rng();
+    let path = gen_path(max_depth, &mut rnd);
+    if rnd.random_bool(0.3) {
+        return format!("{}?{}", path, gen_query(&mut rnd));
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Characters allowed in URLs by RFC 3986, with `%` only in front of two hex digits.
+    fn is_valid_url(url: &str) -> bool {
+        let bytes = url.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let c = bytes[i];
+            if c == b'%' {
+                if i + 2 >= bytes.len()
+                    || !bytes[i + 1].is_ascii_hexdigit()
+                    || !bytes[i + 2].is_ascii_hexdigit()
+                {
+                    return false;
+                }
+                i += 3;
+                continue;
+            }
+            if !(c.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'()*+,;=".contains(&c)) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("a b&c=d"), "a%20b%26c%3Dd");
+        assert_eq!(percent_encode("safe-._~"), "safe-._~");
+        assert_eq!(percent_encode("ü"), "%C3%BC");
+    }
+
+    #[test]
+    fn test_format_ipv6() {
+        assert_eq!(
+            format_ipv6([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]),
+            "2001:db8::1"
+        );
+        assert_eq!(
+            format_ipv6([0x2001, 0xdb8, 1, 0, 1, 0, 0, 1]),
+            "2001:db8:1:0:1::1"
+        );
+        assert_eq!(format_ipv6([1, 2, 3, 4, 5, 6, 7, 8]), "1:2:3:4:5:6:7:8");
+        assert_eq!(format_ipv6([0xfe80, 0, 0, 0, 0, 0, 0, 0]), "fe80::");
+    }
+
+    #[test]
+    fn test_gen_url_with() {
+        for _ in 0..200 {
+            let url = gen_url_with(&UrlOptions::default());
+            let text = url.to_string();
+            assert!(is_valid_url(&text), "{}", text);
+            assert!(text.starts_with("http://") || text.starts_with("https://"));
+
+            let url = gen_url_with(&UrlOptions::default().https_only(true).max_depth(1));
+            assert_eq!(url.scheme, "https");
+            assert!(url.path.matches('/').count() <= 1, "{}", url);
+
+            let url = gen_url_with(&UrlOptions::default().fuzz(true).max_depth(3));
+            let text = url.to_string();
+            assert!(is_valid_url(&text), "{}", text);
+            assert!(url.path.starts_with('/'));
+            assert!(url.path.matches('/').count() <= 3, "{}", url);
+
+            let path = gen_request_path(2);
+            assert!(is_valid_url(&path) && path.starts_with('/'), "{}", path);
+        }
+    }
+}